@@ -0,0 +1,34 @@
+//! Compares parsing addresses with [`addrs::ipv4::Addr`] against [`std::net::Ipv4Addr`].
+//!
+//! Run with `cargo bench --bench parse`.
+
+use std::{hint::black_box, net::Ipv4Addr, str::FromStr, time::Instant};
+
+use addrs::ipv4::Addr;
+
+const ROUNDS: usize = 200;
+
+fn bench<T: FromStr>(name: &str, inputs: &[String]) {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for input in inputs {
+            let _ = black_box(black_box(input.as_str()).parse::<T>().ok());
+        }
+    }
+    let elapsed = start.elapsed();
+    let n = (ROUNDS * inputs.len()) as f64;
+    println!(
+        "{name:>10}: {:>6.1} ns/parse",
+        elapsed.as_nanos() as f64 / n
+    );
+}
+
+fn main() {
+    let inputs: Vec<String> = (0..10_000u32)
+        .map(|i| Ipv4Addr::from(i.wrapping_mul(2_654_435_761)).to_string())
+        .collect();
+    for _ in 0..2 {
+        bench::<Ipv4Addr>("Ipv4Addr", &inputs);
+        bench::<Addr>("Addr", &inputs);
+    }
+}
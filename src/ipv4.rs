@@ -2,11 +2,28 @@ use std::ops::RangeInclusive;
 
 use crate::{Error, Result};
 
+/// the unsigned integer type that every IPv4 [`Address`] converts to and from. It implements
+/// [`crate::ui::Int`] so generic code can do address math without naming the integer type.
+///
+/// # Example
+/// ```
+/// # use addrs::{ipv4::{Address, UI}, ui::Int};
+/// # use std::net::Ipv4Addr;
+/// fn next<A: Address>(address: A) -> Option<A> {
+///     let ui: UI = address.into();
+///     ui.checked_add(UI::ONE).map(A::from)
+/// }
+/// assert_eq!(Some(Ipv4Addr::new(10, 0, 1, 0)), next(Ipv4Addr::new(10, 0, 0, 255)));
+/// assert_eq!(None, next(Ipv4Addr::BROADCAST));
+/// ```
+pub type UI = u32;
+
 /// Defines minimum requirements of an ipv4 address for this crate
 ///
 /// The purpose of this trait is not to replace nor even add to [`std::net::Ipv4Addr`]. It is well
 /// thought out and (nearly) complete enough for my purposes[^1]. For this reason, this crate
-/// doesn't even provide a type alias for it.
+/// doesn't even provide a type alias for it. For those who don't want to bring their own type,
+/// [`Addr`] is a compact implementation provided by this crate.
 ///
 /// This trait is mostly for use within this crate to formalize its good properties on which this
 /// crate may depend. We also provide a minimal set of integration tests which are meant as a
@@ -14,6 +31,11 @@ use crate::{Error, Result};
 ///
 /// This trait also servers to limit the touch points this crate has on that type.
 ///
+/// Implementations must order addresses by their numeric value, the same order as the [`UI`]
+/// they convert to, so that [`Ord`] agrees with [`Address::cmp_numeric`]. This crate relies on
+/// that when sorting and searching and so may code that builds sorted structures of addresses.
+/// [`std::net::Ipv4Addr`] and [`Addr`] both do.
+///
 /// [^1]: One minor exception is that I wish it implemented Into<[u8; 4]>. There is
 /// [`std::net::Ipv4Addr::octets`] but it is more awkward.
 pub trait Address:
@@ -21,8 +43,8 @@ pub trait Address:
     + Ord
     + Clone
     + Copy
-    + From<u32>
-    + Into<u32>
+    + From<UI>
+    + Into<UI>
     + From<[u8; 4]>
 //     + Into<[u8; 4]>
     + std::string::ToString
@@ -38,6 +60,33 @@ pub trait Address:
     /// formalize that all v4 address are 32 bits
     const BITS: u8 = 32;
 
+    /// the unspecified address, `0.0.0.0`. This and the other well-known addresses are required
+    /// because [`From<UI>`] can't be called in a const context.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// fn is_sentinel<A: Address>(address: A) -> bool {
+    ///     address == A::UNSPECIFIED || address == A::BROADCAST
+    /// }
+    /// assert!(is_sentinel(Ipv4Addr::new(0, 0, 0, 0)));
+    /// assert!(!is_sentinel(Ipv4Addr::new(10, 0, 0, 1)));
+    /// ```
+    const UNSPECIFIED: Self;
+
+    /// the limited broadcast address, `255.255.255.255`
+    const BROADCAST: Self;
+
+    /// the loopback address, `127.0.0.1`
+    const LOCALHOST: Self;
+
+    /// the smallest address, `0.0.0.0`
+    const MIN: Self = Self::UNSPECIFIED;
+
+    /// the largest address, `255.255.255.255`
+    const MAX: Self = Self::BROADCAST;
+
     /// returns the bytes of the address in network order
     ///
     /// in lieu of implementing Into<[u8; 4]>, this allows this crate to easily get at the
@@ -53,8 +102,380 @@ pub trait Address:
     /// check("1.2.3.4".parse::<Ipv4Addr>().unwrap());
     /// ```
     fn octets(&self) -> [u8; 4];
+
+    /// formats the address in dotted-decimal notation into the given buffer and returns the part
+    /// of the buffer that was written as a string. This doesn't allocate or depend on
+    /// [`std::fmt`]. At most [`MAX_ADDRESS_LEN`] bytes are needed. If the buffer is too small,
+    /// [`Error::BufferTooSmall`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Address, MAX_ADDRESS_LEN};
+    /// # use std::net::Ipv4Addr;
+    /// let mut buf = [0u8; MAX_ADDRESS_LEN];
+    /// let ip = Ipv4Addr::new(192, 168, 0, 1);
+    /// assert_eq!("192.168.0.1", Address::format_into(&ip, &mut buf).unwrap());
+    /// ```
+    fn format_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str> {
+        match format::write_address(self.octets(), buf) {
+            Some(len) => Ok(format::as_str(buf, len)),
+            None => Err(Error::BufferTooSmall),
+        }
+    }
+
+    /// returns the name under `in-addr.arpa` used for reverse DNS (PTR) lookups of the address
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// assert_eq!("4.3.2.1.in-addr.arpa", Ipv4Addr::new(1, 2, 3, 4).to_ptr_name());
+    /// ```
+    fn to_ptr_name(&self) -> String {
+        let [a, b, c, d] = self.octets();
+        format!("{d}.{c}.{b}.{a}.in-addr.arpa")
+    }
+
+    /// parses a reverse DNS name under `in-addr.arpa`, the inverse of [`Address::to_ptr_name`].
+    /// The suffix is matched without regard to case and a trailing dot is allowed. If the name
+    /// isn't exactly four octets under `in-addr.arpa`, [`Error::ParseError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// let ip = Ipv4Addr::from_ptr_name("4.3.2.1.in-addr.arpa.").unwrap();
+    /// assert_eq!(Ipv4Addr::new(1, 2, 3, 4), ip);
+    /// assert!(Ipv4Addr::from_ptr_name("3.2.1.in-addr.arpa").is_err());
+    /// ```
+    fn from_ptr_name(s: &str) -> Result<Self> {
+        const SUFFIX: &str = ".in-addr.arpa";
+        let s = s.strip_suffix('.').unwrap_or(s);
+        let split = s.len().checked_sub(SUFFIX.len()).ok_or(Error::ParseError(None))?;
+        match s.get(split..) {
+            Some(suffix) if suffix.eq_ignore_ascii_case(SUFFIX) => {}
+            _ => return Err(Error::ParseError(None)),
+        }
+        let mut octets = [0u8; 4];
+        let mut labels = s[..split].split('.');
+        for octet in octets.iter_mut().rev() {
+            let label = labels.next().ok_or(Error::ParseError(None))?;
+            *octet = match label.bytes().all(|b| b.is_ascii_digit()) {
+                true => label.parse().map_err(Error::parse_error)?,
+                false => return Err(Error::ParseError(None)),
+            };
+        }
+        match labels.next() {
+            Some(_) => Err(Error::ParseError(None)),
+            None => Ok(octets.into()),
+        }
+    }
+
+    /// returns the numeric value of the address as a decimal string, e.g. `3232235521` for
+    /// `192.168.0.1`, the form databases and log pipelines often store addresses in
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// assert_eq!("3232235521", Ipv4Addr::new(192, 168, 0, 1).to_integer_string());
+    /// ```
+    fn to_integer_string(&self) -> String {
+        let ui: UI = (*self).into();
+        ui.to_string()
+    }
+
+    /// parses the decimal string form of the address's numeric value, the inverse of
+    /// [`Address::to_integer_string`]. Only ASCII digits are accepted. If the string isn't a
+    /// decimal number that fits in 32 bits, [`Error::ParseError`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// assert_eq!(Ipv4Addr::new(192, 168, 0, 1), Ipv4Addr::parse_integer("3232235521").unwrap());
+    /// assert!(Ipv4Addr::parse_integer("4294967296").is_err());
+    /// ```
+    fn parse_integer(s: &str) -> Result<Self> {
+        match !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            true => Ok(s.parse::<UI>().map_err(Error::parse_error)?.into()),
+            false => Err(Error::ParseError(None)),
+        }
+    }
+
+    /// parses an address in strict dotted-decimal notation from the front of the given bytes and
+    /// returns it along with the number of bytes consumed. Whatever follows is left for the
+    /// caller, which makes this suitable for tokenizers and wire protocol decoders. On failure,
+    /// [`Error::ParseError`] is returned with [details](Error::parse_details).
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// let (ip, consumed) = Ipv4Addr::parse_partial(b"10.0.0.1:8080").unwrap();
+    /// assert_eq!((Ipv4Addr::new(10, 0, 0, 1), 8), (ip, consumed));
+    /// ```
+    fn parse_partial(bytes: &[u8]) -> Result<(Self, usize)> {
+        addr::parse_partial(bytes)
+            .map(|(bits, consumed)| (Self::from(bits), consumed))
+            .map_err(|(kind, offset)| {
+                Error::parse_error_at(kind, offset, &String::from_utf8_lossy(bytes))
+            })
+    }
+
+    /// compares two addresses by their numeric value, e.g. `9.255.255.255` is less than
+    /// `10.0.0.0`. This is the order that [`Ord`] is required to follow for every implementation
+    /// but doesn't depend on it.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::{cmp::Ordering, net::Ipv4Addr};
+    /// let a = Ipv4Addr::new(9, 255, 255, 255);
+    /// let b = Ipv4Addr::new(10, 0, 0, 0);
+    /// assert_eq!(Ordering::Less, a.cmp_numeric(&b));
+    /// assert_eq!(a.cmp(&b), a.cmp_numeric(&b));
+    /// ```
+    fn cmp_numeric(&self, other: &Self) -> std::cmp::Ordering {
+        let a: UI = (*self).into();
+        let b: UI = (*other).into();
+        a.cmp(&b)
+    }
+
+    /// returns the address the given number of addresses after this one or [`None`] if that
+    /// would go past `255.255.255.255`
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// let ip = Ipv4Addr::new(10, 0, 0, 255);
+    /// assert_eq!(Some(Ipv4Addr::new(10, 0, 1, 4)), ip.checked_add(5));
+    /// assert_eq!(None, Ipv4Addr::BROADCAST.checked_add(1));
+    /// ```
+    fn checked_add(&self, offset: UI) -> Option<Self> {
+        let ui: UI = (*self).into();
+        ui.checked_add(offset).map(Self::from)
+    }
+
+    /// returns the address the given number of addresses after this one, stopping at
+    /// `255.255.255.255`
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// let ip = Ipv4Addr::new(255, 255, 255, 250);
+    /// assert_eq!(Ipv4Addr::BROADCAST, ip.saturating_add(10));
+    /// ```
+    fn saturating_add(&self, offset: UI) -> Self {
+        let ui: UI = (*self).into();
+        ui.saturating_add(offset).into()
+    }
+
+    /// returns the address the given number of addresses after this one, wrapping around to
+    /// `0.0.0.0` after `255.255.255.255`
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// assert_eq!(Ipv4Addr::new(0, 0, 0, 1), Ipv4Addr::BROADCAST.wrapping_add(2));
+    /// ```
+    fn wrapping_add(&self, offset: UI) -> Self {
+        let ui: UI = (*self).into();
+        ui.wrapping_add(offset).into()
+    }
+
+    /// returns the address the given number of addresses before this one or [`None`] if that
+    /// would go past `0.0.0.0`
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// let ip = Ipv4Addr::new(10, 0, 1, 0);
+    /// assert_eq!(Some(Ipv4Addr::new(10, 0, 0, 255)), ip.checked_sub(1));
+    /// assert_eq!(None, Ipv4Addr::UNSPECIFIED.checked_sub(1));
+    /// ```
+    fn checked_sub(&self, offset: UI) -> Option<Self> {
+        let ui: UI = (*self).into();
+        ui.checked_sub(offset).map(Self::from)
+    }
+
+    /// returns the number of steps between this address and the other in either direction, so
+    /// that `a.checked_add(a.distance_to(&b)) == Some(b)` when `a <= b`
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// let a = Ipv4Addr::new(10, 0, 0, 10);
+    /// let b = Ipv4Addr::new(10, 0, 1, 0);
+    /// assert_eq!(246, a.distance_to(&b));
+    /// assert_eq!(246, b.distance_to(&a));
+    /// ```
+    fn distance_to(&self, other: &Self) -> u32 {
+        let a: UI = (*self).into();
+        let b: UI = (*other).into();
+        a.abs_diff(b)
+    }
+
+    /// returns the bit of the address at the given position counting from the most significant,
+    /// so that bit 0 is the first one a prefix covers. Panics if the position is 32 or greater.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// let ip = Ipv4Addr::new(128, 0, 0, 1);
+    /// assert!(ip.bit(0));
+    /// assert!(!ip.bit(1));
+    /// assert!(ip.bit(31));
+    /// ```
+    fn bit(&self, n: u8) -> bool {
+        assert!(n < Self::BITS, "bit {n} is out of range");
+        let ui: UI = (*self).into();
+        ui & 1 << (31 - n) != 0
+    }
+
+    /// returns a copy of the address with the bit at the given position, counting from the most
+    /// significant, set to the given value. Panics if the position is 32 or greater.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// let ip = Ipv4Addr::new(10, 0, 0, 0);
+    /// assert_eq!(Ipv4Addr::new(10, 128, 0, 0), ip.with_bit(8, true));
+    /// assert_eq!(Ipv4Addr::new(2, 0, 0, 0), ip.with_bit(4, false));
+    /// ```
+    fn with_bit(&self, n: u8, value: bool) -> Self {
+        assert!(n < Self::BITS, "bit {n} is out of range");
+        let ui: UI = (*self).into();
+        let bit = 1 << (31 - n);
+        match value {
+            true => ui | bit,
+            false => ui & !bit,
+        }
+        .into()
+    }
+
+    /// returns the number of leading bits that this address has in common with the other, which
+    /// is the length of the longest prefix containing both
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// let a = Ipv4Addr::new(10, 0, 0, 1);
+    /// assert_eq!(23, a.common_prefix_len(&Ipv4Addr::new(10, 0, 1, 1)));
+    /// assert_eq!(32, a.common_prefix_len(&a));
+    /// ```
+    fn common_prefix_len(&self, other: &Self) -> u8 {
+        let a: UI = (*self).into();
+        let b: UI = (*other).into();
+        (a ^ b).leading_zeros() as u8
+    }
+
+    /// returns an adapter that formats the address as its numeric value with the integer
+    /// formatting traits, e.g. `{:08x}` or `{:032b}`, which is handy when debugging masks
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// let ip = Ipv4Addr::new(10, 0, 0, 1);
+    /// assert_eq!("0a000001", format!("{:08x}", ip.as_bits()));
+    /// assert_eq!("A000001", format!("{:X}", ip.as_bits()));
+    /// ```
+    fn as_bits(&self) -> Bits<Self> {
+        Bits::new(*self)
+    }
+
+    /// parses an address using the given [`ParseMode`] rather than the type's own [`FromStr`]
+    /// implementation which may be more or less permissive. If the string isn't a valid address
+    /// in that mode, [`Error::ParseError`] is returned.
+    ///
+    /// [`FromStr`]: std::str::FromStr
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Address, ParseMode};
+    /// # use std::net::Ipv4Addr;
+    /// assert!(Ipv4Addr::parse_with("010.0.0.1", ParseMode::Strict).is_err());
+    /// assert_eq!(
+    ///     Ipv4Addr::new(8, 0, 0, 1),
+    ///     Ipv4Addr::parse_with("010.0.0.1", ParseMode::Lenient).unwrap()
+    /// );
+    /// assert_eq!(
+    ///     Ipv4Addr::new(10, 0, 0, 1),
+    ///     Ipv4Addr::parse_with("0xa.1", ParseMode::Lenient).unwrap()
+    /// );
+    /// ```
+    fn parse_with(s: &str, mode: ParseMode) -> Result<Self> {
+        let bits = match mode {
+            ParseMode::Strict => addr::parse_strict(s)?,
+            ParseMode::Lenient => parse_mode::parse_lenient(s).ok_or(Error::ParseError(None))?,
+        };
+        Ok(Self::from(bits))
+    }
+
+    /// returns the (x, y) cell holding the address on a Hilbert curve of the given order, which
+    /// fills a 2^order by 2^order grid. The top `2 * order` bits of the address give its distance
+    /// along the curve so that each cell holds a /`2 * order` prefix and nearby addresses land in
+    /// nearby cells. Order 16 gives every address its own cell, as in the well-known maps of the
+    /// IPv4 address space. If the order is greater than 16 then [`Error::InvalidLength`] is
+    /// returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// // with order 4, each of the 256 cells holds a /8
+    /// assert_eq!((0, 0), Ipv4Addr::new(0, 1, 2, 3).hilbert_d(4).unwrap());
+    /// assert_eq!((1, 0), Ipv4Addr::new(1, 0, 0, 0).hilbert_d(4).unwrap());
+    /// assert_eq!((15, 0), Ipv4Addr::new(255, 0, 0, 0).hilbert_d(4).unwrap());
+    /// ```
+    fn hilbert_d(&self, order: u8) -> Result<(u16, u16)> {
+        if order > 16 {
+            return Err(Error::InvalidLength);
+        }
+        let ui: UI = (*self).into();
+        let d = ui.checked_shr(32 - 2 * order as u32).unwrap_or(0);
+        Ok(hilbert::d_to_xy(order, d))
+    }
+
+    /// returns the first address in the given (x, y) cell of a Hilbert curve of the given order,
+    /// the inverse of [`Address::hilbert_d`]. If the order is greater than 16 then
+    /// [`Error::InvalidLength`] is returned and if the cell is outside of the grid then
+    /// [`Error::NotContained`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Address;
+    /// # use std::net::Ipv4Addr;
+    /// assert_eq!(Ipv4Addr::new(255, 0, 0, 0), Ipv4Addr::from_hilbert_d(4, (15, 0)).unwrap());
+    /// assert!(Ipv4Addr::from_hilbert_d(4, (16, 0)).is_err());
+    /// ```
+    fn from_hilbert_d(order: u8, (x, y): (u16, u16)) -> Result<Self> {
+        if order > 16 {
+            return Err(Error::InvalidLength);
+        }
+        if u32::from(x.max(y)) >> order != 0 {
+            return Err(Error::NotContained);
+        }
+        let d = hilbert::xy_to_d(order, (x, y));
+        Ok(d.checked_shl(32 - 2 * order as u32).unwrap_or(0).into())
+    }
 }
 
+/// the maximum number of bytes needed to format an address, e.g. `255.255.255.255`
+pub const MAX_ADDRESS_LEN: usize = 15;
+
+/// the maximum number of bytes needed to format a prefix, e.g. `255.255.255.255/32`
+pub const MAX_PREFIX_LEN: usize = 18;
+
 /// Defines minimum requirements of an ipv4 prefix for this crate and provides implementations of
 /// new methods.
 ///
@@ -93,134 +514,1054 @@ pub trait Prefix: Eq + std::str::FromStr + std::string::ToString {
     fn length(&self) -> u8;
 
     /// returns a new Prefix without checking length for when I know what I'm doing
-    unsafe fn unsafe_new(ip: Self::Address, length: u8) -> Self;
+    ///
+    /// # Safety
+    ///
+    /// `length` must not be greater than [`Address::BITS`]. Implementations may rely on it.
+    unsafe fn new_unchecked(ip: Self::Address, length: u8) -> Self;
 
-    /// returns the prefix for the given address combined with the given prefix length. If the
-    /// length is greater than 32 then [`Error::InvalidLength`] is returned.
+    /// returns a new Prefix for the given address and length, checking the length. If the length
+    /// is greater than 32 then [`Error::InvalidLength`] is returned. This is the safe counterpart
+    /// of [`Prefix::new_unchecked`].
     ///
     /// # Example
     /// ```
     /// # use addrs::ipv4::Prefix;
     /// # use std::net::Ipv4Addr;
-    /// let ip = Ipv4Addr::new(1,2,3,4);
-    /// let prefix: ipnet::Ipv4Net = Prefix::from_address_length(ip, 25).unwrap();
-    /// assert_eq!("1.2.3.4/25", prefix.to_string());
+    /// let prefix = <ipnet::Ipv4Net as Prefix>::new(Ipv4Addr::new(1, 2, 3, 4), 32).unwrap();
+    /// assert_eq!("1.2.3.4/32", prefix.to_string());
+    /// assert!(<ipnet::Ipv4Net as Prefix>::new(Ipv4Addr::new(1, 2, 3, 4), 33).is_err());
     /// ```
-    fn from_address_length(ip: Self::Address, length: u8) -> Result<Self> {
-        match length {
-            length if length < Self::Address::BITS => Ok(unsafe { Self::unsafe_new(ip, length) }),
-            _ => Err(Error::InvalidLength),
-        }
+    fn new(address: Self::Address, length: u8) -> Result<Self> {
+        Ok(Self::from_address_prefix_length(
+            address,
+            PrefixLength::new(length)?,
+        ))
     }
 
-    /// returns the prefix for the given address combined with the given mask. The mask must be an
-    /// instance of [`Address`] where anywhere from 0 to 32 left-most bits are all 1s followed by
-    /// all 0s on the right. If the mask is invalid, [`Error::InvalidMask`] is returned.
+    /// parses a prefix from a string using the strict [`std::str::FromStr`] implementation of the
+    /// type. Any failure is returned as an [`Error::ParseError`] wrapping the original error so
+    /// that callers don't need to deal with each implementation's error type. Since every
+    /// [`Address`] is also a Prefix, this works to parse addresses too.
+    ///
+    /// In addition to whatever the type accepts, the `address/mask` notation, e.g.
+    /// `10.0.0.0/255.255.255.0`, is accepted for every type. It is parsed as if the equivalent
+    /// length had been given so it is rejected wherever the length would be. If the mask is
+    /// invalid, [`Error::InvalidMask`] is returned.
     ///
     /// # Example
     /// ```
     /// # use addrs::ipv4::Prefix;
     /// # use std::net::Ipv4Addr;
-    /// let ip = Ipv4Addr::new(8,7,6,5);
-    /// let mask = Ipv4Addr::new(255,255,252,0);
-    /// let prefix: ipnet::Ipv4Net = Prefix::from_address_mask(ip, mask).unwrap();
-    /// assert_eq!("8.7.6.5/22", prefix.to_string());
-    fn from_address_mask(ip: Self::Address, mask: Self::Address) -> Result<Self> {
-        let mask: u32 = mask.into();
-        let length = mask.leading_ones() as u8;
-        match length + mask.trailing_zeros() as u8 == Self::Address::BITS {
-            true => Ok(unsafe { Self::unsafe_new(ip, length) }),
-            false => Err(Error::InvalidMask),
+    /// let prefix = ipnet::Ipv4Net::parse("1.2.3.4/24").unwrap();
+    /// assert_eq!(24, prefix.length());
+    ///
+    /// let address = Ipv4Addr::parse("1.2.3.4").unwrap();
+    /// assert_eq!(Ipv4Addr::new(1, 2, 3, 4), address);
+    ///
+    /// assert!(ipnet::Ipv4Net::parse("1.2.3.4/33").is_err());
+    ///
+    /// let prefix = ipnet::Ipv4Net::parse("1.2.3.4/255.255.255.0").unwrap();
+    /// assert_eq!("1.2.3.4/24", prefix.to_string());
+    /// ```
+    fn parse(s: &str) -> Result<Self>
+    where
+        <Self as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static,
+    {
+        match s.split_once('/') {
+            Some((address, mask)) if mask.contains('.') => {
+                let length = mask.parse::<Netmask>()?.length();
+                format!("{address}/{length}")
+                    .parse()
+                    .map_err(Error::parse_error)
+            }
+            _ => s.parse().map_err(Error::parse_error),
         }
     }
 
-    /// returns a new Address with `1s` in the first `length` bits and then `0s` representing the
-    /// network mask for this prefix
+    /// parses a prefix from the `address wildcard` notation found in Cisco ACLs, e.g. `10.0.0.0
+    /// 0.0.0.255`. The address and wildcard may be separated by any amount of whitespace. The
+    /// `any` and `host address` keywords are accepted too. If the wildcard isn't contiguous,
+    /// [`Error::InvalidMask`] is returned.
     ///
     /// # Example
     /// ```
     /// # use addrs::ipv4::Prefix;
-    /// let prefix = "1.2.3.4/26".parse::<ipnet::Ipv4Net>().unwrap();
-    /// assert_eq!("255.255.255.192", prefix.mask().to_string());
+    /// let prefix = ipnet::Ipv4Net::parse_wildcard("10.0.0.0 0.0.0.255").unwrap();
+    /// assert_eq!("10.0.0.0/24", prefix.to_string());
+    ///
+    /// let prefix = ipnet::Ipv4Net::parse_wildcard("host 10.0.0.1").unwrap();
+    /// assert_eq!("10.0.0.1/32", prefix.to_string());
+    ///
+    /// assert!(ipnet::Ipv4Net::parse_wildcard("10.0.0.0 0.0.255.0").is_err());
     /// ```
-    fn mask(&self) -> Self::Address {
-        match self.length() {
-            0 => 0,
-            s => 0xffffffff << Self::Address::BITS - s,
-        }
-        .into()
+    fn parse_wildcard(s: &str) -> Result<Self> {
+        let mut words = s.split_whitespace();
+        let (address, wildcard) = match (words.next(), words.next(), words.next()) {
+            (Some("any"), None, None) => ("0.0.0.0", Wildcard::from_length(0)?),
+            (Some("host"), Some(address), None) => (address, Wildcard::from_length(32)?),
+            (Some(address), Some(wildcard), None) => (address, wildcard.parse()?),
+            _ => return Err(Error::ParseError(None)),
+        };
+        let address = address
+            .parse::<Self::Address>()
+            .or(Err(Error::ParseError(None)))?;
+        Ok(Self::from_address_wildcard(address, wildcard))
     }
 
-    /// returns a new Prefix with the host bits zeroed out so that only the bits in the `network`
-    /// part of the prefix can be non-zero. Note that this method ignores special cases where a
-    /// network address might not make sense like in a host route or point-to-point prefix (/32 and
-    /// /31). It just does the math.
+    /// parses the glob notation taken by scanners like nmap where trailing octets are given as
+    /// `*`, e.g. `10.0.*.*`. The last octet that isn't a `*` may also be a range as long as the
+    /// addresses matched make up a single prefix, e.g. `10.0.0-3.*`. Otherwise,
+    /// [`Error::ParseError`] is returned; use [`AddressRange::parse_glob`] to accept any glob.
     ///
     /// # Example
     /// ```
     /// # use addrs::ipv4::Prefix;
-    /// let prefix = "1.2.3.234/26".parse::<ipnet::Ipv4Net>().unwrap();
-    /// assert_eq!("1.2.3.192", Prefix::network(&prefix).to_string());
+    /// let prefix = ipnet::Ipv4Net::parse_glob("10.0.*.*").unwrap();
+    /// assert_eq!("10.0.0.0/16", prefix.to_string());
+    /// let prefix = ipnet::Ipv4Net::parse_glob("10.0.0-3.*").unwrap();
+    /// assert_eq!("10.0.0.0/22", prefix.to_string());
+    /// assert!(ipnet::Ipv4Net::parse_glob("10.0.1-3.*").is_err());
     /// ```
-    fn network(&self) -> Self::Address {
-        self.address() & self.mask()
+    fn parse_glob(s: &str) -> Result<Self> {
+        let (first, last) = address_range::parse_glob(s)?;
+        let size = u64::from(last - first) + 1;
+        match size.is_power_of_two() && u64::from(first) % size == 0 {
+            true => Self::new(first.into(), 32 - size.trailing_zeros() as u8),
+            false => Err(Error::ParseError(None)),
+        }
     }
 
-    /// returns a new Prefix with the network bits zeroed out so that only the bits in the
-    /// `host` part of the prefix can be non-zero.
+    /// parses a prefix in CIDR notation from the front of the given bytes and returns it along
+    /// with the number of bytes consumed, like [`Address::parse_partial`] does for addresses. If
+    /// the length is greater than 32 then [`Error::InvalidLength`] is returned.
     ///
     /// # Example
     /// ```
     /// # use addrs::ipv4::Prefix;
-    /// fn check<P: Prefix>(prefix: P) {
-    /// }
-    ///
-    /// let prefix = "1.2.3.234/26".parse::<ipnet::Ipv4Net>().unwrap();
-    /// assert_eq!(42u32, prefix.host().into());
+    /// let (prefix, consumed) = ipnet::Ipv4Net::parse_cidr_partial(b"10.0.0.0/8 via eth0").unwrap();
+    /// assert_eq!(("10.0.0.0/8", 10), (prefix.to_string().as_str(), consumed));
     /// ```
-    fn host(&self) -> Self::Address {
-        self.address() & !self.mask()
+    fn parse_cidr_partial(bytes: &[u8]) -> Result<(Self, usize)> {
+        let (address, consumed) = Self::Address::parse_partial(bytes)?;
+        let error =
+            |kind, offset| Error::parse_error_at(kind, offset, &String::from_utf8_lossy(bytes));
+        if bytes.get(consumed) != Some(&b'/') {
+            return Err(error(crate::ParseErrorKind::ExpectedSlash, consumed));
+        }
+        let start = consumed + 1;
+        let digits = bytes[start..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if digits == 0 {
+            return Err(error(crate::ParseErrorKind::BadLength, start));
+        }
+        // saturates so that any number too big to be a length is reported as one
+        let length = bytes[start..start + digits].iter().fold(0u32, |n, d| {
+            n.saturating_mul(10).saturating_add((d - b'0').into())
+        });
+        let length = u8::try_from(length).map_err(|_| Error::InvalidLength)?;
+        Ok((Self::new(address, length)?, start + digits))
     }
 
-    /// returns a new Prefix with all the host bits set to 1s. Note that this method ignores
-    /// special cases where a broadcast address might not make sense like in a host route or
-    /// point-to-point prefix (/32 and /31). It just does the math.
+    /// returns the prefix for the given address combined with the given prefix length. If the
+    /// length is greater than 32 then [`Error::InvalidLength`] is returned.
     ///
     /// # Example
     /// ```
     /// # use addrs::ipv4::Prefix;
-    /// let prefix = "1.2.3.1/24".parse::<ipnet::Ipv4Net>().unwrap();
-    /// assert_eq!("1.2.3.255", Prefix::broadcast(&prefix).to_string());
+    /// # use std::net::Ipv4Addr;
+    /// let ip = Ipv4Addr::new(1,2,3,4);
+    /// let prefix: ipnet::Ipv4Net = Prefix::from_address_length(ip, 25).unwrap();
+    /// assert_eq!("1.2.3.4/25", prefix.to_string());
     /// ```
-    fn broadcast(&self) -> Self::Address {
-        self.address() | !self.mask()
+    fn from_address_length(ip: Self::Address, length: u8) -> Result<Self> {
+        Self::new(ip, length)
     }
 
-    /// returns two prefixes that partition this prefix into two equal halves. If the prefix is a
-    /// host route (/32), then None is returned.
+    /// returns the prefix for the given address combined with the given prefix length. Since a
+    /// [`PrefixLength`] is always valid, this cannot fail.
     ///
     /// # Example
     /// ```
-    /// # use addrs::ipv4::Prefix;
-    /// let prefix: ipnet::Ipv4Net = "1.2.3.0/24".parse().unwrap();
-    /// let (a, b) = prefix.halves().unwrap();
-    /// assert_eq!("1.2.3.0/25", a.to_string());
-    /// assert_eq!("1.2.3.128/25", b.to_string());
+    /// # use addrs::ipv4::{Prefix, PrefixLength};
+    /// # use std::net::Ipv4Addr;
+    /// let ip = Ipv4Addr::new(1, 2, 3, 4);
+    /// let prefix: ipnet::Ipv4Net = Prefix::from_address_prefix_length(ip, PrefixLength::of::<25>());
+    /// assert_eq!("1.2.3.4/25", prefix.to_string());
     /// ```
-    fn halves(&self) -> Option<(Self, Self)> {
-        match self.length() {
+    fn from_address_prefix_length(ip: Self::Address, length: PrefixLength) -> Self {
+        unsafe { Self::new_unchecked(ip, length.get()) }
+    }
+
+    /// returns the prefix length as a [`PrefixLength`]
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Prefix, PrefixLength};
+    /// let prefix = "1.2.3.4/23".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!(PrefixLength::of::<23>(), prefix.prefix_length());
+    /// ```
+    fn prefix_length(&self) -> PrefixLength {
+        PrefixLength::new(self.length()).expect("prefix length is at most 32")
+    }
+
+    /// returns the prefix for the given address combined with the given mask. The mask must be an
+    /// instance of [`Address`] where anywhere from 0 to 32 left-most bits are all 1s followed by
+    /// all 0s on the right. If the mask is invalid, [`Error::InvalidMask`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// # use std::net::Ipv4Addr;
+    /// let ip = Ipv4Addr::new(8,7,6,5);
+    /// let mask = Ipv4Addr::new(255,255,252,0);
+    /// let prefix: ipnet::Ipv4Net = Prefix::from_address_mask(ip, mask).unwrap();
+    /// assert_eq!("8.7.6.5/22", prefix.to_string());
+    /// ```
+    fn from_address_mask(ip: Self::Address, mask: Self::Address) -> Result<Self> {
+        Ok(Self::from_address_netmask(ip, Netmask::new(mask)?))
+    }
+
+    /// returns the prefix for the given address combined with the given netmask. Since a
+    /// [`Netmask`] is always valid, this cannot fail.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Netmask, Prefix};
+    /// # use std::net::Ipv4Addr;
+    /// let ip = Ipv4Addr::new(8, 7, 6, 5);
+    /// let mask: Netmask = "255.255.252.0".parse().unwrap();
+    /// let prefix: ipnet::Ipv4Net = Prefix::from_address_netmask(ip, mask);
+    /// assert_eq!("8.7.6.5/22", prefix.to_string());
+    /// ```
+    fn from_address_netmask(ip: Self::Address, mask: Netmask) -> Self {
+        unsafe { Self::new_unchecked(ip, mask.length()) }
+    }
+
+    /// returns the prefix for the given address combined with the given wildcard, as found in
+    /// Cisco ACL configurations. Since a [`Wildcard`] is always valid, this cannot fail.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Prefix, Wildcard};
+    /// # use std::net::Ipv4Addr;
+    /// let ip = Ipv4Addr::new(10, 0, 0, 0);
+    /// let wildcard: Wildcard = "0.0.0.255".parse().unwrap();
+    /// let prefix: ipnet::Ipv4Net = Prefix::from_address_wildcard(ip, wildcard);
+    /// assert_eq!("10.0.0.0/24", prefix.to_string());
+    /// ```
+    fn from_address_wildcard(ip: Self::Address, wildcard: Wildcard) -> Self {
+        unsafe { Self::new_unchecked(ip, wildcard.length()) }
+    }
+
+    /// returns a new Address with `1s` in the first `length` bits and then `0s` representing the
+    /// network mask for this prefix
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let prefix = "1.2.3.4/26".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!("255.255.255.192", prefix.mask().to_string());
+    /// ```
+    fn mask(&self) -> Self::Address {
+        MASKS[self.length() as usize].into()
+    }
+
+    /// returns a new Prefix with the host bits zeroed out so that only the bits in the `network`
+    /// part of the prefix can be non-zero. Note that this method ignores special cases where a
+    /// network address might not make sense like in a host route or point-to-point prefix (/32 and
+    /// /31). It just does the math.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let prefix = "1.2.3.234/26".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!("1.2.3.192", Prefix::network(&prefix).to_string());
+    /// ```
+    fn network(&self) -> Self::Address {
+        self.address() & self.mask()
+    }
+
+    /// returns a new Prefix with the network bits zeroed out so that only the bits in the
+    /// `host` part of the prefix can be non-zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// fn check<P: Prefix>(prefix: P) {
+    /// }
+    ///
+    /// let prefix = "1.2.3.234/26".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!(42u32, prefix.host().into());
+    /// ```
+    fn host(&self) -> Self::Address {
+        self.address() & !self.mask()
+    }
+
+    /// returns a new Prefix with all the host bits set to 1s. Note that this method ignores
+    /// special cases where a broadcast address might not make sense like in a host route or
+    /// point-to-point prefix (/32 and /31). It just does the math.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let prefix = "1.2.3.1/24".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!("1.2.3.255", Prefix::broadcast(&prefix).to_string());
+    /// ```
+    fn broadcast(&self) -> Self::Address {
+        self.address() | !self.mask()
+    }
+
+    /// returns true if all of the host bits of the address are zero, i.e. the address is the
+    /// network address of the prefix. Many configurations reject prefixes that aren't.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// assert!("10.0.0.0/24".parse::<ipnet::Ipv4Net>().unwrap().is_aligned());
+    /// assert!(!"10.0.0.1/24".parse::<ipnet::Ipv4Net>().unwrap().is_aligned());
+    /// ```
+    fn is_aligned(&self) -> bool {
+        self.address() == self.network()
+    }
+
+    /// returns a new Prefix of the same length with the host bits of the address cleared, so
+    /// that it is aligned
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let prefix = "10.0.0.1/24".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!("10.0.0.0/24", prefix.canonicalize().to_string());
+    /// ```
+    fn canonicalize(&self) -> Self {
+        unsafe { Self::new_unchecked(self.network(), self.length()) }
+    }
+
+    /// returns the first address in the prefix that can be assigned to a host. That is the one
+    /// after the network address except in a /31, where both addresses are usable, and a /32,
+    /// where the only address is both the first and last host.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Pfx, Prefix};
+    /// let prefix: Pfx = "10.0.0.0/24".parse().unwrap();
+    /// assert_eq!(Addr::new(10, 0, 0, 1), prefix.first_host());
+    /// let prefix: Pfx = "10.0.0.0/31".parse().unwrap();
+    /// assert_eq!(Addr::new(10, 0, 0, 0), prefix.first_host());
+    /// ```
+    fn first_host(&self) -> Self::Address {
+        let network: UI = self.network().into();
+        match self.length() {
+            31.. => network.into(),
+            _ => (network + 1).into(),
+        }
+    }
+
+    /// returns the last address in the prefix that can be assigned to a host. That is the one
+    /// before the broadcast address except in a /31, where both addresses are usable, and a /32,
+    /// where the only address is both the first and last host.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Pfx, Prefix};
+    /// let prefix: Pfx = "10.0.0.0/24".parse().unwrap();
+    /// assert_eq!(Addr::new(10, 0, 0, 254), prefix.last_host());
+    /// let prefix: Pfx = "10.0.0.0/31".parse().unwrap();
+    /// assert_eq!(Addr::new(10, 0, 0, 1), prefix.last_host());
+    /// ```
+    fn last_host(&self) -> Self::Address {
+        let broadcast: UI = self.broadcast().into();
+        match self.length() {
+            31.. => broadcast.into(),
+            _ => (broadcast - 1).into(),
+        }
+    }
+
+    /// returns the number of addresses in the prefix that can be assigned to hosts, the number
+    /// that [`Prefix::hosts`] yields. Unlike [`Set::num_addresses`], every prefix, even a /0, has
+    /// few enough hosts to count.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// assert_eq!(254, "10.0.0.0/24".parse::<Pfx>().unwrap().num_hosts());
+    /// assert_eq!(2, "10.0.0.0/31".parse::<Pfx>().unwrap().num_hosts());
+    /// assert_eq!(1, "10.0.0.0/32".parse::<Pfx>().unwrap().num_hosts());
+    /// ```
+    fn num_hosts(&self) -> u32 {
+        let first: UI = self.first_host().into();
+        let last: UI = self.last_host().into();
+        last - first + 1
+    }
+
+    /// returns an iterator, in order, over the addresses in the prefix that can be assigned to
+    /// hosts, from [`Prefix::first_host`] to [`Prefix::last_host`]. The network and broadcast
+    /// addresses are skipped except for /31 point-to-point links, where both addresses are usable
+    /// as described in [RFC 3021], and /32s, which hold a single host.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Pfx, Prefix};
+    /// let prefix: Pfx = "10.0.0.0/30".parse().unwrap();
+    /// let hosts: Vec<Addr> = prefix.hosts().collect();
+    /// assert_eq!(vec![Addr::new(10, 0, 0, 1), Addr::new(10, 0, 0, 2)], hosts);
+    ///
+    /// let prefix: Pfx = "10.0.0.0/31".parse().unwrap();
+    /// let hosts: Vec<Addr> = prefix.hosts().collect();
+    /// assert_eq!(vec![Addr::new(10, 0, 0, 0), Addr::new(10, 0, 0, 1)], hosts);
+    /// ```
+    ///
+    /// [RFC 3021]: https://www.rfc-editor.org/rfc/rfc3021
+    fn hosts(&self) -> impl Iterator<Item = Self::Address> {
+        let first: UI = self.first_host().into();
+        let last: UI = self.last_host().into();
+        (first..=last).map(Self::Address::from)
+    }
+
+    /// returns an iterator, in order, over every address in the prefix including the network and
+    /// broadcast addresses. Use [`Prefix::hosts`] to skip those.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Pfx, Prefix};
+    /// let prefix: Pfx = "10.0.0.1/31".parse().unwrap();
+    /// let addresses: Vec<Addr> = prefix.addresses().collect();
+    /// assert_eq!(vec![Addr::new(10, 0, 0, 0), Addr::new(10, 0, 0, 1)], addresses);
+    /// ```
+    fn addresses(&self) -> Addresses<Self::Address> {
+        Addresses::new(self.network(), self.broadcast())
+    }
+
+    /// returns an iterator over every address in the prefix, each exactly once, in a pseudorandom
+    /// order. The same seed always gives the same order. Scanners can use this to spread their
+    /// probes across the prefix without keeping track of the addresses already visited.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// let prefix: Pfx = "10.0.0.0/24".parse().unwrap();
+    /// let first: Vec<_> = prefix.shuffled_addresses(7).take(4).collect();
+    /// assert_eq!(first, prefix.shuffled_addresses(7).take(4).collect::<Vec<_>>());
+    /// assert_eq!(256, prefix.shuffled_addresses(7).count());
+    /// ```
+    fn shuffled_addresses(&self, seed: u64) -> Shuffled<Self::Address> {
+        Shuffled::new(self.network(), self.length(), seed)
+    }
+
+    /// returns true if the prefix is wholly contained in the private address space, `10.0.0.0/8`,
+    /// `172.16.0.0/12` or `192.168.0.0/16`, from [RFC 1918]
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// assert!("172.16.5.0/24".parse::<Pfx>().unwrap().is_private());
+    /// assert!(!"172.15.0.0/16".parse::<Pfx>().unwrap().is_private());
+    /// ```
+    ///
+    /// [RFC 1918]: https://www.rfc-editor.org/rfc/rfc1918
+    fn is_private(&self) -> bool {
+        special::contains(
+            sets::PRIVATE.as_slice(),
+            self.network().into(),
+            self.length(),
+        )
+    }
+
+    /// returns true if the prefix is wholly contained in the loopback block, `127.0.0.0/8`
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// assert!("127.0.0.1/32".parse::<Pfx>().unwrap().is_loopback());
+    /// assert!(!"126.0.0.0/7".parse::<Pfx>().unwrap().is_loopback());
+    /// ```
+    fn is_loopback(&self) -> bool {
+        special::contains(
+            sets::LOOPBACK.as_slice(),
+            self.network().into(),
+            self.length(),
+        )
+    }
+
+    /// returns true if the prefix is wholly contained in the link-local block, `169.254.0.0/16`
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// assert!("169.254.10.0/24".parse::<Pfx>().unwrap().is_link_local());
+    /// assert!(!"169.255.0.0/16".parse::<Pfx>().unwrap().is_link_local());
+    /// ```
+    fn is_link_local(&self) -> bool {
+        special::contains(
+            sets::LINK_LOCAL.as_slice(),
+            self.network().into(),
+            self.length(),
+        )
+    }
+
+    /// returns true if the prefix is wholly contained in the shared address space for carrier-grade
+    /// NAT, `100.64.0.0/10`, from [RFC 6598]
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// assert!("100.100.0.0/16".parse::<Pfx>().unwrap().is_shared());
+    /// assert!(!"100.0.0.0/8".parse::<Pfx>().unwrap().is_shared());
+    /// ```
+    ///
+    /// [RFC 6598]: https://www.rfc-editor.org/rfc/rfc6598
+    fn is_shared(&self) -> bool {
+        special::contains(
+            sets::SHARED.as_slice(),
+            self.network().into(),
+            self.length(),
+        )
+    }
+
+    /// returns true if the prefix is wholly contained in one of the blocks reserved for
+    /// documentation, `192.0.2.0/24`, `198.51.100.0/24` or `203.0.113.0/24`, from [RFC 5737]
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// assert!("198.51.100.0/25".parse::<Pfx>().unwrap().is_documentation());
+    /// assert!(!"198.51.0.0/16".parse::<Pfx>().unwrap().is_documentation());
+    /// ```
+    ///
+    /// [RFC 5737]: https://www.rfc-editor.org/rfc/rfc5737
+    fn is_documentation(&self) -> bool {
+        special::contains(
+            sets::DOCUMENTATION.as_slice(),
+            self.network().into(),
+            self.length(),
+        )
+    }
+
+    /// returns true if the prefix is wholly contained in the block reserved for benchmarking,
+    /// `198.18.0.0/15`, from [RFC 2544]
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// assert!("198.19.0.0/16".parse::<Pfx>().unwrap().is_benchmarking());
+    /// assert!(!"198.16.0.0/14".parse::<Pfx>().unwrap().is_benchmarking());
+    /// ```
+    ///
+    /// [RFC 2544]: https://www.rfc-editor.org/rfc/rfc2544
+    fn is_benchmarking(&self) -> bool {
+        special::contains(
+            sets::BENCHMARKING.as_slice(),
+            self.network().into(),
+            self.length(),
+        )
+    }
+
+    /// returns true if the prefix is wholly contained in the multicast block, `224.0.0.0/4`
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// assert!("239.255.0.0/16".parse::<Pfx>().unwrap().is_multicast());
+    /// assert!(!"224.0.0.0/3".parse::<Pfx>().unwrap().is_multicast());
+    /// ```
+    fn is_multicast(&self) -> bool {
+        special::contains(
+            sets::MULTICAST.as_slice(),
+            self.network().into(),
+            self.length(),
+        )
+    }
+
+    /// returns true if the prefix is wholly contained in the block reserved for future use,
+    /// `240.0.0.0/4`, which includes the limited broadcast address
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// assert!("255.255.255.255/32".parse::<Pfx>().unwrap().is_reserved());
+    /// assert!(!"192.0.0.0/2".parse::<Pfx>().unwrap().is_reserved());
+    /// ```
+    fn is_reserved(&self) -> bool {
+        special::contains(
+            sets::RESERVED.as_slice(),
+            self.network().into(),
+            self.length(),
+        )
+    }
+
+    /// returns true if every address in the prefix is globally reachable according to the IANA
+    /// special-purpose address registry. It agrees with [`std::net::Ipv4Addr::is_global`] for
+    /// addresses: private, shared, loopback, link-local, documentation, benchmarking and reserved
+    /// addresses, as well as `0.0.0.0/8` and most of `192.0.0.0/24`, are not global while
+    /// multicast addresses are.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Pfx, Prefix};
+    /// assert!("8.8.8.0/24".parse::<Pfx>().unwrap().is_global());
+    /// assert!(!"10.0.0.0/24".parse::<Pfx>().unwrap().is_global());
+    /// // part of it is private
+    /// assert!(!"8.0.0.0/4".parse::<Pfx>().unwrap().is_global());
+    /// assert!(Addr::new(192, 0, 0, 9).is_global());
+    /// ```
+    fn is_global(&self) -> bool {
+        let network: UI = self.network().into();
+        special::contains(special::GLOBAL, network, self.length())
+            || !special::overlaps(special::not_global(), network, self.length())
+    }
+
+    /// returns both ends of a point-to-point link if this prefix is a /31. As described in [RFC
+    /// 3021], a /31 has no network or broadcast address so both of its addresses are usable. The
+    /// lower address is returned first. For any other length, None is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let prefix = "10.0.0.7/31".parse::<ipnet::Ipv4Net>().unwrap();
+    /// let (a, b) = prefix.p2p_pair().unwrap();
+    /// assert_eq!("10.0.0.6", a.to_string());
+    /// assert_eq!("10.0.0.7", b.to_string());
+    ///
+    /// let prefix = "10.0.0.4/30".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!(None, prefix.p2p_pair());
+    /// ```
+    ///
+    /// [RFC 3021]: https://www.rfc-editor.org/rfc/rfc3021
+    fn p2p_pair(&self) -> Option<(Self::Address, Self::Address)> {
+        match self.length() {
+            length if length == Self::Address::BITS - 1 => Some((self.network(), self.broadcast())),
+            _ => None,
+        }
+    }
+
+    /// returns two prefixes that partition this prefix into two equal halves. If the prefix is a
+    /// host route (/32), then None is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let prefix: ipnet::Ipv4Net = "1.2.3.0/24".parse().unwrap();
+    /// let (a, b) = prefix.halves().unwrap();
+    /// assert_eq!("1.2.3.0/25", a.to_string());
+    /// assert_eq!("1.2.3.128/25", b.to_string());
+    /// ```
+    fn halves(&self) -> Option<(Self, Self)> {
+        match self.length() {
             length if length < Self::Address::BITS => {
                 let left: u32 = self.network().address().into();
                 let right = left | (0x80000000 >> length);
                 Some((
-                    unsafe { Self::unsafe_new(left.into(), length + 1) },
-                    unsafe { Self::unsafe_new(right.into(), length + 1) },
+                    unsafe { Self::new_unchecked(left.into(), length + 1) },
+                    unsafe { Self::new_unchecked(right.into(), length + 1) },
                 ))
             }
             _ => None,
         }
     }
 
+    /// returns the prefix one bit shorter that contains this one, the inverse of
+    /// [`Prefix::halves`]. For /0, there is no parent and [`None`] is returned. The same goes for
+    /// address types, which can't represent anything shorter than a /32.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// let prefix: Pfx = "1.2.3.128/25".parse().unwrap();
+    /// assert_eq!("1.2.3.0/24", prefix.parent().unwrap().to_string());
+    /// ```
+    fn parent(&self) -> Option<Self> {
+        self.supernet(1)
+    }
+
+    /// returns the prefix that contains this one and is shorter by the given number of bits.
+    /// Going up more levels than the length of this prefix returns [`None`], as does any supernet
+    /// of an address type since it can only represent a /32.
+    ///
+    /// Note that [`ipnet::Ipv4Net`] has its own `supernet` method without an argument which
+    /// takes precedence; call this one as `Prefix::supernet(&net, levels)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// let prefix: Pfx = "1.2.3.128/25".parse().unwrap();
+    /// assert_eq!("1.2.0.0/22", prefix.supernet(3).unwrap().to_string());
+    /// assert_eq!("0.0.0.0/0", prefix.supernet(25).unwrap().to_string());
+    /// assert!(prefix.supernet(26).is_none());
+    /// ```
+    fn supernet(&self, levels: u8) -> Option<Self> {
+        let length = self.length().checked_sub(levels)?;
+        let ui: UI = self.address().into();
+        let network = ui & MASKS[length as usize];
+        let supernet = unsafe { Self::new_unchecked(network.into(), length) };
+        match supernet.length() == length {
+            true => Some(supernet),
+            false => None,
+        }
+    }
+
+    /// returns the adjacent prefix of the same length that follows this one, or [`None`] if this
+    /// prefix is at the end of the address space
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// let prefix: Pfx = "10.0.0.0/24".parse().unwrap();
+    /// assert_eq!("10.0.1.0/24", prefix.next().unwrap().to_string());
+    /// assert!("255.255.255.0/24".parse::<Pfx>().unwrap().next().is_none());
+    /// ```
+    fn next(&self) -> Option<Self> {
+        let broadcast: UI = self.broadcast().into();
+        let network = broadcast.checked_add(1)?;
+        Some(unsafe { Self::new_unchecked(network.into(), self.length()) })
+    }
+
+    /// returns the adjacent prefix of the same length that precedes this one, or [`None`] if this
+    /// prefix is at the start of the address space
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// let prefix: Pfx = "10.0.1.0/24".parse().unwrap();
+    /// assert_eq!("10.0.0.0/24", prefix.prev().unwrap().to_string());
+    /// assert!("0.0.0.0/24".parse::<Pfx>().unwrap().prev().is_none());
+    /// ```
+    fn prev(&self) -> Option<Self> {
+        let network: UI = self.network().into();
+        let previous = network.checked_sub(1)? & MASKS[self.length() as usize];
+        Some(unsafe { Self::new_unchecked(previous.into(), self.length()) })
+    }
+
+    /// returns true if the other prefix is this prefix's sibling: the two have the same length,
+    /// don't overlap and together make up their parent, so they can be merged into it
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// let p = |s: &str| s.parse::<Pfx>().unwrap();
+    /// assert!(p("10.0.0.0/25").is_adjacent_to(&p("10.0.0.128/25")));
+    /// // these touch but would not make a prefix together
+    /// assert!(!p("10.0.0.128/25").is_adjacent_to(&p("10.0.1.0/25")));
+    /// ```
+    fn is_adjacent_to<P: Prefix<Address = Self::Address>>(&self, other: &P) -> bool {
+        let a: UI = self.network().into();
+        let b: UI = other.network().into();
+        self.length() == other.length()
+            && self.length() > 0
+            && a ^ b == 1 << (32 - u32::from(self.length()))
+    }
+
+    /// returns the parent of this prefix and its sibling, the fundamental step of aggregation. If
+    /// the other prefix isn't this prefix's sibling (see [`Prefix::is_adjacent_to`]), or this is
+    /// an address type that can't hold the merged length, [`None`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// let p = |s: &str| s.parse::<Pfx>().unwrap();
+    /// assert_eq!(Some(p("10.0.0.0/24")), p("10.0.0.128/25").merge(&p("10.0.0.0/25")));
+    /// assert_eq!(None, p("10.0.0.0/25").merge(&p("10.0.0.0/25")));
+    /// ```
+    fn merge<P: Prefix<Address = Self::Address>>(&self, other: &P) -> Option<Self> {
+        match self.is_adjacent_to(other) {
+            true => self.parent(),
+            false => None,
+        }
+    }
+
+    /// returns an iterator over every prefix that contains this one, from its own network at its
+    /// own length down to /0, as used for longest prefix match fallbacks and hierarchical
+    /// lookups. To stop at a given length, use [`Iterator::take_while`]. An address type only
+    /// yields itself since it can't represent anything shorter.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// let prefix: Pfx = "10.1.2.3/24".parse().unwrap();
+    /// let ancestors: Vec<String> = prefix
+    ///     .ancestors()
+    ///     .take_while(|p| p.length() >= 22)
+    ///     .map(|p| p.to_string())
+    ///     .collect();
+    /// assert_eq!(vec!["10.1.2.0/24", "10.1.2.0/23", "10.1.0.0/22"], ancestors);
+    /// assert_eq!(25, prefix.ancestors().count());
+    /// ```
+    fn ancestors(&self) -> impl Iterator<Item = Self> {
+        let ui: UI = self.address().into();
+        (0..=self.length()).rev().map_while(move |length| {
+            let network = ui & MASKS[length as usize];
+            let ancestor = unsafe { Self::new_unchecked(network.into(), length) };
+            match ancestor.length() == length {
+                true => Some(ancestor),
+                false => None,
+            }
+        })
+    }
+
+    /// returns an iterator, in order, over all of the subnets of the given length that partition
+    /// this prefix, e.g. the four /26s inside a /24. If the length is shorter than this prefix's
+    /// length or greater than 32, [`Error::InvalidLength`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let prefix: ipnet::Ipv4Net = "1.2.3.0/24".parse().unwrap();
+    /// let subnets: Vec<ipnet::Ipv4Net> = prefix.subnets(26).unwrap().collect();
+    /// assert_eq!(4, subnets.len());
+    /// assert_eq!("1.2.3.192/26", subnets[3].to_string());
+    /// ```
+    fn subnets(&self, length: u8) -> Result<impl Iterator<Item = Self>> {
+        if length < self.length() || Self::Address::BITS < length {
+            return Err(Error::InvalidLength);
+        }
+        Ok(self.subnets_of(PrefixLength::new(length)?))
+    }
+
+    /// returns an iterator, in order, over all of the subnets of the given, already validated,
+    /// length that partition this prefix. Like [`Prefix::subnets`] but it can't fail; if the
+    /// length is shorter than this prefix's length then there are no subnets of that length.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Prefix, PrefixLength};
+    /// let prefix: ipnet::Ipv4Net = "1.2.3.0/24".parse().unwrap();
+    /// let subnets: Vec<ipnet::Ipv4Net> = prefix.subnets_of(PrefixLength::of::<26>()).collect();
+    /// assert_eq!(4, subnets.len());
+    /// assert_eq!(0, prefix.subnets_of(PrefixLength::of::<23>()).count());
+    /// ```
+    fn subnets_of(&self, length: PrefixLength) -> impl Iterator<Item = Self> {
+        let length = length.get();
+        let first: UI = self.network().into();
+        let shift = 32 - u32::from(length);
+        let count = match self.length() <= length {
+            true => 1u64 << (length - self.length()),
+            false => 0,
+        };
+        (0..count).map(move |i| {
+            let network = (u64::from(first) + (i << shift)) as UI;
+            unsafe { Self::new_unchecked(network.into(), length) }
+        })
+    }
+
+    /// returns the subnet of the given length at the given index inside this prefix, counting
+    /// from zero, without iterating like [`Prefix::subnets`] would. If the length is shorter than
+    /// this prefix's length or greater than 32, [`Error::InvalidLength`] is returned. If the index
+    /// is past the last subnet, [`Error::NotContained`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let prefix: ipnet::Ipv4Net = "10.0.0.0/16".parse().unwrap();
+    /// assert_eq!("10.0.7.0/24", prefix.nth_subnet(7, 24).unwrap().to_string());
+    /// assert!(prefix.nth_subnet(256, 24).is_err());
+    /// ```
+    fn nth_subnet(&self, index: u64, length: u8) -> Result<Self> {
+        if length < self.length() || Self::Address::BITS < length {
+            return Err(Error::InvalidLength);
+        }
+        if index >= 1u64 << (length - self.length()) {
+            return Err(Error::NotContained);
+        }
+        let first: UI = self.network().into();
+        let network = (u64::from(first) + (index << (32 - u32::from(length)))) as UI;
+        Ok(unsafe { Self::new_unchecked(network.into(), length) })
+    }
+
+    /// returns the address at the given offset from the start of the prefix, e.g. offset 10 of
+    /// `192.168.1.0/24` is `192.168.1.10`. If the offset is past the end of the prefix,
+    /// [`Error::NotContained`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// # use std::net::Ipv4Addr;
+    /// let prefix: ipnet::Ipv4Net = "192.168.1.0/24".parse().unwrap();
+    /// assert_eq!(Ipv4Addr::new(192, 168, 1, 10), prefix.address_at(10).unwrap());
+    /// assert!(prefix.address_at(256).is_err());
+    /// ```
+    fn address_at(&self, offset: u32) -> Result<Self::Address> {
+        let host_bits = 32 - u32::from(self.length());
+        match u64::from(offset) < 1u64 << host_bits {
+            true => {
+                let network: UI = self.network().into();
+                Ok((network | offset).into())
+            }
+            false => Err(Error::NotContained),
+        }
+    }
+
+    /// returns the offset of the given address from the start of the prefix, the inverse of
+    /// [`Prefix::address_at`], or [`None`] if the prefix doesn't contain it. This maps the
+    /// addresses of a prefix to slots in an array or bits in a bitmap.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Pfx, Prefix};
+    /// let prefix: Pfx = "192.168.1.0/24".parse().unwrap();
+    /// assert_eq!(Some(10), prefix.position_of(&Addr::new(192, 168, 1, 10)));
+    /// assert_eq!(None, prefix.position_of(&Addr::new(192, 168, 2, 10)));
+    /// ```
+    fn position_of(&self, address: &Self::Address) -> Option<u32> {
+        let ui: UI = (*address).into();
+        let network: UI = self.network().into();
+        match ui & self.mask().into() == network {
+            true => Some(ui - network),
+            false => None,
+        }
+    }
+
+    /// returns the prefixes left over when the given child is carved out of this prefix. These
+    /// are the siblings of the child and of each of its ancestors up to, but not including, this
+    /// prefix. They are returned in order and, together with the child, exactly partition this
+    /// prefix. If the child is this prefix, nothing is left over. If the child isn't contained in
+    /// this prefix, [`Error::NotContained`] is returned.
+    ///
+    /// This is the single-step version of excluding one prefix from another, which allocators use
+    /// to return leftover blocks to a free list.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let p = |s: &str| s.parse::<ipnet::Ipv4Net>().unwrap();
+    /// let leftovers = p("10.0.0.0/24").carve(&p("10.0.0.64/26")).unwrap();
+    /// assert_eq!(vec![p("10.0.0.0/26"), p("10.0.0.128/25")], leftovers);
+    /// ```
+    fn carve<P: Prefix<Address = Self::Address>>(&self, child: &P) -> Result<Vec<Self>> {
+        if !Set::contains(self, child) {
+            return Err(Error::NotContained);
+        }
+        let network: u32 = child.network().into();
+        let mut leftovers: Vec<Self> = (self.length() + 1..=child.length())
+            .map(|length| {
+                let sibling = (network & MASKS[length as usize]) ^ (1 << (32 - length));
+                unsafe { Self::new_unchecked(sibling.into(), length) }
+            })
+            .collect();
+        leftovers.sort_by_key(|prefix| Into::<u32>::into(prefix.address()));
+        Ok(leftovers)
+    }
+
+    /// returns the fewest prefixes that together cover exactly the addresses in this prefix but
+    /// not in the other, in order, like Python's `address_exclude`. Unlike [`Prefix::carve`],
+    /// the other prefix doesn't need to be contained in this one: if it contains this prefix,
+    /// nothing is left and if they don't overlap, this prefix is returned whole.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Prefix};
+    /// let p = |s: &str| s.parse::<Pfx>().unwrap();
+    /// assert_eq!(
+    ///     vec![p("10.0.0.0/26"), p("10.0.0.128/25")],
+    ///     p("10.0.0.0/24").exclude(&p("10.0.0.64/26"))
+    /// );
+    /// assert_eq!(Vec::<Pfx>::new(), p("10.0.0.0/24").exclude(&p("10.0.0.0/16")));
+    /// assert_eq!(vec![p("10.0.0.0/24")], p("10.0.0.0/24").exclude(&p("10.0.1.0/24")));
+    /// ```
+    fn exclude<P: Prefix<Address = Self::Address>>(&self, other: &P) -> Vec<Self> {
+        if Set::contains(other, self) {
+            return vec![];
+        }
+        match self.carve(other) {
+            Ok(leftovers) => leftovers,
+            // they don't overlap
+            Err(_) => vec![unsafe { Self::new_unchecked(self.network(), self.length()) }],
+        }
+    }
+
+    /// returns the free space in this prefix as the fewest prefixes that together cover exactly
+    /// the addresses not in any of the allocated ones, in order. Allocated prefixes may overlap
+    /// each other and may reach outside of this prefix; only the parts inside of it matter.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let p = |s: &str| s.parse::<ipnet::Ipv4Net>().unwrap();
+    /// let free = p("10.0.0.0/24").free_blocks([p("10.0.0.64/26"), p("10.0.0.0/27")]);
+    /// assert_eq!(vec![p("10.0.0.32/27"), p("10.0.0.128/25")], free);
+    /// ```
+    fn free_blocks<P, I>(&self, allocated: I) -> Vec<Self>
+    where
+        P: Prefix<Address = Self::Address>,
+        I: IntoIterator<Item = P>,
+    {
+        let first: u32 = self.network().into();
+        let last: u32 = self.broadcast().into();
+
+        let mut allocated: Vec<(u32, u32)> = allocated
+            .into_iter()
+            .map(|p| (p.network().into(), p.broadcast().into()))
+            .collect();
+        allocated.sort_unstable();
+
+        let mut free = vec![];
+        // the next address not yet known to be allocated; None once the end of self is reached
+        let mut next = Some(first);
+        for (start, end) in allocated {
+            let Some(n) = next else { break };
+            if last < start {
+                break;
+            }
+            if n < start {
+                free.extend(covering_prefixes(n, start - 1));
+            }
+            if n <= end {
+                next = end.checked_add(1).filter(|n| *n <= last);
+            }
+        }
+        if let Some(n) = next {
+            free.extend(covering_prefixes(n, last));
+        }
+        free.into_iter()
+            .map(|(network, length)| unsafe { Self::new_unchecked(network.into(), length) })
+            .collect()
+    }
+
+    /// returns the largest prefix that is free in this prefix, given the allocated ones, or
+    /// [`None`] if it is fully allocated. When several free prefixes are equally large, the first
+    /// one is returned. See [`Prefix::free_blocks`].
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let p = |s: &str| s.parse::<ipnet::Ipv4Net>().unwrap();
+    /// let free = p("10.0.0.0/24").largest_free_block([p("10.0.0.64/26"), p("10.0.0.0/27")]);
+    /// assert_eq!(Some(p("10.0.0.128/25")), free);
+    /// assert_eq!(None, p("10.0.0.0/24").largest_free_block([p("10.0.0.0/16")]));
+    /// ```
+    fn largest_free_block<P, I>(&self, allocated: I) -> Option<Self>
+    where
+        P: Prefix<Address = Self::Address>,
+        I: IntoIterator<Item = P>,
+    {
+        self.free_blocks(allocated)
+            .into_iter()
+            .min_by_key(|prefix| prefix.length())
+    }
+
+    /// formats the prefix in CIDR notation, including host bits, into the given buffer and
+    /// returns the part of the buffer that was written as a string. This doesn't allocate or
+    /// depend on [`std::fmt`]. At most [`MAX_PREFIX_LEN`] bytes are needed. If the buffer is too
+    /// small, [`Error::BufferTooSmall`] is returned.
+    ///
+    /// It is named differently from [`Address::format_into`] because every [`Address`] is also a
+    /// Prefix and the names would be ambiguous.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Prefix, MAX_PREFIX_LEN};
+    /// let mut buf = [0u8; MAX_PREFIX_LEN];
+    /// let prefix = "10.224.24.1/27".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!("10.224.24.1/27", prefix.format_cidr_into(&mut buf).unwrap());
+    /// ```
+    fn format_cidr_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str> {
+        match format::write_prefix(self.address().octets(), self.length(), buf) {
+            Some(len) => Ok(format::as_str(buf, len)),
+            None => Err(Error::BufferTooSmall),
+        }
+    }
+
     /// returns an inclusive range of IP addresses equivalent to the range of addresses contained
     /// within this Prefix. The range is not open-ended so that the entire IP range can be
     /// represented.
@@ -241,6 +1582,201 @@ pub trait Prefix: Eq + std::str::FromStr + std::string::ToString {
     fn as_range_i(&self) -> RangeInclusive<Self::Address> {
         RangeInclusive::new(self.network().address(), self.broadcast().address())
     }
+
+    /// returns a [`display::Rendering`] to pick an alternate notation to display the prefix in
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let prefix = "10.0.0.0/24".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!("10.0.0.0-10.0.0.255", prefix.display().as_range().to_string());
+    /// assert_eq!("10.0.0.0 0.0.0.255", prefix.display().as_wildcard().to_string());
+    /// ```
+    fn display(&self) -> display::Rendering<Self::Address> {
+        display::Rendering::prefix(
+            self.address(),
+            self.length(),
+            self.network().address(),
+            self.broadcast().address(),
+        )
+    }
+
+    /// returns an iterator over the significant bits of the prefix, from the most significant bit
+    /// of the address up to its length, as booleans. This is the path a binary trie takes to
+    /// reach the prefix.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let prefix = "160.0.0.0/3".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!(vec![true, false, true], prefix.network_bits().collect::<Vec<_>>());
+    /// ```
+    fn network_bits(&self) -> impl Iterator<Item = bool> {
+        let ui: UI = self.address().into();
+        (0..u32::from(self.length())).map(move |i| ui & (1 << (31 - i)) != 0)
+    }
+
+    /// returns the name of the reverse DNS zone under `in-addr.arpa` for the prefix, ignoring its
+    /// host bits. When the length isn't a multiple of 8, the RFC 2317 style of naming classless
+    /// delegations is used, where the octet that is split is written as `first/length`.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let p = |s: &str| s.parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!("3.2.1.in-addr.arpa", p("1.2.3.0/24").reverse_zone_name());
+    /// assert_eq!("64/26.3.2.1.in-addr.arpa", p("1.2.3.64/26").reverse_zone_name());
+    /// ```
+    fn reverse_zone_name(&self) -> String {
+        reverse_zone_name(self.network().into(), self.length())
+    }
+
+    /// returns an iterator over the PTR record owner names and addresses for the hosts in the
+    /// prefix, as written in a reverse zone file. See [`Prefix::hosts`] for which addresses are
+    /// hosts.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// # use std::net::Ipv4Addr;
+    /// let prefix = "10.0.0.0/30".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!(
+    ///     vec![
+    ///         ("1.0.0.10.in-addr.arpa".to_string(), Ipv4Addr::new(10, 0, 0, 1)),
+    ///         ("2.0.0.10.in-addr.arpa".to_string(), Ipv4Addr::new(10, 0, 0, 2)),
+    ///     ],
+    ///     prefix.ptr_records().collect::<Vec<_>>()
+    /// );
+    /// ```
+    fn ptr_records(&self) -> impl Iterator<Item = (String, Self::Address)> {
+        self.hosts().map(|address| (address.to_ptr_name(), address))
+    }
+
+    /// returns the names of the standard reverse DNS zones, on octet boundaries, that together
+    /// cover exactly the prefix. A prefix longer than /24 isn't covered by any such zone so its
+    /// RFC 2317 name from [`Prefix::reverse_zone_name`] is returned alone.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// let p = |s: &str| s.parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!(
+    ///     vec!["4.2.1.in-addr.arpa", "5.2.1.in-addr.arpa"],
+    ///     p("1.2.4.0/23").reverse_zones()
+    /// );
+    /// assert_eq!(vec!["2.1.in-addr.arpa"], p("1.2.0.0/16").reverse_zones());
+    /// ```
+    fn reverse_zones(&self) -> Vec<String> {
+        let first: UI = self.network().into();
+        let last: UI = self.broadcast().into();
+        match self.length() {
+            length @ 25.. => vec![reverse_zone_name(first, length)],
+            length => {
+                let length = length.next_multiple_of(8);
+                let shift = 32 - u32::from(length);
+                (u64::from(first) >> shift..=u64::from(last) >> shift)
+                    .map(|i| reverse_zone_name((i << shift) as UI, length))
+                    .collect()
+            }
+        }
+    }
+
+    /// checks whether the given children exactly partition this prefix: each child is contained
+    /// in it, no two children overlap, and together they leave no part of it uncovered. If not, a
+    /// [`PartitionReport`] describing every problem found is returned as the error.
+    ///
+    /// This is the key invariant of a hierarchical address plan.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Prefix;
+    /// # use std::net::Ipv4Addr;
+    /// let p = |s: &str| s.parse::<ipnet::Ipv4Net>().unwrap();
+    /// let parent = p("10.0.0.0/24");
+    ///
+    /// assert!(parent
+    ///     .is_partitioned_by([p("10.0.0.128/25"), p("10.0.0.0/26"), p("10.0.0.64/26")])
+    ///     .is_ok());
+    ///
+    /// let report = parent
+    ///     .is_partitioned_by([p("10.0.0.0/25"), p("10.0.0.64/26")])
+    ///     .unwrap_err();
+    /// assert_eq!(vec![(p("10.0.0.0/25"), p("10.0.0.64/26"))], report.overlaps);
+    /// assert_eq!(
+    ///     vec![Ipv4Addr::new(10, 0, 0, 128)..=Ipv4Addr::new(10, 0, 0, 255)],
+    ///     report.missing
+    /// );
+    /// ```
+    fn is_partitioned_by<P, I>(&self, children: I) -> std::result::Result<(), PartitionReport<P>>
+    where
+        P: Prefix<Address = Self::Address> + Clone,
+        I: IntoIterator<Item = P>,
+    {
+        let first: u32 = self.network().into();
+        let last: u32 = self.broadcast().into();
+
+        let mut children: Vec<P> = children.into_iter().collect();
+        children.sort_by_key(|child| (Into::<u32>::into(child.network()), child.length()));
+
+        let mut report = PartitionReport {
+            overlaps: vec![],
+            outside: vec![],
+            missing: vec![],
+        };
+        // children sorted this way will only overlap children that contain them so a stack of
+        // nested containers is enough to find every overlapping pair
+        let mut containers: Vec<&P> = vec![];
+        // the next address not yet covered by a child; None once the end of self is covered
+        let mut next = Some(first);
+        for child in &children {
+            let child_first: u32 = child.network().into();
+            let child_last: u32 = child.broadcast().into();
+
+            while let Some(container) = containers.last() {
+                match Into::<u32>::into(container.broadcast()) < child_first {
+                    true => containers.pop(),
+                    false => break,
+                };
+            }
+            for container in &containers {
+                report.overlaps.push(((*container).clone(), child.clone()));
+            }
+            containers.push(child);
+
+            if child_first < first || last < child_last {
+                report.outside.push(child.clone());
+                continue;
+            }
+            if let Some(n) = next {
+                if n < child_first {
+                    report.missing.push(n.into()..=(child_first - 1).into());
+                }
+                if n <= child_last {
+                    next = child_last.checked_add(1).filter(|n| *n <= last);
+                }
+            }
+        }
+        if let Some(n) = next {
+            report.missing.push(n.into()..=last.into());
+        }
+
+        match report.overlaps.is_empty() && report.outside.is_empty() && report.missing.is_empty() {
+            true => Ok(()),
+            false => Err(report),
+        }
+    }
+}
+
+/// describes why a collection of prefixes fails to exactly partition a parent prefix. It is
+/// returned by [`Prefix::is_partitioned_by`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct PartitionReport<P: Prefix> {
+    /// every pair of children where the first contains the second, in order
+    pub overlaps: Vec<(P, P)>,
+    /// children which are not wholly contained in the parent
+    pub outside: Vec<P>,
+    /// ranges of the parent not covered by any child, in order
+    pub missing: Vec<RangeInclusive<P::Address>>,
 }
 
 impl<T, P> Set for P
@@ -264,13 +1800,37 @@ where
         }
     }
 
+    fn num_prefixes_u64(&self, length: u8) -> Result<u64> {
+        match length {
+            length if length < self.length() => Ok(0),
+            length if Self::Address::BITS < length => Err(Error::InvalidLength),
+            length => Ok(1 << (length - self.length())),
+        }
+    }
+
     fn contains<P2: Prefix>(&self, other: &P2) -> bool {
         use prefix_private::{Cmp, PrefixOrd::*};
 
-        match self.cmp(other) {
-            (Same | Contains, _, _, _) => true,
-            _ => false,
-        }
+        matches!(self.cmp(other), (Same | Contains, _, _, _))
+    }
+
+    fn overlaps<P2: Prefix<Address = T>>(&self, other: &P2) -> bool {
+        use prefix_private::{Cmp, PrefixOrd::*};
+
+        !matches!(self.cmp(other), (Disjoint, _, _, _))
+    }
+
+    fn coarsen<P2: Prefix<Address = T>>(&self, length: u8) -> Result<Vec<P2>> {
+        coarsen(self.network().into(), self.broadcast().into(), length)
+    }
+
+    fn prefixes<P2: Prefix<Address = T>>(&self, length: u8) -> Result<impl Iterator<Item = P2>> {
+        let block = (self.network().into(), self.length());
+        aligned_prefixes(std::iter::once(block), length)
+    }
+
+    fn iter_addresses(&self) -> impl Iterator<Item = T> {
+        Prefix::addresses(self)
     }
 }
 
@@ -281,6 +1841,10 @@ where
     type Address = T;
 
     fn num_prefixes(&self, length: u8) -> Result<u32> {
+        u32::try_from(self.num_prefixes_u64(length)?).map_err(|_| Error::TooMany)
+    }
+
+    fn num_prefixes_u64(&self, length: u8) -> Result<u64> {
         match length {
             length if T::BITS < length => Err(Error::InvalidLength),
             _ => {
@@ -297,17 +1861,47 @@ where
                     Some(m) => (m, 1 << (u32::BITS - (zeros + 1))),
                     None => (0, 1 << (u32::BITS - 1)), // zeroes is 0
                 };
+                let size = 1u64 << (u32::BITS - length as u32);
+                // only a range that is exactly one aligned block can hold a prefix that spans the
+                // middle
+                if start & !mask == 0 && end | mask == u32::MAX {
+                    return Ok((u64::from(end - start) + 1) / size);
+                }
                 let middle = (start & mask) | pivot;
-                let size = 2u32.pow(u32::BITS - length as u32);
-                Ok((middle - start) / size + (end - middle + 1) / size)
+                Ok(u64::from(middle - start) / size + (u64::from(end - middle) + 1) / size)
             }
         }
     }
 
     fn contains<P2: Prefix<Address = T>>(&self, other: &P2) -> bool {
         // This implementation will need to change when Prefix changes to Set for the containee
-        return RangeInclusive::<T>::contains::<T>(self, &other.network().address())
-            && RangeInclusive::<T>::contains::<T>(self, &other.broadcast().address());
+        RangeInclusive::<T>::contains::<T>(self, &other.network().address())
+            && RangeInclusive::<T>::contains::<T>(self, &other.broadcast().address())
+    }
+
+    fn overlaps<P2: Prefix<Address = T>>(&self, other: &P2) -> bool {
+        self.start() <= self.end()
+            && *self.start() <= other.broadcast()
+            && other.network() <= *self.end()
+    }
+
+    fn coarsen<P2: Prefix<Address = T>>(&self, length: u8) -> Result<Vec<P2>> {
+        match self.start() <= self.end() {
+            true => coarsen((*self.start()).into(), (*self.end()).into(), length),
+            false => match length {
+                0..=32 => Ok(vec![]),
+                _ => Err(Error::InvalidLength),
+            },
+        }
+    }
+
+    fn prefixes<P2: Prefix<Address = T>>(&self, length: u8) -> Result<impl Iterator<Item = P2>> {
+        let blocks = covering_prefixes((*self.start()).into(), (*self.end()).into());
+        aligned_prefixes(blocks, length)
+    }
+
+    fn iter_addresses(&self) -> impl Iterator<Item = T> {
+        addresses(self)
     }
 }
 
@@ -324,7 +1918,7 @@ where
         Self::BITS
     }
 
-    unsafe fn unsafe_new(ip: Self::Address, _length: u8) -> Self {
+    unsafe fn new_unchecked(ip: Self::Address, _length: u8) -> Self {
         ip
     }
 }
@@ -336,7 +1930,7 @@ pub trait Set {
 
     /// returns the number of addresses in the set.
     /// It ignores any bits set in the host part of the address. In the case of 0 prefix length, it
-    /// returns [`Error::TooMany`].
+    /// returns [`Error::TooMany`]. Use [`Set::num_addresses_u64`] to count every address.
     ///
     /// # Example
     /// ```
@@ -348,9 +1942,24 @@ pub trait Set {
         self.num_prefixes(Self::Address::BITS)
     }
 
+    /// returns the number of addresses in the set as a [`u64`], which is wide enough to count the
+    /// entire address space
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Set;
+    /// let all = "0.0.0.0/0".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!(1 << 32, all.num_addresses_u64());
+    /// ```
+    fn num_addresses_u64(&self) -> u64 {
+        // the length is always valid
+        self.num_prefixes_u64(Self::Address::BITS)
+            .unwrap_or_default()
+    }
+
     /// returns the number of prefixes of the given length contained in this set. If the number
-    /// would overflow a [`u32`] it returns [`Error::TooMany`]. If >32 is passed for length then
-    /// [`Error::InvalidLength`] is returned.
+    /// would overflow a [`u32`] it returns [`Error::TooMany`]; use [`Set::num_prefixes_u64`] to
+    /// count them all. If >32 is passed for length then [`Error::InvalidLength`] is returned.
     ///
     /// # Examples
     /// ```
@@ -372,6 +1981,41 @@ pub trait Set {
     /// ```
     fn num_prefixes(&self, length: u8) -> Result<u32>;
 
+    /// returns the number of prefixes of the given length contained in this set as a [`u64`],
+    /// which never overflows. Like [`Set::num_prefixes`], only aligned, wholly contained prefixes
+    /// are counted. If >32 is passed for length then [`Error::InvalidLength`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Set;
+    /// let all = "0.0.0.0/0".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!(1 << 32, all.num_prefixes_u64(32).unwrap());
+    /// assert!(all.num_prefixes(32).is_err());
+    /// ```
+    fn num_prefixes_u64(&self, length: u8) -> Result<u64> {
+        match self.num_prefixes(length) {
+            Ok(count) => Ok(count.into()),
+            // there are at most 2^length prefixes of a length so only holding every one of them
+            // overflows a u32
+            Err(Error::TooMany) => Ok(1 << length),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// returns the number of prefixes of the given, already validated, length contained in this
+    /// set. Like [`Set::num_prefixes`] but it can't fail with [`Error::InvalidLength`]. If the
+    /// number would overflow a [`u32`] it returns [`Error::TooMany`].
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{PrefixLength, Set};
+    /// let prefix = "1.2.3.0/25".parse::<ipnet::Ipv4Net>().unwrap();
+    /// assert_eq!(4, prefix.num_prefixes_of(PrefixLength::of::<27>()).unwrap());
+    /// ```
+    fn num_prefixes_of(&self, length: PrefixLength) -> Result<u32> {
+        self.num_prefixes(length.get())
+    }
+
     /// returns true if the set is empty
     /// # Example
     /// ```
@@ -380,15 +2024,12 @@ pub trait Set {
     /// assert!(!prefix.is_empty());
     /// ```
     fn is_empty(&self) -> bool {
-        match self.num_addresses() {
-            Ok(0) => true,
-            _ => false,
-        }
+        matches!(self.num_addresses(), Ok(0))
     }
 
     /// returns true if the given containee is wholly contained within this Prefix. If the two
     /// Prefixes are equal, true is returned. The host bits in the address are ignored when testing
-    /// containership.
+    /// containership. Use [`Set::contains_set`] to test a range or a whole set.
     ///
     /// # Example
     /// ```
@@ -411,10 +2052,865 @@ pub trait Set {
     /// assert!(!net.contains(&ip_no));
     /// ```
     fn contains<P2: Prefix<Address = Self::Address>>(&self, other: &P2) -> bool;
+
+    /// returns true if every address in the other set is also in this one. Unlike
+    /// [`Set::contains`], the other set can be any set: a range, an [`IpSet`] or a prefix. It
+    /// narrows in on the places where the two sets differ, so it takes time proportional to the
+    /// number of prefixes needed to describe the other set rather than to its size.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{IpSet, Pfx, Set};
+    /// # use std::net::Ipv4Addr;
+    /// let allowed: IpSet<ipnet::Ipv4Net> = ["10.0.0.0/24", "10.0.1.0/25"]
+    ///     .iter()
+    ///     .map(|s| s.parse::<ipnet::Ipv4Net>().unwrap())
+    ///     .collect();
+    /// let customer = Ipv4Addr::new(10, 0, 0, 200)..=Ipv4Addr::new(10, 0, 1, 100);
+    /// assert!(allowed.contains_set(&customer));
+    ///
+    /// let customer = Ipv4Addr::new(10, 0, 0, 200)..=Ipv4Addr::new(10, 0, 1, 200);
+    /// assert!(!allowed.contains_set(&customer));
+    /// ```
+    fn contains_set<S: Set<Address = Self::Address>>(&self, other: &S) -> bool {
+        // start from the whole address space
+        let all = unsafe { Block::new_unchecked(0.into(), 0) };
+        contains_within(self, other, all)
+    }
+
+    /// returns true if the set and the given prefix have any address in common, whether one
+    /// contains the other or they only partly intersect. The host bits in the address are ignored.
+    ///
+    /// Unlike most methods of this trait, there is no provided implementation. With only
+    /// [`Set::contains`] to go on, showing that a prefix misses the set could take a look at every
+    /// address in it, and the provided [`Set::coarsen`], [`Set::prefixes`] and
+    /// [`Set::contains_set`] are built on this method in turn.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Set};
+    /// # use std::net::Ipv4Addr;
+    /// let net: Pfx = "192.168.0.0/24".parse().unwrap();
+    /// assert!(net.overlaps(&"192.168.0.0/16".parse::<Pfx>().unwrap()));
+    /// assert!(net.overlaps(&"192.168.0.128/25".parse::<Pfx>().unwrap()));
+    /// assert!(!net.overlaps(&"192.168.1.0/24".parse::<Pfx>().unwrap()));
+    ///
+    /// // a range may overlap a prefix without either containing the other
+    /// let range = Ipv4Addr::new(192, 168, 0, 200)..=Ipv4Addr::new(192, 168, 1, 10);
+    /// assert!(range.overlaps(&"192.168.1.0/24".parse::<ipnet::Ipv4Net>().unwrap()));
+    /// ```
+    fn overlaps<P2: Prefix<Address = Self::Address>>(&self, other: &P2) -> bool;
+
+    /// returns, in order, every prefix of the given length that intersects the set. This views the
+    /// set at a coarser granularity, rounding everything out to whole prefixes of that length, as
+    /// when counting which /24s hold any addresses. If >32 is passed for length then
+    /// [`Error::InvalidLength`] is returned. The provided implementation finds the prefixes with
+    /// [`Set::contains`] and [`Set::overlaps`], like [`Set::prefixes`].
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Set;
+    /// # use std::net::Ipv4Addr;
+    /// let from = "10.0.0.200".parse::<Ipv4Addr>().unwrap();
+    /// let to = "10.0.2.10".parse::<Ipv4Addr>().unwrap();
+    /// let prefixes: Vec<ipnet::Ipv4Net> = (from..=to).coarsen(24).unwrap();
+    /// assert_eq!(
+    ///     vec!["10.0.0.0/24", "10.0.1.0/24", "10.0.2.0/24"],
+    ///     prefixes.iter().map(|p| p.to_string()).collect::<Vec<_>>()
+    /// );
+    /// ```
+    fn coarsen<P: Prefix<Address = Self::Address>>(&self, length: u8) -> Result<Vec<P>> {
+        if Self::Address::BITS < length {
+            return Err(Error::InvalidLength);
+        }
+        let mut blocks = Vec::new();
+        let all = unsafe { Block::new_unchecked(0.into(), 0) };
+        covered_blocks(self, all, length, true, &mut blocks);
+        Ok(aligned_prefixes(blocks.into_iter(), length)?.collect())
+    }
+
+    /// returns an iterator, in order, over every prefix of the given length that is wholly
+    /// contained in the set, the prefixes that [`Set::num_prefixes`] counts. Like there, only
+    /// aligned prefixes are included. If >32 is passed for length then [`Error::InvalidLength`] is
+    /// returned. The provided implementation finds the blocks in the set with [`Set::contains`]
+    /// and [`Set::overlaps`], narrowing in on the places where the set only partly covers a block.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Set;
+    /// # use std::net::Ipv4Addr;
+    /// let range = Ipv4Addr::new(10, 0, 0, 200)..=Ipv4Addr::new(10, 0, 3, 10);
+    /// let prefixes: Vec<ipnet::Ipv4Net> = range.prefixes(24).unwrap().collect();
+    /// assert_eq!("[10.0.1.0/24, 10.0.2.0/24]", format!("{prefixes:?}"));
+    /// ```
+    fn prefixes<P: Prefix<Address = Self::Address>>(
+        &self,
+        length: u8,
+    ) -> Result<impl Iterator<Item = P>> {
+        if Self::Address::BITS < length {
+            return Err(Error::InvalidLength);
+        }
+        let mut blocks = Vec::new();
+        let all = unsafe { Block::new_unchecked(0.into(), 0) };
+        covered_blocks(self, all, length, false, &mut blocks);
+        aligned_prefixes(blocks.into_iter(), length)
+    }
+
+    /// returns an iterator, in order, over every address in the set. Addresses are produced lazily
+    /// so even the largest sets can be scanned without collecting them first. Prefixes and ranges
+    /// also have an `addresses` method, [`Prefix::addresses`] and [`addresses`], returning an
+    /// [`Addresses`] that can be walked from either end.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, IpSet, Pfx, Set};
+    /// let prefix: Pfx = "10.0.0.1/31".parse().unwrap();
+    /// let addresses: Vec<Addr> = prefix.iter_addresses().collect();
+    /// assert_eq!(vec![Addr::new(10, 0, 0, 0), Addr::new(10, 0, 0, 1)], addresses);
+    ///
+    /// let set: IpSet = ["10.0.0.0/31", "10.0.0.8/32"]
+    ///     .iter()
+    ///     .map(|s| s.parse::<Pfx>().unwrap())
+    ///     .collect();
+    /// assert_eq!(3, set.iter_addresses().count());
+    /// ```
+    fn iter_addresses(&self) -> impl Iterator<Item = Self::Address> {
+        // every set can be split into /32s so this never fails
+        self.prefixes::<Self::Address>(Self::Address::BITS)
+            .into_iter()
+            .flatten()
+    }
+
+    /// returns an address chosen uniformly at random from the set, or [`None`] if it is empty.
+    /// Every address is equally likely, no matter how the set is made up. The provided
+    /// implementation picks an index and skips to it with [`Set::iter_addresses`].
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Pfx, Set};
+    /// let prefix: Pfx = "10.0.0.0/24".parse().unwrap();
+    /// let address = prefix.random_address(&mut rand::rng()).unwrap();
+    /// assert!(prefix.contains(&address));
+    /// ```
+    #[cfg(feature = "rand")]
+    fn random_address<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Option<Self::Address> {
+        match self.num_addresses_u64() {
+            0 => None,
+            n => self.iter_addresses().nth(rng.random_range(0..n) as usize),
+        }
+    }
+
+    /// picks a uniformly random prefix of the given length inside `within` that doesn't
+    /// intersect this set, i.e. one that is free to allocate when the set holds what is already
+    /// in use. Picking at random instead of the lowest free prefix avoids contention between
+    /// allocators working independently. Only the parts of the set inside `within` are visited,
+    /// so a large `within` costs no more than a small one. If nothing is free, [`None`] is
+    /// returned. If the length is shorter than the length of `within`, greater than 32 or can't
+    /// be represented by `P`, [`Error::InvalidLength`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Set;
+    /// let p = |s: &str| s.parse::<ipnet::Ipv4Net>().unwrap();
+    /// let used = p("10.0.0.0/25");
+    /// let free = used.random_free_prefix(&p("10.0.0.0/24"), 26, &mut rand::rng());
+    /// let free = free.unwrap().unwrap();
+    /// assert!([p("10.0.0.128/26"), p("10.0.0.192/26")].contains(&free));
+    /// ```
+    #[cfg(feature = "rand")]
+    fn random_free_prefix<P, R>(&self, within: &P, length: u8, rng: &mut R) -> Result<Option<P>>
+    where
+        P: Prefix<Address = Self::Address>,
+        R: rand::Rng + ?Sized,
+    {
+        if length < within.length() || Self::Address::BITS < length {
+            return Err(Error::InvalidLength);
+        }
+        let block = unsafe { Block::new_unchecked(within.network(), within.length()) };
+        let mut used = Vec::new();
+        used_runs(self, block, length, &mut used);
+        let count: u64 = used.iter().map(|(_, n)| n).sum();
+        let free = (1u64 << (length - within.length())) - count;
+        if free == 0 {
+            return Ok(None);
+        }
+        let shift = 32 - u32::from(length);
+        let mut pick = (u64::from(within.network().into()) >> shift) + rng.random_range(0..free);
+        // skip over used prefixes, which are in order, to find the chosen free one
+        for (first, n) in used {
+            match first <= pick {
+                true => pick += n,
+                false => break,
+            }
+        }
+        let prefix = unsafe { P::new_unchecked(((pick << shift) as UI).into(), length) };
+        match prefix.length() == length {
+            true => Ok(Some(prefix)),
+            false => Err(Error::InvalidLength),
+        }
+    }
+}
+
+/// all of the valid IPv4 netmasks indexed by prefix length, from `0.0.0.0` for /0 to
+/// `255.255.255.255` for /32
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::MASKS;
+/// # use std::net::Ipv4Addr;
+/// assert_eq!(Ipv4Addr::new(255, 255, 252, 0), Ipv4Addr::from(MASKS[22]));
+/// ```
+pub const MASKS: [u32; 33] = {
+    let mut masks = [0u32; 33];
+    let mut length = 1;
+    while length < masks.len() {
+        masks[length] = u32::MAX << (32 - length);
+        length += 1;
+    }
+    masks
+};
+
+/// returns the netmask for the given prefix length without needing a prefix. If the length is
+/// greater than 32 then [`Error::InvalidLength`] is returned.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::mask_for_length;
+/// # use std::net::Ipv4Addr;
+/// let mask: Ipv4Addr = mask_for_length(26).unwrap();
+/// assert_eq!("255.255.255.192", mask.to_string());
+/// assert!(mask_for_length::<Ipv4Addr>(33).is_err());
+/// ```
+pub fn mask_for_length<A: Address>(length: u8) -> Result<A> {
+    match MASKS.get(length as usize) {
+        Some(mask) => Ok((*mask).into()),
+        None => Err(Error::InvalidLength),
+    }
+}
+
+/// checks whether the given prefixes, sorted by network address, cover a single range of
+/// addresses without any gaps. Overlapping prefixes are allowed. If there are gaps between them,
+/// they are returned, in order, as the error. An empty collection is considered contiguous.
+///
+/// This is useful to validate that blocks delegated from a parent allocation fully tile it.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::are_contiguous;
+/// # use std::net::Ipv4Addr;
+/// let p = |s: &str| s.parse::<ipnet::Ipv4Net>().unwrap();
+///
+/// assert!(are_contiguous([p("10.0.0.0/25"), p("10.0.0.128/26"), p("10.0.0.192/26")]).is_ok());
+///
+/// let gaps = are_contiguous([p("10.0.0.0/26"), p("10.0.0.128/25")]).unwrap_err();
+/// assert_eq!(vec![Ipv4Addr::new(10, 0, 0, 64)..=Ipv4Addr::new(10, 0, 0, 127)], gaps);
+/// ```
+pub fn are_contiguous<P, I>(prefixes: I) -> std::result::Result<(), Vec<RangeInclusive<P::Address>>>
+where
+    P: Prefix,
+    I: IntoIterator<Item = P>,
+{
+    let mut gaps = vec![];
+    let mut end: Option<u32> = None;
+    for prefix in prefixes {
+        let first: u32 = prefix.network().into();
+        let last: u32 = prefix.broadcast().into();
+        if let Some(end) = end {
+            if end < u32::MAX && end + 1 < first {
+                gaps.push((end + 1).into()..=(first - 1).into());
+            }
+        }
+        end = Some(end.map_or(last, |end| end.max(last)));
+    }
+    match gaps.is_empty() {
+        true => Ok(()),
+        false => Err(gaps),
+    }
+}
+
+/// returns the first and last prefixes of the given length that are aligned and wholly contained
+/// in the range, bounding the usable aligned region of a misaligned block. Every aligned prefix of
+/// that length in between is also inside the range; [`Set::num_prefixes`] counts them. If no
+/// prefix of that length fits, [`None`] is returned. If >32 is passed for length then
+/// [`Error::InvalidLength`] is returned.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::alignments_within;
+/// # use std::net::Ipv4Addr;
+/// let from = "10.223.255.1".parse::<Ipv4Addr>().unwrap();
+/// let to = "10.225.0.254".parse::<Ipv4Addr>().unwrap();
+/// let (first, last): (ipnet::Ipv4Net, ipnet::Ipv4Net) =
+///     alignments_within(&(from..=to), 24).unwrap().unwrap();
+/// assert_eq!("10.224.0.0/24", first.to_string());
+/// assert_eq!("10.224.255.0/24", last.to_string());
+/// ```
+pub fn alignments_within<P: Prefix>(
+    range: &RangeInclusive<P::Address>,
+    length: u8,
+) -> Result<Option<(P, P)>> {
+    if P::Address::BITS < length {
+        return Err(Error::InvalidLength);
+    }
+    let size = 1u64 << (32 - length);
+    let start = u64::from((*range.start()).into());
+    let end = u64::from((*range.end()).into()) + 1;
+    let first = start.next_multiple_of(size);
+    let last = end / size * size;
+    match first + size <= last {
+        true => unsafe {
+            Ok(Some((
+                P::new_unchecked((first as UI).into(), length),
+                P::new_unchecked(((last - size) as UI).into(), length),
+            )))
+        },
+        false => Ok(None),
+    }
+}
+
+/// returns an iterator, in order, over every address in the given range. [`std::iter::Step`]
+/// isn't stable so a [`RangeInclusive`] of addresses can't be iterated directly. An empty range
+/// gives no addresses.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::addresses;
+/// # use std::net::Ipv4Addr;
+/// let range = Ipv4Addr::new(10, 0, 0, 254)..=Ipv4Addr::new(10, 0, 1, 1);
+/// assert_eq!(
+///     vec![
+///         Ipv4Addr::new(10, 0, 0, 254),
+///         Ipv4Addr::new(10, 0, 0, 255),
+///         Ipv4Addr::new(10, 0, 1, 0),
+///         Ipv4Addr::new(10, 0, 1, 1),
+///     ],
+///     addresses(&range).collect::<Vec<_>>()
+/// );
+/// ```
+pub fn addresses<A: Address>(range: &RangeInclusive<A>) -> Addresses<A> {
+    Addresses::new(*range.start(), *range.end())
+}
+
+/// returns an iterator, in order, over the fewest prefixes that together cover exactly the given
+/// range of addresses, the inverse of [`Prefix::as_range_i`]. [`Set::num_prefixes`] can count
+/// the prefixes of one length in a range but this enumerates the mix of lengths needed. An empty
+/// range gives no prefixes. When the prefix type is an [`Address`], which can only hold a single
+/// address, every address in the range is returned.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::summarize_range;
+/// # use std::net::Ipv4Addr;
+/// let range = Ipv4Addr::new(10, 0, 0, 1)..=Ipv4Addr::new(10, 0, 0, 9);
+/// let prefixes: Vec<String> = summarize_range::<ipnet::Ipv4Net>(&range)
+///     .map(|p| p.to_string())
+///     .collect();
+/// assert_eq!(vec!["10.0.0.1/32", "10.0.0.2/31", "10.0.0.4/30", "10.0.0.8/31"], prefixes);
+/// ```
+pub fn summarize_range<P: Prefix>(range: &RangeInclusive<P::Address>) -> impl Iterator<Item = P> {
+    to_prefixes(covering_prefixes(
+        (*range.start()).into(),
+        (*range.end()).into(),
+    ))
+}
+
+/// returns the smallest single prefix that contains the whole range, which may include addresses
+/// outside of it. This is useful for coarse ACLs where exactness isn't needed. If the range is
+/// empty, or `P` is an address type and the range holds more than one address, [`None`] is
+/// returned.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::spanning_prefix;
+/// # use std::net::Ipv4Addr;
+/// let range = Ipv4Addr::new(10, 0, 0, 100)..=Ipv4Addr::new(10, 0, 1, 5);
+/// let prefix: ipnet::Ipv4Net = spanning_prefix(&range).unwrap();
+/// assert_eq!("10.0.0.0/23", prefix.to_string());
+/// ```
+pub fn spanning_prefix<P: Prefix>(range: &RangeInclusive<P::Address>) -> Option<P> {
+    let first: UI = (*range.start()).into();
+    let last: UI = (*range.end()).into();
+    match first <= last {
+        true => common_prefix(range.start(), range.end()),
+        false => None,
+    }
+}
+
+/// returns the longest prefix that contains both addresses, the one covering the leading bits
+/// they have in common. Its length is [`Address::common_prefix_len`] and its host bits are zero.
+/// If `P` is an address type, it can only hold that prefix when the addresses are the same, so
+/// [`None`] is returned otherwise.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::common_prefix;
+/// # use std::net::Ipv4Addr;
+/// let a = Ipv4Addr::new(10, 0, 0, 1);
+/// let b = Ipv4Addr::new(10, 0, 3, 1);
+/// let prefix: ipnet::Ipv4Net = common_prefix(&a, &b).unwrap();
+/// assert_eq!("10.0.0.0/22", prefix.to_string());
+/// assert_eq!(None, common_prefix::<Ipv4Addr>(&a, &b));
+/// ```
+pub fn common_prefix<P: Prefix>(a: &P::Address, b: &P::Address) -> Option<P> {
+    let length = a.common_prefix_len(b);
+    let network: UI = (*a).into();
+    let network = network & MASKS[length as usize];
+    let prefix = unsafe { P::new_unchecked(network.into(), length) };
+    match prefix.length() == length {
+        true => Some(prefix),
+        false => None,
+    }
+}
+
+/// returns only the maximal prefixes from the given ones, dropping each prefix that is contained
+/// in another. Duplicates are kept once. The result is sorted by network address. Unlike full
+/// aggregation, adjacent prefixes are not merged.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::without_subsets;
+/// let p = |s: &str| s.parse::<ipnet::Ipv4Net>().unwrap();
+/// let prefixes = [p("10.0.1.0/24"), p("10.0.0.0/16"), p("192.168.0.0/24"), p("10.0.0.0/16")];
+/// assert_eq!(vec![p("10.0.0.0/16"), p("192.168.0.0/24")], without_subsets(prefixes));
+/// ```
+pub fn without_subsets<P, I>(prefixes: I) -> Vec<P>
+where
+    P: Prefix,
+    I: IntoIterator<Item = P>,
+{
+    let mut prefixes: Vec<P> = prefixes.into_iter().collect();
+    prefixes.sort_by_key(|p| (p.network(), p.length()));
+    let mut result: Vec<P> = Vec::with_capacity(prefixes.len());
+    for prefix in prefixes {
+        match result.last() {
+            Some(last) if Set::contains(last, &prefix) => {}
+            _ => result.push(prefix),
+        }
+    }
+    result
+}
+
+/// returns only the minimal prefixes from the given ones, dropping each prefix that contains
+/// another. Duplicates are kept once. The result is sorted by network address.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::without_supersets;
+/// let p = |s: &str| s.parse::<ipnet::Ipv4Net>().unwrap();
+/// let prefixes = [p("10.0.1.0/24"), p("10.0.0.0/16"), p("192.168.0.0/24"), p("10.0.2.0/24")];
+/// assert_eq!(
+///     vec![p("10.0.1.0/24"), p("10.0.2.0/24"), p("192.168.0.0/24")],
+///     without_supersets(prefixes)
+/// );
+/// ```
+pub fn without_supersets<P, I>(prefixes: I) -> Vec<P>
+where
+    P: Prefix,
+    I: IntoIterator<Item = P>,
+{
+    let mut prefixes: Vec<P> = prefixes.into_iter().collect();
+    prefixes.sort_by_key(|p| (p.network(), p.length()));
+    let mut result = Vec::with_capacity(prefixes.len());
+    // the prefixes containing the current one, each marked when it is found to contain another
+    let mut stack: Vec<(P, bool)> = vec![];
+    for prefix in prefixes {
+        while let Some((top, _)) = stack.last() {
+            if Set::contains(top, &prefix) {
+                break;
+            }
+            if let Some((top, false)) = stack.pop() {
+                result.push(top);
+            }
+        }
+        match stack.last() {
+            Some((top, _)) if top.length() == prefix.length() => continue,
+            _ => {}
+        }
+        stack.iter_mut().for_each(|(_, covers)| *covers = true);
+        stack.push((prefix, false));
+    }
+    result.extend(
+        stack
+            .into_iter()
+            .filter(|(_, covers)| !covers)
+            .map(|(p, _)| p),
+    );
+    result
+}
+
+/// returns every pair of the given prefixes that overlap, as `(container, contained)`, sorted by
+/// the contained prefix and then the container. Prefixes only overlap when one contains the other
+/// so this finds every conflict in a list which should be disjoint, like the networks in a router
+/// config or an import into IPAM. Duplicates are reported as a pair where either could be the
+/// container.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::overlapping_pairs;
+/// let p = |s: &str| s.parse::<ipnet::Ipv4Net>().unwrap();
+/// let prefixes = [p("10.0.1.0/24"), p("10.0.0.0/16"), p("192.168.0.0/24"), p("10.0.1.7/32")];
+/// assert_eq!(
+///     vec![
+///         (p("10.0.0.0/16"), p("10.0.1.0/24")),
+///         (p("10.0.0.0/16"), p("10.0.1.7/32")),
+///         (p("10.0.1.0/24"), p("10.0.1.7/32")),
+///     ],
+///     overlapping_pairs(prefixes)
+/// );
+/// ```
+pub fn overlapping_pairs<P, I>(prefixes: I) -> Vec<(P, P)>
+where
+    P: Prefix + Clone,
+    I: IntoIterator<Item = P>,
+{
+    let mut prefixes: Vec<P> = prefixes.into_iter().collect();
+    prefixes.sort_by_key(|p| (p.network(), p.length()));
+
+    let mut pairs = vec![];
+    // prefixes sorted this way will only overlap prefixes that contain them so a stack of nested
+    // containers is enough to find every overlapping pair
+    let mut containers: Vec<&P> = vec![];
+    for prefix in &prefixes {
+        while let Some(container) = containers.last() {
+            match container.broadcast() < prefix.network() {
+                true => containers.pop(),
+                false => break,
+            };
+        }
+        for container in &containers {
+            pairs.push(((*container).clone(), prefix.clone()));
+        }
+        containers.push(prefix);
+    }
+    pairs
+}
+
+/// aggregates the given prefixes into the fewest prefixes that together cover exactly the same
+/// addresses, in order, like the `aggregate6` tool. Duplicates and prefixes contained in others
+/// are dropped and adjacent prefixes are merged. If `max_length` is given, prefixes longer than
+/// it are discarded before aggregating. Any bits set in the host part of a prefix are ignored.
+/// When the prefix type is an [`Address`], which can't hold a merged block, the addresses are
+/// returned sorted and deduplicated.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::aggregate;
+/// let p = |s: &str| s.parse::<ipnet::Ipv4Net>().unwrap();
+/// let prefixes = [p("10.0.1.0/24"), p("10.0.0.0/24"), p("10.0.0.64/26"), p("192.168.0.1/32")];
+/// let aggregated: Vec<ipnet::Ipv4Net> = aggregate(prefixes, None).collect();
+/// assert_eq!(vec![p("10.0.0.0/23"), p("192.168.0.1/32")], aggregated);
+///
+/// let aggregated: Vec<ipnet::Ipv4Net> = aggregate(prefixes, Some(24)).collect();
+/// assert_eq!(vec![p("10.0.0.0/23")], aggregated);
+/// ```
+pub fn aggregate<P, I>(prefixes: I, max_length: Option<u8>) -> impl Iterator<Item = P>
+where
+    P: Prefix,
+    I: IntoIterator<Item = P>,
+{
+    let max_length = max_length.unwrap_or(P::Address::BITS);
+    let mut blocks: Vec<(UI, UI)> = prefixes
+        .into_iter()
+        .filter(|p| p.length() <= max_length)
+        .map(|p| (p.network().into(), p.broadcast().into()))
+        .collect();
+    blocks.sort_unstable();
+
+    // merge overlapping and adjacent blocks into the ranges they cover
+    let mut ranges: Vec<(UI, UI)> = Vec::with_capacity(blocks.len());
+    for (first, last) in blocks {
+        match ranges.last_mut() {
+            Some((_, end)) if first <= end.saturating_add(1) => *end = (*end).max(last),
+            _ => ranges.push((first, last)),
+        }
+    }
+    to_prefixes(
+        ranges
+            .into_iter()
+            .flat_map(|(first, last)| covering_prefixes(first, last)),
+    )
+}
+
+/// counts how many addresses from the given addresses or prefixes fall into each prefix of the
+/// given length, the aggregation behind heatmaps of the address space. Buckets are returned in
+/// order and only those with a non-zero count are included. Addresses that appear more than once
+/// are counted each time. If >32 is passed for length, or a length that the bucket type can't
+/// hold, like anything but 32 for an address type, then [`Error::InvalidLength`] is returned.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::bucket_counts;
+/// # use std::net::Ipv4Addr;
+/// let addresses = [
+///     Ipv4Addr::new(10, 0, 0, 1),
+///     Ipv4Addr::new(10, 0, 0, 2),
+///     Ipv4Addr::new(10, 0, 2, 1),
+/// ];
+/// let buckets: Vec<(ipnet::Ipv4Net, u64)> = bucket_counts(addresses, 24).unwrap();
+/// assert_eq!(
+///     vec![("10.0.0.0/24".parse().unwrap(), 2), ("10.0.2.0/24".parse().unwrap(), 1)],
+///     buckets
+/// );
+/// ```
+pub fn bucket_counts<P, Q, I>(items: I, length: u8) -> Result<Vec<(Q, u64)>>
+where
+    P: Prefix,
+    Q: Prefix<Address = P::Address>,
+    I: IntoIterator<Item = P>,
+{
+    let mask = *MASKS.get(length as usize).ok_or(Error::InvalidLength)?;
+    // the buckets would lose their length if the type can't hold it
+    if unsafe { Q::new_unchecked(0.into(), length) }.length() != length {
+        return Err(Error::InvalidLength);
+    }
+    let mut counts = std::collections::BTreeMap::<UI, u64>::new();
+    for item in items {
+        let first: UI = item.network().into();
+        match item.length() < length {
+            // the item covers several whole buckets
+            true => {
+                let per_bucket = 1u64 << (32 - length);
+                for bucket in coarsen::<Q>(first, item.broadcast().into(), length)? {
+                    *counts.entry(bucket.address().into()).or_default() += per_bucket;
+                }
+            }
+            false => {
+                let size = 1u64 << (32 - item.length());
+                *counts.entry(first & mask).or_default() += size;
+            }
+        }
+    }
+    Ok(counts
+        .into_iter()
+        .map(|(bucket, count)| unsafe { (Q::new_unchecked(bucket.into(), length), count) })
+        .collect())
+}
+
+// returns true if every address of `inner` inside of the block is also in `outer`
+fn contains_within<S1, S2>(outer: &S1, inner: &S2, block: Block<S1::Address>) -> bool
+where
+    S1: Set + ?Sized,
+    S2: Set<Address = S1::Address>,
+{
+    if !inner.overlaps(&block) || outer.contains(&block) {
+        return true;
+    }
+    if !outer.overlaps(&block) || inner.contains(&block) {
+        return false;
+    }
+    // both only partly cover the block so it can't be a single address and must have halves
+    match block.halves() {
+        Some((left, right)) => {
+            contains_within(outer, inner, left) && contains_within(outer, inner, right)
+        }
+        None => true,
+    }
+}
+
+// collects, in order, the runs of prefixes of the given length inside the block that intersect
+// the set, each as the index of its first prefix and the number of prefixes in it
+#[cfg(feature = "rand")]
+fn used_runs<S>(set: &S, block: Block<S::Address>, length: u8, runs: &mut Vec<(u64, u64)>)
+where
+    S: Set + ?Sized,
+{
+    if !set.overlaps(&block) {
+        return;
+    }
+    if block.length() < length && !set.contains(&block) {
+        // the block is only partly used and is longer than a prefix, so it must have halves
+        if let Some((left, right)) = block.halves() {
+            used_runs(set, left, length, runs);
+            used_runs(set, right, length, runs);
+        }
+        return;
+    }
+    let first = u64::from(block.network().into()) >> (32 - u32::from(length));
+    let n = 1u64 << (length - block.length());
+    match runs.last_mut() {
+        Some((previous, count)) if *previous + *count == first => *count += n,
+        _ => runs.push((first, n)),
+    }
+}
+
+// collects, in order, the largest blocks inside the given one that are wholly contained in the set,
+// each as its network and length, leaving out any longer than the given length. With `partial`,
+// blocks of the given length that the set only partly covers are collected too.
+fn covered_blocks<S>(
+    set: &S,
+    block: Block<S::Address>,
+    length: u8,
+    partial: bool,
+    blocks: &mut Vec<(UI, u8)>,
+) where
+    S: Set + ?Sized,
+{
+    if length < block.length() || !set.overlaps(&block) {
+        return;
+    }
+    if set.contains(&block) || (partial && block.length() == length) {
+        blocks.push((block.network().into(), block.length()));
+        return;
+    }
+    // the set only partly covers the block so it can't be a single address and must have halves
+    if let Some((left, right)) = block.halves() {
+        covered_blocks(set, left, length, partial, blocks);
+        covered_blocks(set, right, length, partial, blocks);
+    }
+}
+
+// returns every aligned prefix of the given length in the given blocks, each given by its network
+// and length
+fn aligned_prefixes<P: Prefix>(
+    blocks: impl Iterator<Item = (UI, u8)>,
+    length: u8,
+) -> Result<impl Iterator<Item = P>> {
+    if P::Address::BITS < length {
+        return Err(Error::InvalidLength);
+    }
+    let shift = 32 - u32::from(length);
+    Ok(blocks
+        .filter(move |&(_, block)| block <= length)
+        .flat_map(move |(network, block)| {
+            (0..1u64 << (length - block)).map(move |i| {
+                let network = (u64::from(network) + (i << shift)) as UI;
+                unsafe { P::new_unchecked(network.into(), length) }
+            })
+        }))
+}
+
+// builds prefixes from blocks, each given by its network and length. A block that the prefix type
+// can't represent, like any block bigger than one address when it is an address, is split into
+// the single addresses in it so that no address is lost.
+fn to_prefixes<P: Prefix>(blocks: impl Iterator<Item = (UI, u8)>) -> impl Iterator<Item = P> {
+    blocks.flat_map(|(network, length)| {
+        let prefix = unsafe { P::new_unchecked(network.into(), length) };
+        let (prefix, count) = match prefix.length() == length {
+            true => (Some(prefix), 0),
+            false => (None, 1u64 << (32 - length)),
+        };
+        prefix.into_iter().chain((0..count).map(move |i| {
+            let address = (u64::from(network) + i) as UI;
+            unsafe { P::new_unchecked(address.into(), 32) }
+        }))
+    })
+}
+
+// returns the fewest prefixes, as network addresses and lengths, that together cover exactly the
+// addresses from first to last, in order
+fn covering_prefixes(first: UI, last: UI) -> impl Iterator<Item = (UI, u8)> {
+    let mut next = (first <= last).then_some(u64::from(first));
+    std::iter::from_fn(move || {
+        let start = next?;
+        // the largest aligned block starting here that doesn't go past the last address
+        let mut bits = start.trailing_zeros().min(32);
+        while start + (1 << bits) - 1 > u64::from(last) {
+            bits -= 1;
+        }
+        let end = start + (1 << bits);
+        next = (end <= u64::from(last)).then_some(end);
+        Some((start as UI, (32 - bits) as u8))
+    })
+}
+
+// returns the reverse DNS zone name for the network of the given length
+fn reverse_zone_name(network: UI, length: u8) -> String {
+    let octets = network.to_be_bytes();
+    let whole = usize::from(length / 8);
+    let mut name = String::new();
+    if !length.is_multiple_of(8) {
+        name += &format!("{}/{length}.", octets[whole]);
+    }
+    for octet in octets[..whole].iter().rev() {
+        name += &format!("{octet}.");
+    }
+    name + "in-addr.arpa"
+}
+
+// returns every prefix of the given length intersecting the addresses from first to last
+fn coarsen<P: Prefix>(first: UI, last: UI, length: u8) -> Result<Vec<P>> {
+    let mask = *MASKS.get(length as usize).ok_or(Error::InvalidLength)?;
+    let shift = 32 - u32::from(length);
+    let (first, last) = (
+        u64::from(first & mask) >> shift,
+        u64::from(last & mask) >> shift,
+    );
+    Ok((first..=last)
+        .map(|i| unsafe { P::new_unchecked(((i << shift) as UI).into(), length) })
+        .collect())
 }
 
 // https://stackoverflow.com/questions/53204327/how-to-have-a-private-part-of-a-trait
 mod prefix_private;
 
+mod format;
+
+mod hilbert;
+
+mod special;
+
+mod block;
+use block::Block;
+
+/// pack addresses and prefixes into type-length-value byte layouts
+pub mod tlv;
+
+/// display prefixes and ranges in alternate notations
+pub mod display;
+
+/// well-known blocks of the address space
+pub mod sets;
+
+mod addr;
+pub use addr::Addr;
+
+mod addresses;
+pub use addresses::Addresses;
+
+mod shuffled;
+pub use shuffled::Shuffled;
+
+mod bits;
+pub use bits::Bits;
+
+mod pfx;
+pub use pfx::Pfx;
+
+mod subnet;
+pub use subnet::Subnet;
+
+mod interface;
+pub use interface::Interface;
+
+mod netmask;
+pub use netmask::Netmask;
+
+mod wildcard;
+pub use wildcard::Wildcard;
+
+mod prefix_length;
+pub use prefix_length::PrefixLength;
+
+mod address_range;
+pub use address_range::AddressRange;
+
+mod ip_set;
+pub use ip_set::{IpSet, IpSetIter};
+
+mod interner;
+pub use interner::{Id, Interner};
+
+mod walker;
+pub use walker::Walker;
+
+mod cursor;
+pub use cursor::Cursor;
+
+mod prefix_list;
+pub use prefix_list::{parse_prefixes, LineError};
+
+mod parse_mode;
+pub use parse_mode::ParseMode;
+
 /// implements traits for external types
 pub mod implementations;
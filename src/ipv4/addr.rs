@@ -0,0 +1,261 @@
+use std::{fmt, net::Ipv4Addr, str::FromStr};
+
+use crate::{
+    Error,
+    ParseErrorKind::{self, *},
+};
+
+/// A concrete IPv4 address provided by this crate so that it can be used out of the box without
+/// implementing [`super::Address`] for a type of your own.
+///
+/// It is stored as a single [`u32`] so it is as small as [`std::net::Ipv4Addr`] and comparisons
+/// follow the natural numeric order of addresses. Converting to and from [`std::net::Ipv4Addr`]
+/// is free.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::Addr;
+/// const GATEWAY: Addr = Addr::new(192, 168, 0, 1);
+/// assert_eq!("192.168.0.1", GATEWAY.to_string());
+/// assert_eq!(GATEWAY, "192.168.0.1".parse().unwrap());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Addr(u32);
+
+impl Addr {
+    /// the unspecified address, 0.0.0.0
+    pub const UNSPECIFIED: Self = Self::new(0, 0, 0, 0);
+
+    /// the limited broadcast address, 255.255.255.255
+    pub const BROADCAST: Self = Self::new(255, 255, 255, 255);
+
+    /// the loopback address, 127.0.0.1
+    pub const LOCALHOST: Self = Self::new(127, 0, 0, 1);
+
+    /// returns a new address from its four octets in network order
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Addr;
+    /// let address = Addr::new(10, 224, 24, 1);
+    /// assert_eq!(0x0ae01801u32, address.into());
+    /// ```
+    pub const fn new(a: u8, b: u8, c: u8, d: u8) -> Self {
+        Self(u32::from_be_bytes([a, b, c, d]))
+    }
+
+    /// returns a new address from its numeric value
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Addr;
+    /// assert_eq!(Addr::new(10, 224, 24, 1), Addr::from_bits(0x0ae01801));
+    /// ```
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// returns the numeric value of the address
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Addr;
+    /// assert_eq!(0x0ae01801, Addr::new(10, 224, 24, 1).to_bits());
+    /// ```
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    /// parses an address in strict dotted-decimal notation in a const context, returning [`None`]
+    /// if it is invalid. The [`addr!`](crate::addr) macro uses this to check literals at compile
+    /// time.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Addr;
+    /// const GATEWAY: Option<Addr> = Addr::parse_const("192.168.0.1");
+    /// assert_eq!(Some(Addr::new(192, 168, 0, 1)), GATEWAY);
+    /// assert_eq!(None, Addr::parse_const("192.168.0.256"));
+    /// ```
+    pub const fn parse_const(s: &str) -> Option<Self> {
+        match parse_partial(s.as_bytes()) {
+            Ok((bits, consumed)) if consumed == s.len() => Some(Self(bits)),
+            _ => None,
+        }
+    }
+
+    /// returns the bytes of the address in network order
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Addr;
+    /// assert_eq!([10, 224, 24, 1], Addr::new(10, 224, 24, 1).octets());
+    /// ```
+    pub const fn octets(&self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+}
+
+impl super::Address for Addr {
+    const UNSPECIFIED: Self = Addr::UNSPECIFIED;
+    const BROADCAST: Self = Addr::BROADCAST;
+    const LOCALHOST: Self = Addr::LOCALHOST;
+
+    fn octets(&self) -> [u8; 4] {
+        Addr::octets(self)
+    }
+}
+
+impl From<u32> for Addr {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Addr> for u32 {
+    fn from(value: Addr) -> Self {
+        value.0
+    }
+}
+
+impl From<[u8; 4]> for Addr {
+    fn from(value: [u8; 4]) -> Self {
+        Self(u32::from_be_bytes(value))
+    }
+}
+
+impl From<Addr> for [u8; 4] {
+    fn from(value: Addr) -> Self {
+        value.octets()
+    }
+}
+
+impl From<Ipv4Addr> for Addr {
+    fn from(value: Ipv4Addr) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<Addr> for Ipv4Addr {
+    fn from(value: Addr) -> Self {
+        value.0.into()
+    }
+}
+
+impl fmt::Display for Addr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&Ipv4Addr::from(*self), f)
+    }
+}
+
+impl fmt::Debug for Addr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for Addr {
+    type Err = crate::Error;
+
+    /// parses an address in strict dotted-decimal notation, accepting exactly what
+    /// [`std::net::Ipv4Addr`] does, without allocating or going through it
+    fn from_str(s: &str) -> crate::Result<Self> {
+        parse_strict(s).map(Self)
+    }
+}
+
+// parses a whole string as a dotted-decimal address
+pub(super) fn parse_strict(s: &str) -> crate::Result<u32> {
+    match parse_partial(s.as_bytes()) {
+        Ok((bits, consumed)) if consumed == s.len() => Ok(bits),
+        Ok((_, consumed)) => Err(Error::parse_error_at(TrailingCharacters, consumed, s)),
+        Err((kind, offset)) => Err(Error::parse_error_at(kind, offset, s)),
+    }
+}
+
+// parses a dotted-decimal address from the front of the given bytes and returns it with the
+// number of bytes consumed. Octets must be decimal numbers from 0 to 255 without leading zeros.
+// On failure, it returns what was wrong and where.
+pub(super) const fn parse_partial(bytes: &[u8]) -> Result<(u32, usize), (ParseErrorKind, usize)> {
+    let mut bits = 0u32;
+    let mut i = 0;
+    let mut octet = 0;
+    while octet < 4 {
+        if octet > 0 {
+            if i >= bytes.len() || bytes[i] != b'.' {
+                return Err((ExpectedDot, i));
+            }
+            i += 1;
+        }
+        let start = i;
+        let mut n = 0u32;
+        while i < bytes.len() && i - start < 3 && bytes[i].is_ascii_digit() {
+            n = n * 10 + (bytes[i] - b'0') as u32;
+            i += 1;
+        }
+        let digits = i - start;
+        if digits == 0 || n > 255 || (digits > 1 && bytes[start] == b'0') {
+            return Err((BadOctet, start));
+        }
+        if i < bytes.len() && bytes[i].is_ascii_digit() {
+            return Err((BadOctet, start));
+        }
+        bits = bits << 8 | n;
+        octet += 1;
+    }
+    Ok((bits, i))
+}
+
+macro_rules! impl_fmt {
+    ($($t:ident),*) => {
+        $(
+            impl fmt::$t for Addr {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::$t::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+}
+
+// formats the numeric value of the address, e.g. `format!("{:08x}", address)`
+impl_fmt!(Binary, Octal, LowerHex, UpperHex);
+
+impl std::ops::BitAnd for Addr {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl std::ops::BitOr for Addr {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::Not for Addr {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        Self(!self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Addr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Addr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,304 @@
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+use super::{format, Addr, Address, Addresses, Prefix, Set, UI};
+use crate::{Error, Result};
+
+/// An inclusive range of addresses from `first` to `last`.
+///
+/// Unlike [`RangeInclusive`], an AddressRange can never be empty: `first` is checked to be less
+/// than or equal to `last` when it is created. It can be parsed from and displayed in the common
+/// `first-last` notation. When parsing, the shorthand found in firewall and DHCP configurations
+/// where only the trailing octets of the last address are given, e.g. `10.0.0.10-50`, is accepted
+/// too. The [`Set`] implementation of [`RangeInclusive`] is kept for
+/// compatibility.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{Addr, AddressRange, Set};
+/// let range: AddressRange = "10.0.0.10-10.0.0.50".parse().unwrap();
+/// assert_eq!(Addr::new(10, 0, 0, 10), range.first());
+/// assert_eq!(Addr::new(10, 0, 0, 50), range.last());
+/// assert_eq!(41, range.len());
+/// assert!(range.contains(&Addr::new(10, 0, 0, 20)));
+/// assert_eq!("10.0.0.10-10.0.0.50", range.to_string());
+/// assert_eq!(range, "10.0.0.10-50".parse().unwrap());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct AddressRange<A: Address = Addr> {
+    first: A,
+    last: A,
+}
+
+impl<A: Address> AddressRange<A> {
+    /// returns the range of addresses from `first` to `last`, inclusive. If `first` is greater
+    /// than `last` then [`Error::InvalidRange`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, AddressRange};
+    /// let range = AddressRange::new(Addr::new(10, 0, 0, 1), Addr::new(10, 0, 0, 1)).unwrap();
+    /// assert_eq!(1, range.len());
+    /// assert!(AddressRange::new(Addr::new(10, 0, 0, 1), Addr::new(10, 0, 0, 0)).is_err());
+    /// ```
+    pub fn new(first: A, last: A) -> Result<Self> {
+        match first <= last {
+            true => Ok(Self { first, last }),
+            false => Err(Error::InvalidRange),
+        }
+    }
+
+    /// parses the glob notation taken by scanners like nmap where trailing octets are given as
+    /// `*` to match any value, e.g. `10.0.*.*`. The last octet that isn't a `*` may also be given
+    /// as a range, e.g. `10.0.0-3.*` or `10.0.0.10-50`.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::AddressRange;
+    /// let range: AddressRange = AddressRange::parse_glob("10.0.0-3.*").unwrap();
+    /// assert_eq!("10.0.0.0-10.0.3.255", range.to_string());
+    /// assert!(AddressRange::<addrs::ipv4::Addr>::parse_glob("10.*.0.*").is_err());
+    /// ```
+    pub fn parse_glob(s: &str) -> Result<Self> {
+        let (first, last) = parse_glob(s)?;
+        Self::new(A::from(first), A::from(last))
+    }
+
+    /// returns the range of addresses contained in the given prefix, ignoring its host bits
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{AddressRange, Pfx};
+    /// let prefix: Pfx = "10.0.0.1/24".parse().unwrap();
+    /// let range = AddressRange::from_prefix(&prefix);
+    /// assert_eq!("10.0.0.0-10.0.0.255", range.to_string());
+    /// ```
+    pub fn from_prefix<P: Prefix<Address = A>>(prefix: &P) -> Self {
+        Self {
+            first: prefix.network(),
+            last: prefix.broadcast(),
+        }
+    }
+
+    /// returns the first address in the range
+    pub fn first(&self) -> A {
+        self.first
+    }
+
+    /// returns the last address in the range
+    pub fn last(&self) -> A {
+        self.last
+    }
+
+    /// returns the number of addresses in the range. Unlike [`Set::num_addresses`], this can count
+    /// the entire IPv4 address space.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, AddressRange};
+    /// let all = AddressRange::new(Addr::UNSPECIFIED, Addr::BROADCAST).unwrap();
+    /// assert_eq!(1 << 32, all.len());
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u64 {
+        let first: u32 = self.first.into();
+        let last: u32 = self.last.into();
+        (last - first) as u64 + 1
+    }
+
+    /// returns an iterator, in order, over every address in the range
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, AddressRange};
+    /// let range: AddressRange = "10.0.0.254-10.0.1.0".parse().unwrap();
+    /// assert_eq!(
+    ///     vec![Addr::new(10, 0, 0, 254), Addr::new(10, 0, 0, 255), Addr::new(10, 0, 1, 0)],
+    ///     range.addresses().collect::<Vec<_>>()
+    /// );
+    /// ```
+    pub fn addresses(&self) -> Addresses<A> {
+        super::addresses(&self.as_range_i())
+    }
+
+    /// returns an iterator, in order, over the fewest prefixes that together cover exactly the
+    /// range. See [`super::summarize_range`]. For the prefixes of a single length, call
+    /// [`Set::prefixes`] as `Set::prefixes(&range, length)`.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{AddressRange, Pfx};
+    /// let range: AddressRange = "10.0.0.0-10.0.0.191".parse().unwrap();
+    /// let prefixes: Vec<Pfx> = range.prefixes().collect();
+    /// assert_eq!("[10.0.0.0/25, 10.0.0.128/26]", format!("{prefixes:?}"));
+    /// ```
+    pub fn prefixes<P: Prefix<Address = A>>(&self) -> impl Iterator<Item = P> {
+        super::summarize_range(&self.as_range_i())
+    }
+
+    /// returns the smallest single prefix that contains the whole range. See
+    /// [`super::spanning_prefix`]. A range is never empty so [`None`] is only returned when `P`
+    /// is an address type and the range holds more than one address.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, AddressRange, Pfx};
+    /// let range: AddressRange = "10.0.0.100-10.0.1.5".parse().unwrap();
+    /// let prefix: Pfx = range.spanning_prefix().unwrap();
+    /// assert_eq!("10.0.0.0/23", prefix.to_string());
+    /// assert_eq!(None, range.spanning_prefix::<Addr>());
+    /// ```
+    pub fn spanning_prefix<P: Prefix<Address = A>>(&self) -> Option<P> {
+        super::spanning_prefix(&self.as_range_i())
+    }
+
+    /// returns a [`Rendering`](super::display::Rendering) to pick an alternate notation to display
+    /// the range in
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::AddressRange;
+    /// let range: AddressRange = "10.0.0.0-10.0.0.191".parse().unwrap();
+    /// assert_eq!("10.0.0.0/25, 10.0.0.128/26", range.display().as_cidr().to_string());
+    /// assert_eq!(
+    ///     "10.0.0.0 0.0.0.127, 10.0.0.128 0.0.0.63",
+    ///     range.display().as_wildcard().to_string()
+    /// );
+    /// ```
+    pub fn display(&self) -> super::display::Rendering<A> {
+        super::display::Rendering::range(self.first, self.last)
+    }
+
+    /// returns the equivalent [`RangeInclusive`]
+    pub fn as_range_i(&self) -> RangeInclusive<A> {
+        self.first..=self.last
+    }
+}
+
+impl<A: Address> Set for AddressRange<A> {
+    type Address = A;
+
+    fn num_prefixes(&self, length: u8) -> Result<u32> {
+        self.as_range_i().num_prefixes(length)
+    }
+
+    fn num_prefixes_u64(&self, length: u8) -> Result<u64> {
+        self.as_range_i().num_prefixes_u64(length)
+    }
+
+    fn contains<P: Prefix<Address = A>>(&self, other: &P) -> bool {
+        Set::contains(&self.as_range_i(), other)
+    }
+
+    fn overlaps<P: Prefix<Address = A>>(&self, other: &P) -> bool {
+        self.as_range_i().overlaps(other)
+    }
+
+    fn coarsen<P: Prefix<Address = A>>(&self, length: u8) -> Result<Vec<P>> {
+        self.as_range_i().coarsen(length)
+    }
+
+    fn prefixes<P: Prefix<Address = A>>(&self, length: u8) -> Result<impl Iterator<Item = P>> {
+        let blocks = super::covering_prefixes(self.first.into(), self.last.into());
+        super::aligned_prefixes(blocks, length)
+    }
+
+    fn iter_addresses(&self) -> impl Iterator<Item = A> {
+        self.addresses()
+    }
+}
+
+impl<A: Address> TryFrom<RangeInclusive<A>> for AddressRange<A> {
+    type Error = Error;
+
+    fn try_from(value: RangeInclusive<A>) -> Result<Self> {
+        Self::new(*value.start(), *value.end())
+    }
+}
+
+impl<A: Address> From<AddressRange<A>> for RangeInclusive<A> {
+    fn from(value: AddressRange<A>) -> Self {
+        value.as_range_i()
+    }
+}
+
+impl<A: Address> fmt::Display for AddressRange<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0u8; 2 * super::MAX_ADDRESS_LEN + 1];
+        // the buffer is always big enough
+        let len = format::write_range(self.first.octets(), self.last.octets(), &mut buf).unwrap();
+        f.pad(format::as_str(&buf, len))
+    }
+}
+
+impl<A: Address> fmt::Debug for AddressRange<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<A> FromStr for AddressRange<A>
+where
+    A: Address,
+    <A as FromStr>::Err: std::error::Error + Send + Sync + 'static,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // parses an octet as strictly as Ipv4Addr does, with only digits and no leading zeros
+        fn octet(s: &str) -> Result<u8> {
+            let digits = !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+            match digits && (s == "0" || !s.starts_with('0')) {
+                true => s.parse().map_err(Error::parse_error),
+                false => Err(Error::ParseError(None)),
+            }
+        }
+
+        let (first, last) = s.split_once('-').ok_or(Error::ParseError(None))?;
+        let first = first.parse::<A>().map_err(Error::parse_error)?;
+        let last = match last.matches('.').count() {
+            3.. => last.parse::<A>().map_err(Error::parse_error)?,
+            // shorthand which only gives the trailing octets that differ from the first address
+            dots => {
+                let mut octets = first.octets();
+                for (o, s) in octets[3 - dots..].iter_mut().zip(last.split('.')) {
+                    *o = octet(s)?;
+                }
+                octets.into()
+            }
+        };
+        Self::new(first, last)
+    }
+}
+
+// parses glob notation into the numeric values of the first and last addresses it matches
+pub(super) fn parse_glob(s: &str) -> Result<(UI, UI)> {
+    fn octet(s: &str) -> Result<UI> {
+        match !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            true => Ok(s.parse::<u8>().map_err(Error::parse_error)?.into()),
+            false => Err(Error::ParseError(None)),
+        }
+    }
+
+    let (mut first, mut last) = (0, 0);
+    let mut fixed = true;
+    let mut octets = 0;
+    for s in s.split('.') {
+        let (lo, hi) = match (s, s.split_once('-')) {
+            ("*", _) => (0, 255),
+            (_, _) if !fixed => return Err(Error::ParseError(None)),
+            (_, Some((lo, hi))) => (octet(lo)?, octet(hi)?),
+            (_, None) => octet(s).map(|o| (o, o))?,
+        };
+        if lo > hi {
+            return Err(Error::InvalidRange);
+        }
+        fixed = lo == hi;
+        first = first << 8 | lo;
+        last = last << 8 | hi;
+        octets += 1;
+    }
+    match octets {
+        4 => Ok((first, last)),
+        _ => Err(Error::ParseError(None)),
+    }
+}
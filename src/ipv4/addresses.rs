@@ -0,0 +1,89 @@
+use std::{iter::FusedIterator, marker::PhantomData};
+
+use super::{Address, UI};
+
+/// Iterates, in order, over every address from a first to a last address, inclusive. Use
+/// [`Prefix::addresses`](super::Prefix::addresses),
+/// [`AddressRange::addresses`](super::AddressRange::addresses) or
+/// [`addresses`](super::addresses()) to get one. It can be walked from either end. It is an
+/// [`ExactSizeIterator`] on targets where [`usize`] is wide enough to count all 2<sup>32</sup>
+/// addresses.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{Addr, Pfx, Prefix};
+/// let prefix: Pfx = "10.0.0.0/30".parse().unwrap();
+/// let mut addresses = prefix.addresses();
+/// assert_eq!(4, addresses.len());
+/// assert_eq!(Some(Addr::new(10, 0, 0, 0)), addresses.next());
+/// assert_eq!(Some(Addr::new(10, 0, 0, 3)), addresses.next_back());
+/// assert_eq!(2, addresses.len());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Addresses<A: Address> {
+    // kept as u64 so that the whole address space can be counted and `front == back` is empty
+    front: u64,
+    back: u64,
+    address: PhantomData<A>,
+}
+
+impl<A: Address> Addresses<A> {
+    pub(super) fn new(first: A, last: A) -> Self {
+        let first: UI = first.into();
+        let last: UI = last.into();
+        Self {
+            front: first.into(),
+            back: u64::from(last) + 1,
+            address: PhantomData,
+        }
+    }
+
+    fn remaining(&self) -> u64 {
+        self.back.saturating_sub(self.front)
+    }
+}
+
+impl<A: Address> Iterator for Addresses<A> {
+    type Item = A;
+
+    fn next(&mut self) -> Option<A> {
+        match self.front < self.back {
+            true => {
+                let next = A::from(self.front as UI);
+                self.front += 1;
+                Some(next)
+            }
+            false => None,
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<A> {
+        self.front = self.front.saturating_add(n as u64).min(self.back);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining()) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl<A: Address> DoubleEndedIterator for Addresses<A> {
+    fn next_back(&mut self) -> Option<A> {
+        match self.front < self.back {
+            true => {
+                self.back -= 1;
+                Some(A::from(self.back as UI))
+            }
+            false => None,
+        }
+    }
+}
+
+// every count fits when usize has at least 64 bits
+#[cfg(target_pointer_width = "64")]
+impl<A: Address> ExactSizeIterator for Addresses<A> {}
+
+impl<A: Address> FusedIterator for Addresses<A> {}
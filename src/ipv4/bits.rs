@@ -0,0 +1,39 @@
+use std::fmt;
+
+use super::{Address, UI};
+
+/// Formats any [`Address`] as its numeric value with the integer formatting traits, honoring all
+/// of their flags, e.g. `{:08x}` or `{:#034b}`. Use [`Address::as_bits`] to get one. This is for
+/// address types, like [`std::net::Ipv4Addr`], that don't implement those traits themselves.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::Address;
+/// # use std::net::Ipv4Addr;
+/// let mask = Ipv4Addr::new(255, 255, 252, 0);
+/// assert_eq!("fffffc00", format!("{:x}", mask.as_bits()));
+/// assert_eq!("0b11111111111111111111110000000000", format!("{:#b}", mask.as_bits()));
+/// ```
+#[derive(Clone, Copy)]
+pub struct Bits<A: Address>(A);
+
+impl<A: Address> Bits<A> {
+    pub(super) fn new(address: A) -> Self {
+        Self(address)
+    }
+}
+
+macro_rules! impl_fmt {
+    ($($t:ident),*) => {
+        $(
+            impl<A: Address> fmt::$t for Bits<A> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let ui: UI = self.0.into();
+                    fmt::$t::fmt(&ui, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt!(Binary, Octal, LowerHex, UpperHex);
@@ -0,0 +1,44 @@
+use std::{fmt, str::FromStr};
+
+use super::{Address, Prefix};
+use crate::{Error, Result};
+
+// A minimal prefix for any address type, used internally where an algorithm needs prefixes but
+// the caller only supplied an address type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct Block<A: Address> {
+    address: A,
+    length: u8,
+}
+
+impl<A: Address> Prefix for Block<A> {
+    type Address = A;
+
+    fn address(&self) -> A {
+        self.address
+    }
+    fn length(&self) -> u8 {
+        self.length
+    }
+
+    unsafe fn new_unchecked(address: A, length: u8) -> Self {
+        Self { address, length }
+    }
+}
+
+impl<A: Address> fmt::Display for Block<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.address.to_string(), self.length)
+    }
+}
+
+impl<A: Address> FromStr for Block<A> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (address, length) = s.split_once('/').ok_or(Error::ParseError(None))?;
+        let address = address.parse().or(Err(Error::ParseError(None)))?;
+        let length = length.parse().or(Err(Error::ParseError(None)))?;
+        Self::from_address_length(address, length)
+    }
+}
@@ -0,0 +1,78 @@
+use super::{Addr, Address, AddressRange, UI};
+use crate::{Error, Result};
+
+/// Hands out the addresses of a pool one at a time in strictly increasing order, never repeating
+/// one, for simple sequential assignment where a full allocator isn't needed. Only the last
+/// address handed out needs to be persisted to pick up where it left off with
+/// [`Cursor::resume`]. Once every address has been handed out, [`Error::Exhausted`] is returned.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{Addr, AddressRange, Cursor, Pfx};
+/// let prefix: Pfx = "10.0.0.0/31".parse().unwrap();
+/// let mut cursor = Cursor::new(AddressRange::from_prefix(&prefix));
+/// assert_eq!(Addr::new(10, 0, 0, 0), cursor.allocate().unwrap());
+/// assert_eq!(Addr::new(10, 0, 0, 1), cursor.allocate().unwrap());
+/// assert!(matches!(cursor.allocate(), Err(addrs::Error::Exhausted)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Cursor<A: Address = Addr> {
+    pool: AddressRange<A>,
+    last: Option<A>,
+}
+
+impl<A: Address> Cursor<A> {
+    /// returns a cursor that starts with the first address in the pool
+    pub fn new(pool: AddressRange<A>) -> Self {
+        Self { pool, last: None }
+    }
+
+    /// returns a cursor that continues after the given address, as returned by
+    /// [`Cursor::last`]. [`None`] starts from the beginning. If the address isn't in the pool,
+    /// [`Error::NotContained`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, AddressRange, Cursor};
+    /// let pool = AddressRange::new(Addr::new(10, 0, 0, 10), Addr::new(10, 0, 0, 20)).unwrap();
+    /// let mut cursor = Cursor::resume(pool, Some(Addr::new(10, 0, 0, 14))).unwrap();
+    /// assert_eq!(Addr::new(10, 0, 0, 15), cursor.allocate().unwrap());
+    /// ```
+    pub fn resume(pool: AddressRange<A>, last: Option<A>) -> Result<Self> {
+        match last {
+            Some(a) if a < pool.first() || pool.last() < a => Err(Error::NotContained),
+            _ => Ok(Self { pool, last }),
+        }
+    }
+
+    /// returns the next address, which is greater than any returned before. If the pool is
+    /// exhausted, [`Error::Exhausted`] is returned.
+    pub fn allocate(&mut self) -> Result<A> {
+        let next = match self.last {
+            None => self.pool.first(),
+            Some(last) if last == self.pool.last() => return Err(Error::Exhausted),
+            Some(last) => A::from(last.into() + 1 as UI),
+        };
+        self.last = Some(next);
+        Ok(next)
+    }
+
+    /// returns the last address handed out or [`None`] if none has been. This is all that needs
+    /// to be persisted to resume later.
+    pub fn last(&self) -> Option<A> {
+        self.last
+    }
+
+    /// returns the pool of addresses
+    pub fn pool(&self) -> AddressRange<A> {
+        self.pool
+    }
+
+    /// returns the number of addresses left to hand out
+    pub fn remaining(&self) -> u64 {
+        match self.last {
+            None => self.pool.len(),
+            Some(last) => u64::from(self.pool.last().into() - last.into()),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Adapters to display prefixes and ranges of addresses in alternate notations. Get them from
+//! [`Prefix::display`](crate::ipv4::Prefix::display) or
+//! [`AddressRange::display`](crate::ipv4::AddressRange::display).
+//!
+//! # Example
+//! ```
+//! # use addrs::ipv4::Prefix;
+//! let prefix = "10.0.0.0/24".parse::<ipnet::Ipv4Net>().unwrap();
+//! assert_eq!("10.0.0.0-10.0.0.255", prefix.display().as_range().to_string());
+//! assert_eq!("10.0.0.0/24", prefix.display().as_cidr().to_string());
+//! assert_eq!("10.0.0.0 0.0.0.255", prefix.display().as_wildcard().to_string());
+//! ```
+
+use std::fmt;
+
+use super::{format, Address, MASKS, MAX_ADDRESS_LEN, MAX_PREFIX_LEN, UI};
+
+/// Picks how to display a prefix or range of addresses.
+#[derive(Clone, Copy, Debug)]
+pub struct Rendering<A: Address> {
+    first: A,
+    last: A,
+    // the address, including host bits, and length of a prefix
+    prefix: Option<(A, u8)>,
+}
+
+impl<A: Address> Rendering<A> {
+    pub(super) fn prefix(address: A, length: u8, first: A, last: A) -> Self {
+        Self {
+            first,
+            last,
+            prefix: Some((address, length)),
+        }
+    }
+
+    pub(super) fn range(first: A, last: A) -> Self {
+        Self {
+            first,
+            last,
+            prefix: None,
+        }
+    }
+
+    /// displays the first and last addresses, e.g. `10.0.0.0-10.0.0.255`
+    pub fn as_range(self) -> AsRange<A> {
+        AsRange(self)
+    }
+
+    /// displays CIDR notation, e.g. `10.0.0.0/24`. A range that isn't a single prefix is displayed
+    /// as the fewest prefixes that cover it exactly, separated by `, `.
+    pub fn as_cidr(self) -> AsCidr<A> {
+        AsCidr(self)
+    }
+
+    /// displays the network address and wildcard mask used in Cisco ACLs, e.g.
+    /// `10.0.0.0 0.0.0.255`. A range that isn't a single prefix is displayed as the fewest
+    /// prefixes that cover it exactly, separated by `, `.
+    pub fn as_wildcard(self) -> AsWildcard<A> {
+        AsWildcard(self)
+    }
+
+    // writes each of the prefixes covering the rendering with the given function and pads the
+    // whole list as one
+    fn write_each(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        write: impl Fn(A, u8, &mut [u8]) -> Option<usize>,
+    ) -> fmt::Result {
+        let mut s = String::new();
+        let mut buf = [0u8; 2 * MAX_ADDRESS_LEN + 1];
+        for (network, length) in super::covering_prefixes(self.first.into(), self.last.into()) {
+            if !s.is_empty() {
+                s.push_str(", ");
+            }
+            let len = write(A::from(network), length, &mut buf).unwrap();
+            s.push_str(format::as_str(&buf, len));
+        }
+        f.pad(&s)
+    }
+}
+
+/// Displays the first and last addresses. See [`Rendering::as_range`].
+#[derive(Clone, Copy, Debug)]
+pub struct AsRange<A: Address>(Rendering<A>);
+
+impl<A: Address> fmt::Display for AsRange<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0u8; 2 * MAX_ADDRESS_LEN + 1];
+        let len =
+            format::write_range(self.0.first.octets(), self.0.last.octets(), &mut buf).unwrap();
+        f.pad(format::as_str(&buf, len))
+    }
+}
+
+/// Displays CIDR notation. See [`Rendering::as_cidr`].
+#[derive(Clone, Copy, Debug)]
+pub struct AsCidr<A: Address>(Rendering<A>);
+
+impl<A: Address> fmt::Display for AsCidr<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.prefix {
+            Some((address, length)) => {
+                let mut buf = [0u8; MAX_PREFIX_LEN];
+                let len = format::write_prefix(address.octets(), length, &mut buf).unwrap();
+                f.pad(format::as_str(&buf, len))
+            }
+            None => self.0.write_each(f, |network, length, buf| {
+                format::write_prefix(network.octets(), length, buf)
+            }),
+        }
+    }
+}
+
+/// Displays the network address and wildcard mask. See [`Rendering::as_wildcard`].
+#[derive(Clone, Copy, Debug)]
+pub struct AsWildcard<A: Address>(Rendering<A>);
+
+impl<A: Address> fmt::Display for AsWildcard<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.write_each(f, |network, length, buf| {
+            let wildcard = A::from(!MASKS[length as usize] as UI);
+            format::write_wildcard(network.octets(), wildcard.octets(), buf)
+        })
+    }
+}
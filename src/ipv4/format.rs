@@ -0,0 +1,56 @@
+// helpers to format addresses and prefixes into byte buffers without allocating or depending on
+// std::fmt. Each returns the number of bytes written or None if the buffer is too small.
+
+pub fn write_u8(n: u8, buf: &mut [u8]) -> Option<usize> {
+    let digits: &[u8] = match n {
+        0..=9 => &[n],
+        10..=99 => &[n / 10, n % 10],
+        _ => &[n / 100, n / 10 % 10, n % 10],
+    };
+    let buf = buf.get_mut(..digits.len())?;
+    for (b, d) in buf.iter_mut().zip(digits) {
+        *b = b'0' + d;
+    }
+    Some(digits.len())
+}
+
+pub fn write_address(octets: [u8; 4], buf: &mut [u8]) -> Option<usize> {
+    let mut len = 0;
+    for (i, octet) in octets.into_iter().enumerate() {
+        if i > 0 {
+            *buf.get_mut(len)? = b'.';
+            len += 1;
+        }
+        len += write_u8(octet, buf.get_mut(len..)?)?;
+    }
+    Some(len)
+}
+
+pub fn write_prefix(octets: [u8; 4], length: u8, buf: &mut [u8]) -> Option<usize> {
+    let mut len = write_address(octets, buf)?;
+    *buf.get_mut(len)? = b'/';
+    len += 1;
+    len += write_u8(length, buf.get_mut(len..)?)?;
+    Some(len)
+}
+
+pub fn write_range(first: [u8; 4], last: [u8; 4], buf: &mut [u8]) -> Option<usize> {
+    let mut len = write_address(first, buf)?;
+    *buf.get_mut(len)? = b'-';
+    len += 1;
+    len += write_address(last, buf.get_mut(len..)?)?;
+    Some(len)
+}
+
+pub fn write_wildcard(network: [u8; 4], wildcard: [u8; 4], buf: &mut [u8]) -> Option<usize> {
+    let mut len = write_address(network, buf)?;
+    *buf.get_mut(len)? = b' ';
+    len += 1;
+    len += write_address(wildcard, buf.get_mut(len..)?)?;
+    Some(len)
+}
+
+// only ascii digits, dots, slashes, dashes and spaces are ever written
+pub fn as_str(buf: &[u8], len: usize) -> &str {
+    unsafe { std::str::from_utf8_unchecked(&buf[..len]) }
+}
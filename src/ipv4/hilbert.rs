@@ -0,0 +1,41 @@
+// Conversions between the distance along a Hilbert curve and (x, y) cells in its grid, following
+// https://en.wikipedia.org/wiki/Hilbert_curve#Applications_and_mapping_algorithms. The order is
+// at most 16 so that the coordinates fit in a u16 and the distance in a u32.
+
+// rotates and flips a quadrant of size n as needed
+fn rotate(n: u32, (x, y): (u32, u32), rx: u32, ry: u32) -> (u32, u32) {
+    match (rx, ry) {
+        (1, 0) => (n - 1 - y, n - 1 - x),
+        (_, 0) => (y, x),
+        _ => (x, y),
+    }
+}
+
+pub(super) fn d_to_xy(order: u8, d: u32) -> (u16, u16) {
+    let (mut x, mut y) = (0, 0);
+    let mut t = d;
+    for level in 0..order {
+        let s = 1u32 << level;
+        let rx = 1 & (t >> 1);
+        let ry = 1 & (t ^ rx);
+        (x, y) = rotate(s, (x, y), rx, ry);
+        x += s * rx;
+        y += s * ry;
+        t >>= 2;
+    }
+    (x as u16, y as u16)
+}
+
+pub(super) fn xy_to_d(order: u8, (x, y): (u16, u16)) -> u32 {
+    let n = 1u32 << order;
+    let (mut x, mut y) = (u32::from(x), u32::from(y));
+    let mut d = 0;
+    for level in (0..order).rev() {
+        let s = 1u32 << level;
+        let rx = u32::from(x & s > 0);
+        let ry = u32::from(y & s > 0);
+        d += s * s * ((3 * rx) ^ ry);
+        (x, y) = rotate(n, (x, y), rx, ry);
+    }
+    d
+}
@@ -1,4 +1,8 @@
 impl crate::ipv4::Address for std::net::Ipv4Addr {
+    const UNSPECIFIED: Self = std::net::Ipv4Addr::UNSPECIFIED;
+    const BROADCAST: Self = std::net::Ipv4Addr::BROADCAST;
+    const LOCALHOST: Self = std::net::Ipv4Addr::LOCALHOST;
+
     fn octets(&self) -> [u8; 4] {
         self.octets()
     }
@@ -15,7 +19,7 @@ impl crate::ipv4::Prefix for ipnet::Ipv4Net {
         self.prefix_len()
     }
 
-    unsafe fn unsafe_new(ip: Self::Address, length: u8) -> Self {
+    unsafe fn new_unchecked(ip: Self::Address, length: u8) -> Self {
         Self::new(ip, length).unwrap_unchecked()
     }
 }
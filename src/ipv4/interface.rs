@@ -0,0 +1,160 @@
+use std::{fmt, str::FromStr};
+
+use super::{Addr, Pfx, Prefix, Subnet};
+use crate::{Error, Result};
+
+/// An address assigned within a prefix, like the output of `ip addr`, e.g. `10.0.0.1/24`.
+///
+/// It is the complement of [`Subnet`]: the host bits are the point and are always preserved. It
+/// provides the methods that interface configuration code typically needs.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{Addr, Interface};
+/// let interface: Interface = "10.0.0.1/24".parse().unwrap();
+/// assert_eq!(Addr::new(10, 0, 0, 1), interface.address());
+/// assert_eq!("10.0.0.0/24", interface.subnet().to_string());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Interface(Pfx);
+
+impl Interface {
+    /// returns a new interface for the given address and the length of its prefix. If the length
+    /// is greater than 32 then [`Error::InvalidLength`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Interface};
+    /// let interface = Interface::new(Addr::new(10, 0, 0, 1), 24).unwrap();
+    /// assert_eq!("10.0.0.1/24", interface.to_string());
+    /// ```
+    pub fn new(address: Addr, length: u8) -> Result<Self> {
+        Ok(Self(Pfx::new(address, length)?))
+    }
+
+    /// returns the address assigned to the interface
+    pub fn address(&self) -> Addr {
+        self.0.address()
+    }
+
+    /// returns the subnet the interface is attached to
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Interface;
+    /// let interface: Interface = "10.0.0.77/26".parse().unwrap();
+    /// assert_eq!("10.0.0.64/26", interface.subnet().to_string());
+    /// ```
+    pub fn subnet(&self) -> Subnet {
+        // the length was checked when the interface was created
+        unsafe { Subnet::new_unchecked(self.address(), self.length()) }
+    }
+
+    /// returns the address at the other end of a point-to-point link if the interface is on a /31.
+    /// For any other length, None is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Interface};
+    /// let interface: Interface = "10.0.0.6/31".parse().unwrap();
+    /// assert_eq!(Some(Addr::new(10, 0, 0, 7)), interface.peer());
+    /// ```
+    pub fn peer(&self) -> Option<Addr> {
+        self.0
+            .p2p_pair()
+            .map(|(a, b)| if a == self.address() { b } else { a })
+    }
+
+    /// returns the first address that can be assigned to a host in the interface's subnet. Both
+    /// addresses of a /31 are usable and the only address in a /32 is the first and last host.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Interface};
+    /// let interface: Interface = "10.0.0.77/24".parse().unwrap();
+    /// assert_eq!(Addr::new(10, 0, 0, 1), interface.first_host());
+    /// ```
+    pub fn first_host(&self) -> Addr {
+        Prefix::first_host(self)
+    }
+
+    /// returns the last address that can be assigned to a host in the interface's subnet. Both
+    /// addresses of a /31 are usable and the only address in a /32 is the first and last host.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Interface};
+    /// let interface: Interface = "10.0.0.77/24".parse().unwrap();
+    /// assert_eq!(Addr::new(10, 0, 0, 254), interface.last_host());
+    /// ```
+    pub fn last_host(&self) -> Addr {
+        Prefix::last_host(self)
+    }
+}
+
+impl Prefix for Interface {
+    type Address = Addr;
+
+    fn address(&self) -> Self::Address {
+        self.0.address()
+    }
+
+    fn length(&self) -> u8 {
+        self.0.length()
+    }
+
+    unsafe fn new_unchecked(ip: Self::Address, length: u8) -> Self {
+        Self(Pfx::new_unchecked(ip, length))
+    }
+}
+
+impl From<Pfx> for Interface {
+    fn from(value: Pfx) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Interface> for Pfx {
+    fn from(value: Interface) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Interface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Debug for Interface {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for Interface {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(Self(s.parse()?))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Interface {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Interface {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        Ok(Self(Pfx::deserialize(deserializer)?))
+    }
+}
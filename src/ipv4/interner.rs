@@ -0,0 +1,111 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{Error, Result};
+
+/// A small handle to a value stored in an [`Interner`]. It is only meaningful to the interner
+/// that returned it.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct Id(u32);
+
+impl Id {
+    /// returns the position of the value in its interner. Ids are handed out sequentially from
+    /// zero so this can be used to index a side table.
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl From<Id> for u32 {
+    fn from(value: Id) -> Self {
+        value.0
+    }
+}
+
+/// Deduplicates addresses or prefixes that are referenced many times, handing out a 4 byte
+/// [`Id`] for each distinct value. This is useful when storing a very large number of references
+/// to a comparatively small number of distinct prefixes.
+///
+/// Values are never removed, so an [`Id`] stays valid for the lifetime of the interner.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{Interner, Pfx};
+/// let mut interner = Interner::new();
+/// let a = interner.intern("10.0.0.0/8".parse::<Pfx>().unwrap()).unwrap();
+/// let b = interner.intern("10.0.0.0/8".parse::<Pfx>().unwrap()).unwrap();
+/// assert_eq!(a, b);
+/// assert_eq!(1, interner.len());
+/// assert_eq!(Some("10.0.0.0/8".parse().unwrap()), interner.resolve(a));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Interner<T> {
+    values: Vec<T>,
+    ids: HashMap<T, Id>,
+}
+
+impl<T> Default for Interner<T> {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            ids: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Copy + Eq + Hash> Interner<T> {
+    /// returns a new, empty interner
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// returns a new, empty interner with room for the given number of distinct values
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+            ids: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// returns the id of the given value, storing it first if it hasn't been seen before. If the
+    /// interner already holds [`u32::MAX`] distinct values, [`Error::TooMany`] is returned.
+    pub fn intern(&mut self, value: T) -> Result<Id> {
+        if let Some(id) = self.ids.get(&value) {
+            return Ok(*id);
+        }
+        let id = Id(self.values.len().try_into().or(Err(Error::TooMany))?);
+        if id.0 == u32::MAX {
+            return Err(Error::TooMany);
+        }
+        self.values.push(value);
+        self.ids.insert(value, id);
+        Ok(id)
+    }
+
+    /// returns the id of the given value if it has been interned
+    pub fn id(&self, value: &T) -> Option<Id> {
+        self.ids.get(value).copied()
+    }
+
+    /// returns the value with the given id or [`None`] if the id didn't come from this interner
+    pub fn resolve(&self, id: Id) -> Option<T> {
+        self.values.get(id.index()).copied()
+    }
+
+    /// returns the number of distinct values interned
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// returns true if nothing has been interned
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// returns an iterator over the ids and values in the order they were first interned
+    pub fn iter(&self) -> impl Iterator<Item = (Id, T)> + '_ {
+        self.values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (Id(i as u32), *value))
+    }
+}
@@ -0,0 +1,752 @@
+use std::{
+    fmt,
+    marker::PhantomData,
+    ops::{self, RangeInclusive},
+};
+
+use super::{
+    aligned_prefixes, covering_prefixes, Address, AddressRange, Block, Pfx, Prefix, Set, MASKS, UI,
+};
+use crate::{Error, Result};
+
+/// A mutable set of addresses stored as a binary trie of prefixes. Prefixes, addresses and ranges
+/// can be inserted and removed in any order. The trie is always kept in its most compact form:
+/// overlapping and adjacent entries are merged so that iterating gives the fewest prefixes that
+/// cover exactly the addresses in the set. It follows that two sets holding the same addresses
+/// are equal no matter how they were built.
+///
+/// The type parameter is the type of prefix that iteration yields. Prefixes of any type with the
+/// same address type can be inserted. If it is an address type, iteration yields every address
+/// in the set since it can't represent anything bigger. A set can also be collected from an iterator of them or of
+/// ranges.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{Addr, AddressRange, IpSet, Pfx, Set};
+/// let mut set: IpSet = IpSet::new();
+/// set.insert(&"10.0.0.0/25".parse::<Pfx>().unwrap());
+/// set.insert(&"10.0.0.128/25".parse::<Pfx>().unwrap());
+/// set.insert(&Addr::new(10, 0, 1, 0));
+/// assert_eq!("{10.0.0.0/24, 10.0.1.0/32}", format!("{set:?}"));
+///
+/// set.remove(&"10.0.0.0/26".parse::<Pfx>().unwrap());
+/// assert_eq!("{10.0.0.64/26, 10.0.0.128/25, 10.0.1.0/32}", format!("{set:?}"));
+/// assert!(set.contains(&Addr::new(10, 0, 0, 64)));
+/// assert!(!set.contains(&Addr::new(10, 0, 0, 63)));
+///
+/// let lines = ["10.0.0.0/25", "10.0.0.128/25", "10.0.1.0/24"];
+/// let set: IpSet = lines.iter().map(|s| s.parse::<Pfx>()).collect::<Result<_, _>>().unwrap();
+/// assert_eq!("{10.0.0.0/23}", format!("{set:?}"));
+///
+/// // ranges are split into prefixes as they are added
+/// let range: AddressRange = "10.0.0.5-10.0.0.27".parse().unwrap();
+/// let set = IpSet::<Pfx>::from(range);
+/// assert_eq!(
+///     "{10.0.0.5/32, 10.0.0.6/31, 10.0.0.8/29, 10.0.0.16/29, 10.0.0.24/30}",
+///     format!("{set:?}")
+/// );
+/// ```
+pub struct IpSet<P: Prefix = Pfx> {
+    root: Option<Node>,
+    prefix: PhantomData<fn() -> P>,
+}
+
+impl<P: Prefix> IpSet<P> {
+    /// returns a new, empty set
+    pub fn new() -> Self {
+        Self::from_root(None)
+    }
+
+    fn from_root(root: Option<Node>) -> Self {
+        Self {
+            root,
+            prefix: PhantomData,
+        }
+    }
+
+    /// adds every address in the given prefix, or address, to the set. The host bits of the
+    /// prefix are ignored.
+    pub fn insert<Q: Prefix<Address = P::Address>>(&mut self, prefix: &Q) {
+        let node = Node::full(prefix.network().into(), prefix.length());
+        self.root = union(self.root.take(), Some(node));
+    }
+
+    /// adds every address in the given range to the set
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::IpSet;
+    /// # use std::net::Ipv4Addr;
+    /// let mut set: IpSet<ipnet::Ipv4Net> = IpSet::new();
+    /// set.insert_range(&(Ipv4Addr::new(10, 0, 0, 5)..=Ipv4Addr::new(10, 0, 0, 12)));
+    /// assert_eq!("{10.0.0.5/32, 10.0.0.6/31, 10.0.0.8/30, 10.0.0.12/32}", format!("{set:?}"));
+    /// ```
+    pub fn insert_range(&mut self, range: &RangeInclusive<P::Address>) {
+        for (network, length) in covering_prefixes((*range.start()).into(), (*range.end()).into()) {
+            self.root = union(self.root.take(), Some(Node::full(network, length)));
+        }
+    }
+
+    /// removes every address in the given prefix, or address, from the set. The host bits of the
+    /// prefix are ignored.
+    pub fn remove<Q: Prefix<Address = P::Address>>(&mut self, prefix: &Q) {
+        let node = Node::full(prefix.network().into(), prefix.length());
+        self.root = self.root.take().and_then(|root| difference(root, &node));
+    }
+
+    /// removes every address in the given range from the set
+    pub fn remove_range(&mut self, range: &RangeInclusive<P::Address>) {
+        for (network, length) in covering_prefixes((*range.start()).into(), (*range.end()).into()) {
+            let node = Node::full(network, length);
+            self.root = self.root.take().and_then(|root| difference(root, &node));
+        }
+    }
+
+    /// removes every address from the set
+    pub fn clear(&mut self) {
+        self.root = None;
+    }
+
+    /// returns a new set holding the addresses that are in either set. The `|` operator does the
+    /// same.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{IpSet, Pfx};
+    /// let mut a: IpSet = IpSet::new();
+    /// a.insert(&"10.0.0.0/25".parse::<Pfx>().unwrap());
+    /// let mut b: IpSet = IpSet::new();
+    /// b.insert(&"10.0.0.128/25".parse::<Pfx>().unwrap());
+    /// assert_eq!("{10.0.0.0/24}", format!("{:?}", a.union(&b)));
+    /// assert_eq!(a.union(&b), &a | &b);
+    /// ```
+    pub fn union<Q: Prefix<Address = P::Address>>(&self, other: &IpSet<Q>) -> Self {
+        Self::from_root(union(self.root.clone(), other.root.clone()))
+    }
+
+    /// returns a new set holding the addresses that are in both sets. The `&` operator does the
+    /// same.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{IpSet, Pfx};
+    /// let mut a: IpSet = IpSet::new();
+    /// a.insert(&"10.0.0.0/24".parse::<Pfx>().unwrap());
+    /// let mut b: IpSet = IpSet::new();
+    /// b.insert(&"10.0.0.128/25".parse::<Pfx>().unwrap());
+    /// b.insert(&"10.0.1.0/24".parse::<Pfx>().unwrap());
+    /// assert_eq!("{10.0.0.128/25}", format!("{:?}", a.intersection(&b)));
+    /// assert_eq!(a.intersection(&b), &a & &b);
+    /// ```
+    pub fn intersection<Q: Prefix<Address = P::Address>>(&self, other: &IpSet<Q>) -> Self {
+        match (&self.root, &other.root) {
+            (Some(a), Some(b)) => Self::from_root(intersection(a, b)),
+            _ => Self::new(),
+        }
+    }
+
+    /// returns a new set holding the addresses that are in this set but not in the other. The `-`
+    /// operator does the same.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{IpSet, Pfx};
+    /// let mut a: IpSet = IpSet::new();
+    /// a.insert(&"10.0.0.0/24".parse::<Pfx>().unwrap());
+    /// let mut b: IpSet = IpSet::new();
+    /// b.insert(&"10.0.0.128/25".parse::<Pfx>().unwrap());
+    /// assert_eq!("{10.0.0.0/25}", format!("{:?}", a.difference(&b)));
+    /// assert_eq!(a.difference(&b), &a - &b);
+    /// ```
+    pub fn difference<Q: Prefix<Address = P::Address>>(&self, other: &IpSet<Q>) -> Self {
+        match (&self.root, &other.root) {
+            (Some(a), Some(b)) => Self::from_root(difference(a.clone(), b)),
+            _ => self.clone(),
+        }
+    }
+
+    /// returns true if every address in this set is also in the other. It walks both tries
+    /// together without building any new set.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{IpSet, Pfx};
+    /// let mut a: IpSet = IpSet::new();
+    /// a.insert(&"10.0.0.0/25".parse::<Pfx>().unwrap());
+    /// let mut b: IpSet = IpSet::new();
+    /// b.insert(&"10.0.0.0/24".parse::<Pfx>().unwrap());
+    /// assert!(a.is_subset(&b));
+    /// assert!(b.is_superset(&a));
+    /// assert!(!b.is_subset(&a));
+    /// ```
+    pub fn is_subset<Q: Prefix<Address = P::Address>>(&self, other: &IpSet<Q>) -> bool {
+        match (&self.root, &other.root) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(a), Some(b)) => is_subset(a, b),
+        }
+    }
+
+    /// returns true if every address in the other set is also in this one
+    pub fn is_superset<Q: Prefix<Address = P::Address>>(&self, other: &IpSet<Q>) -> bool {
+        other.is_subset(self)
+    }
+
+    /// returns true if the two sets have no address in common. It walks both tries together
+    /// without building any new set. Use [`Set::overlaps`] to check against a single prefix.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{IpSet, Pfx};
+    /// let mut a: IpSet = IpSet::new();
+    /// a.insert(&"10.0.0.0/25".parse::<Pfx>().unwrap());
+    /// let mut b: IpSet = IpSet::new();
+    /// b.insert(&"10.0.0.128/25".parse::<Pfx>().unwrap());
+    /// assert!(a.is_disjoint(&b));
+    /// b.insert(&"10.0.0.0/32".parse::<Pfx>().unwrap());
+    /// assert!(!a.is_disjoint(&b));
+    /// ```
+    pub fn is_disjoint<Q: Prefix<Address = P::Address>>(&self, other: &IpSet<Q>) -> bool {
+        match (&self.root, &other.root) {
+            (Some(a), Some(b)) => is_disjoint(a, b),
+            _ => true,
+        }
+    }
+
+    /// returns true if the two sets hold exactly the same addresses, however they were built and
+    /// whatever type of prefix they yield. For sets of the same type, `==` does the same.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{IpSet, Pfx, Subnet};
+    /// let mut a: IpSet = IpSet::new();
+    /// a.insert(&"10.0.0.0/25".parse::<Pfx>().unwrap());
+    /// a.insert(&"10.0.0.128/25".parse::<Pfx>().unwrap());
+    /// let mut b: IpSet<Subnet> = IpSet::new();
+    /// b.insert(&"10.0.0.0/24".parse::<Subnet>().unwrap());
+    /// assert!(a.set_eq(&b));
+    /// ```
+    pub fn set_eq<Q: Prefix<Address = P::Address>>(&self, other: &IpSet<Q>) -> bool {
+        // the tries are always in their most compact form so equal sets have the same shape
+        self.root == other.root
+    }
+
+    /// returns an iterator, in order, over the fewest prefixes that cover exactly the addresses
+    /// in the set. A prefix that `P` can't represent, like any prefix bigger than one address
+    /// when `P` is an address type, is split into the single addresses in it.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{IpSet, Pfx};
+    /// let mut set: IpSet = IpSet::new();
+    /// set.insert(&"10.0.2.0/24".parse::<Pfx>().unwrap());
+    /// set.insert(&"10.0.0.0/23".parse::<Pfx>().unwrap());
+    /// let prefixes: Vec<Pfx> = set.iter().collect();
+    /// assert_eq!("[10.0.0.0/23, 10.0.2.0/24]", format!("{prefixes:?}"));
+    /// ```
+    pub fn iter(&self) -> IpSetIter<'_, P> {
+        IpSetIter {
+            stack: self.root.iter().collect(),
+            addresses: 0..0,
+            prefix: PhantomData,
+        }
+    }
+
+    // returns an iterator over the prefixes in the trie as they are stored, whatever P is
+    fn blocks(&self) -> IpSetIter<'_, Block<P::Address>> {
+        IpSetIter {
+            stack: self.root.iter().collect(),
+            addresses: 0..0,
+            prefix: PhantomData,
+        }
+    }
+}
+
+impl<P: Prefix> Set for IpSet<P> {
+    type Address = P::Address;
+
+    fn num_prefixes(&self, length: u8) -> Result<u32> {
+        if Self::Address::BITS < length {
+            return Err(Error::InvalidLength);
+        }
+        let mut count = 0u32;
+        for prefix in self.blocks() {
+            count = count
+                .checked_add(prefix.num_prefixes(length)?)
+                .ok_or(Error::TooMany)?;
+        }
+        Ok(count)
+    }
+
+    fn num_prefixes_u64(&self, length: u8) -> Result<u64> {
+        if Self::Address::BITS < length {
+            return Err(Error::InvalidLength);
+        }
+        let mut count = 0;
+        for prefix in self.blocks() {
+            count += prefix.num_prefixes_u64(length)?;
+        }
+        Ok(count)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    fn contains<Q: Prefix<Address = Self::Address>>(&self, other: &Q) -> bool {
+        let (network, length) = (other.network().into(), other.length());
+        let mut node = match &self.root {
+            Some(node) => node,
+            None => return false,
+        };
+        loop {
+            if !node.contains(network, length) {
+                return false;
+            }
+            match &node.children {
+                None => return true,
+                Some(_) if node.length == length => return false,
+                Some(children) => node = &children[bit(network, node.length)],
+            }
+        }
+    }
+
+    fn overlaps<Q: Prefix<Address = Self::Address>>(&self, other: &Q) -> bool {
+        let (network, length) = (other.network().into(), other.length());
+        let mut node = match &self.root {
+            Some(node) => node,
+            None => return false,
+        };
+        loop {
+            if !node.overlaps(network, length) {
+                return false;
+            }
+            match &node.children {
+                Some(children) if node.length < length => {
+                    node = &children[bit(network, node.length)]
+                }
+                _ => return true,
+            }
+        }
+    }
+
+    fn coarsen<Q: Prefix<Address = Self::Address>>(&self, length: u8) -> Result<Vec<Q>> {
+        let mut coarse = vec![];
+        for prefix in self.blocks() {
+            coarse.extend(prefix.coarsen::<Q>(length)?);
+        }
+        // neighboring prefixes in the set may fall in the same coarse prefix
+        coarse.dedup();
+        Ok(coarse)
+    }
+
+    fn prefixes<Q: Prefix<Address = Self::Address>>(
+        &self,
+        length: u8,
+    ) -> Result<impl Iterator<Item = Q>> {
+        let blocks = self.blocks().map(|p| (p.network().into(), p.length()));
+        aligned_prefixes(blocks, length)
+    }
+
+    #[cfg(feature = "rand")]
+    fn random_address<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Option<Self::Address> {
+        let mut index = match self.num_addresses_u64() {
+            0 => return None,
+            n => rng.random_range(0..n),
+        };
+        for prefix in self.blocks() {
+            let size = prefix.num_addresses_u64();
+            if index < size {
+                let network: UI = prefix.network().into();
+                return Some((network + index as UI).into());
+            }
+            index -= size;
+        }
+        None
+    }
+}
+
+impl<P: Prefix> Default for IpSet<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// builds a set from prefixes or addresses, e.g. with `collect()`
+impl<P, Q> FromIterator<Q> for IpSet<P>
+where
+    P: Prefix,
+    Q: Prefix<Address = P::Address>,
+{
+    fn from_iter<I: IntoIterator<Item = Q>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<P, Q> Extend<Q> for IpSet<P>
+where
+    P: Prefix,
+    Q: Prefix<Address = P::Address>,
+{
+    fn extend<I: IntoIterator<Item = Q>>(&mut self, iter: I) {
+        for prefix in iter {
+            self.insert(&prefix);
+        }
+    }
+}
+
+// builds a set from ranges, e.g. with `collect()`
+impl<A, P> FromIterator<RangeInclusive<A>> for IpSet<P>
+where
+    A: Address,
+    P: Prefix<Address = A>,
+{
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<A>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<A, P> Extend<RangeInclusive<A>> for IpSet<P>
+where
+    A: Address,
+    P: Prefix<Address = A>,
+{
+    fn extend<I: IntoIterator<Item = RangeInclusive<A>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert_range(&range);
+        }
+    }
+}
+
+impl<A, P> FromIterator<AddressRange<A>> for IpSet<P>
+where
+    A: Address,
+    P: Prefix<Address = A>,
+{
+    fn from_iter<I: IntoIterator<Item = AddressRange<A>>>(iter: I) -> Self {
+        iter.into_iter().map(RangeInclusive::from).collect()
+    }
+}
+
+impl<A, P> Extend<AddressRange<A>> for IpSet<P>
+where
+    A: Address,
+    P: Prefix<Address = A>,
+{
+    fn extend<I: IntoIterator<Item = AddressRange<A>>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().map(RangeInclusive::from));
+    }
+}
+
+impl<A, P> From<RangeInclusive<A>> for IpSet<P>
+where
+    A: Address,
+    P: Prefix<Address = A>,
+{
+    fn from(range: RangeInclusive<A>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl<A, P> From<AddressRange<A>> for IpSet<P>
+where
+    A: Address,
+    P: Prefix<Address = A>,
+{
+    fn from(range: AddressRange<A>) -> Self {
+        Self::from(RangeInclusive::from(range))
+    }
+}
+
+impl<P: Prefix> Clone for IpSet<P> {
+    fn clone(&self) -> Self {
+        Self::from_root(self.root.clone())
+    }
+}
+
+impl<P: Prefix> PartialEq for IpSet<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.set_eq(other)
+    }
+}
+
+impl<P: Prefix> Eq for IpSet<P> {}
+
+impl<P: Prefix> fmt::Debug for IpSet<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        for (i, prefix) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(&prefix.to_string())?;
+        }
+        f.write_str("}")
+    }
+}
+
+impl<P: Prefix> ops::BitOr for &IpSet<P> {
+    type Output = IpSet<P>;
+
+    fn bitor(self, rhs: Self) -> IpSet<P> {
+        self.union(rhs)
+    }
+}
+
+impl<P: Prefix> ops::BitAnd for &IpSet<P> {
+    type Output = IpSet<P>;
+
+    fn bitand(self, rhs: Self) -> IpSet<P> {
+        self.intersection(rhs)
+    }
+}
+
+impl<P: Prefix> ops::Sub for &IpSet<P> {
+    type Output = IpSet<P>;
+
+    fn sub(self, rhs: Self) -> IpSet<P> {
+        self.difference(rhs)
+    }
+}
+
+impl<'a, P: Prefix> IntoIterator for &'a IpSet<P> {
+    type Item = P;
+    type IntoIter = IpSetIter<'a, P>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterates, in order, over the prefixes in an [`IpSet`]. Use [`IpSet::iter`] to get one.
+pub struct IpSetIter<'a, P: Prefix> {
+    stack: Vec<&'a Node>,
+    // the addresses left to yield from a node that P can't represent as one prefix
+    addresses: ops::Range<u64>,
+    prefix: PhantomData<fn() -> P>,
+}
+
+impl<P: Prefix> Iterator for IpSetIter<'_, P> {
+    type Item = P;
+
+    fn next(&mut self) -> Option<P> {
+        if let Some(address) = self.addresses.next() {
+            return Some(unsafe { P::new_unchecked((address as UI).into(), 32) });
+        }
+        while let Some(node) = self.stack.pop() {
+            match &node.children {
+                None => {
+                    let network = P::Address::from(node.network);
+                    let prefix = unsafe { P::new_unchecked(network, node.length) };
+                    if prefix.length() == node.length {
+                        return Some(prefix);
+                    }
+                    let first = u64::from(node.network);
+                    self.addresses = first..first + (1 << (32 - node.length));
+                    return self.next();
+                }
+                Some(children) => {
+                    self.stack.push(&children[1]);
+                    self.stack.push(&children[0]);
+                }
+            }
+        }
+        None
+    }
+}
+
+// A node in the trie covers the prefix given by its network and length. Either every address in
+// that prefix is in the set or it has exactly two children, one on each side of the bit
+// following the prefix. Children may be any number of bits longer than their parent. In its most
+// compact form, which every operation below leaves it in, no node has two full children that are
+// exactly its halves because they would be merged into a full node.
+#[derive(Clone, PartialEq, Eq)]
+struct Node {
+    network: UI,
+    length: u8,
+    // None when every address in the prefix is in the set
+    children: Option<Box<[Node; 2]>>,
+}
+
+impl Node {
+    fn full(network: UI, length: u8) -> Self {
+        Self {
+            network,
+            length,
+            children: None,
+        }
+    }
+
+    // returns the node for the given prefix with the given children, collapsing it if there are
+    // fewer than two or merging them if they are full halves
+    fn split(network: UI, length: u8, left: Option<Node>, right: Option<Node>) -> Option<Self> {
+        match (left, right) {
+            (None, None) => None,
+            (Some(node), None) | (None, Some(node)) => Some(node),
+            (Some(left), Some(right)) => match left.is_half(length) && right.is_half(length) {
+                true => Some(Self::full(network, length)),
+                false => Some(Self {
+                    network,
+                    length,
+                    children: Some(Box::new([left, right])),
+                }),
+            },
+        }
+    }
+
+    fn is_half(&self, length: u8) -> bool {
+        self.children.is_none() && self.length == length + 1
+    }
+
+    // returns the two children, first splitting a full node into its halves
+    fn into_halves(self) -> [Node; 2] {
+        match self.children {
+            Some(children) => *children,
+            None => {
+                let length = self.length + 1;
+                let right = self.network | 1 << (32 - u32::from(length));
+                [Self::full(self.network, length), Self::full(right, length)]
+            }
+        }
+    }
+
+    fn contains(&self, network: UI, length: u8) -> bool {
+        self.length <= length && network & MASKS[self.length as usize] == self.network
+    }
+
+    fn overlaps(&self, network: UI, length: u8) -> bool {
+        let mask = MASKS[self.length.min(length) as usize];
+        network & mask == self.network & mask
+    }
+}
+
+// returns the bit of the network that follows a prefix of the given length, 0 or 1
+fn bit(network: UI, length: u8) -> usize {
+    (network >> (31 - u32::from(length)) & 1) as usize
+}
+
+// returns a trie holding the addresses in either trie
+fn union(a: Option<Node>, b: Option<Node>) -> Option<Node> {
+    let (a, b) = match (a, b) {
+        (None, node) | (node, None) => return node,
+        (Some(a), Some(b)) if b.length < a.length => (b, a),
+        (Some(a), Some(b)) => (a, b),
+    };
+    // `a` is now no longer than `b`
+    if !a.contains(b.network, b.length) {
+        let length = (a.network ^ b.network).leading_zeros() as u8;
+        let network = a.network & MASKS[length as usize];
+        return match bit(a.network, length) {
+            0 => Node::split(network, length, Some(a), Some(b)),
+            _ => Node::split(network, length, Some(b), Some(a)),
+        };
+    }
+    if a.children.is_none() {
+        return Some(a);
+    }
+    let (network, length) = (a.network, a.length);
+    let [left, right] = a.into_halves();
+    match (b.length == length, b.children.is_none()) {
+        (true, true) => Some(b),
+        (true, false) => {
+            let [b_left, b_right] = b.into_halves();
+            let left = union(Some(left), Some(b_left));
+            Node::split(network, length, left, union(Some(right), Some(b_right)))
+        }
+        (false, _) => match bit(b.network, length) {
+            0 => Node::split(network, length, union(Some(left), Some(b)), Some(right)),
+            _ => Node::split(network, length, Some(left), union(Some(right), Some(b))),
+        },
+    }
+}
+
+// returns a trie holding the addresses in `a` that aren't in `b`
+fn difference(a: Node, b: &Node) -> Option<Node> {
+    if !a.overlaps(b.network, b.length) {
+        return Some(a);
+    }
+    let (network, length) = (a.network, a.length);
+    if b.contains(a.network, a.length) {
+        return match &b.children {
+            None => None,
+            Some(children) if b.length < length => difference(a, &children[bit(network, b.length)]),
+            Some(children) => {
+                let [left, right] = a.into_halves();
+                let left = difference(left, &children[0]);
+                Node::split(network, length, left, difference(right, &children[1]))
+            }
+        };
+    }
+    // `a` is shorter than `b` and contains it
+    let [left, right] = a.into_halves();
+    match bit(b.network, length) {
+        0 => Node::split(network, length, difference(left, b), Some(right)),
+        _ => Node::split(network, length, Some(left), difference(right, b)),
+    }
+}
+
+// returns a trie holding the addresses in both tries
+fn intersection(a: &Node, b: &Node) -> Option<Node> {
+    let (a, b) = match b.length < a.length {
+        true => (b, a),
+        false => (a, b),
+    };
+    // `a` is now no longer than `b`
+    if !a.contains(b.network, b.length) {
+        return None;
+    }
+    match (&a.children, &b.children) {
+        (None, _) => Some(b.clone()),
+        (Some(_), None) if a.length == b.length => Some(a.clone()),
+        (Some(a_children), Some(b_children)) if a.length == b.length => {
+            let left = intersection(&a_children[0], &b_children[0]);
+            let right = intersection(&a_children[1], &b_children[1]);
+            Node::split(a.network, a.length, left, right)
+        }
+        (Some(children), _) => intersection(&children[bit(b.network, a.length)], b),
+    }
+}
+
+// returns true if every address in `a` is in `b`
+fn is_subset(a: &Node, b: &Node) -> bool {
+    // the prefix of a node is the smallest one holding all of its addresses so `b` can't cover
+    // them unless its prefix contains that of `a`
+    if !b.contains(a.network, a.length) {
+        return false;
+    }
+    match (&b.children, &a.children) {
+        (None, _) => true,
+        (Some(children), _) if b.length < a.length => {
+            is_subset(a, &children[bit(a.network, b.length)])
+        }
+        (Some(_), None) => false,
+        (Some(b_children), Some(a_children)) => {
+            is_subset(&a_children[0], &b_children[0]) && is_subset(&a_children[1], &b_children[1])
+        }
+    }
+}
+
+// returns true if no address is in both `a` and `b`
+fn is_disjoint(a: &Node, b: &Node) -> bool {
+    let (a, b) = match b.length < a.length {
+        true => (b, a),
+        false => (a, b),
+    };
+    // `a` is now no longer than `b`
+    if !a.contains(b.network, b.length) {
+        return true;
+    }
+    match (&a.children, &b.children) {
+        (None, _) => false,
+        (Some(_), None) if a.length == b.length => false,
+        (Some(a_children), Some(b_children)) if a.length == b.length => {
+            is_disjoint(&a_children[0], &b_children[0])
+                && is_disjoint(&a_children[1], &b_children[1])
+        }
+        (Some(children), _) => is_disjoint(&children[bit(b.network, a.length)], b),
+    }
+}
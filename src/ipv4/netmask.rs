@@ -0,0 +1,127 @@
+use std::{fmt, str::FromStr};
+
+use super::{Addr, Address, MASKS};
+use crate::{Error, Result};
+
+/// A netmask which is guaranteed to be valid: anywhere from 0 to 32 left-most bits are all 1s
+/// followed by all 0s on the right.
+///
+/// Since it can only be constructed from a valid mask, it can be given to
+/// [`super::Prefix::from_address_netmask`] without checking it again.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{Addr, Netmask};
+/// let mask: Netmask = "255.255.252.0".parse().unwrap();
+/// assert_eq!(22, mask.length());
+/// assert_eq!(Addr::new(255, 255, 252, 0), mask.into());
+///
+/// assert!("255.255.0.255".parse::<Netmask>().is_err());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Netmask(u8);
+
+impl Netmask {
+    /// returns the netmask with the given number of leading 1s. If the length is greater than 32
+    /// then [`Error::InvalidLength`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Netmask;
+    /// assert_eq!("255.255.255.192", Netmask::from_length(26).unwrap().to_string());
+    /// assert!(Netmask::from_length(33).is_err());
+    /// ```
+    pub const fn from_length(length: u8) -> Result<Self> {
+        match length {
+            0..=32 => Ok(Self(length)),
+            _ => Err(Error::InvalidLength),
+        }
+    }
+
+    /// returns the netmask equal to the given address. If the address isn't a valid mask then
+    /// [`Error::InvalidMask`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Netmask;
+    /// # use std::net::Ipv4Addr;
+    /// let mask = Netmask::new(Ipv4Addr::new(255, 255, 255, 0)).unwrap();
+    /// assert_eq!(24, mask.length());
+    /// assert!(Netmask::new(Ipv4Addr::new(255, 0, 255, 0)).is_err());
+    /// ```
+    pub fn new<A: Address>(mask: A) -> Result<Self> {
+        let mask: u32 = mask.into();
+        let length = mask.leading_ones() as u8;
+        match length + mask.trailing_zeros() as u8 == A::BITS {
+            true => Ok(Self(length)),
+            false => Err(Error::InvalidMask),
+        }
+    }
+
+    /// returns the number of leading 1s in the mask which is the corresponding prefix length
+    pub const fn length(&self) -> u8 {
+        self.0
+    }
+
+    /// returns the mask as an address of any type
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Netmask;
+    /// # use std::net::Ipv4Addr;
+    /// let mask: Ipv4Addr = Netmask::from_length(20).unwrap().address();
+    /// assert_eq!(Ipv4Addr::new(255, 255, 240, 0), mask);
+    /// ```
+    pub fn address<A: Address>(&self) -> A {
+        MASKS[self.0 as usize].into()
+    }
+}
+
+impl From<Netmask> for u32 {
+    fn from(value: Netmask) -> Self {
+        MASKS[value.0 as usize]
+    }
+}
+
+impl From<Netmask> for Addr {
+    fn from(value: Netmask) -> Self {
+        value.address()
+    }
+}
+
+impl TryFrom<u32> for Netmask {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self> {
+        Self::new(Addr::from(value))
+    }
+}
+
+impl TryFrom<Addr> for Netmask {
+    type Error = Error;
+
+    fn try_from(value: Addr) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl fmt::Display for Netmask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.address::<Addr>(), f)
+    }
+}
+
+impl fmt::Debug for Netmask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for Netmask {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mask: Addr = s.parse()?;
+        Self::new(mask)
+    }
+}
@@ -0,0 +1,46 @@
+use super::UI;
+
+/// Selects how permissive [`super::Address::parse_with`] is.
+///
+/// Different parsers disagreeing on what an address string means has caused real security bugs,
+/// e.g. an allow list checked with one parser while the connection is made with another. Picking
+/// a mode explicitly makes that choice visible at the call site.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug, Default)]
+pub enum ParseMode {
+    /// accepts only four decimal octets without leading zeros, e.g. `10.0.0.1`, exactly like
+    /// [`std::net::Ipv4Addr`]
+    #[default]
+    Strict,
+
+    /// accepts every form taken by the C library's `inet_aton`: one to four parts where the last
+    /// part fills the remaining bytes (e.g. `10.1` is `10.0.0.1`) and each part may be given in
+    /// hexadecimal with a `0x` prefix or in octal with a leading `0` (e.g. `010` is 8)
+    Lenient,
+}
+
+// parses any of the forms accepted by inet_aton
+pub(super) fn parse_lenient(s: &str) -> Option<UI> {
+    fn part(s: &str) -> Option<UI> {
+        let (digits, radix) = match s.as_bytes() {
+            [b'0', b'x' | b'X', ..] => (&s[2..], 16),
+            [b'0', _, ..] => (&s[1..], 8),
+            _ => (s, 10),
+        };
+        match !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            true => UI::from_str_radix(digits, radix).ok(),
+            false => None,
+        }
+    }
+
+    let parts = s.split('.').map(part).collect::<Option<Vec<_>>>()?;
+    let (last, leading) = parts.split_last()?;
+    if leading.len() > 3 || leading.iter().any(|p| *p > 0xff) {
+        return None;
+    }
+    let bits = 8 * leading.len() as u32;
+    if bits > 0 && *last >> (32 - bits) != 0 {
+        return None;
+    }
+    let leading = leading.iter().fold(0 as UI, |acc, p| acc << 8 | p);
+    Some(leading.checked_shl(32 - bits).unwrap_or(0) | last)
+}
@@ -0,0 +1,197 @@
+use std::{fmt, str::FromStr};
+
+use super::{addr, format, Addr, Addresses, Netmask, Prefix, PrefixLength};
+use crate::{Error, ParseErrorKind, Result};
+
+/// A concrete IPv4 prefix provided by this crate so that it can be used out of the box without
+/// depending on [`ipnet`] or implementing [`super::Prefix`] for a type of your own.
+///
+/// It packs an [`Addr`] and a length into 8 bytes. Like other [`super::Prefix`] implementations,
+/// the host bits of the address are kept. Prefixes are ordered first by address and then by
+/// length.
+///
+/// It can be parsed from CIDR notation, e.g. `10.0.0.0/24`, or from an address and a netmask,
+/// e.g. `10.0.0.0/255.255.255.0`.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{Addr, Pfx, Prefix};
+/// let prefix: Pfx = "10.224.24.1/24".parse().unwrap();
+/// assert_eq!(Addr::new(10, 224, 24, 1), prefix.address());
+/// assert_eq!(24, prefix.length());
+/// assert_eq!("10.224.24.1/24", prefix.to_string());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Pfx {
+    address: Addr,
+    length: u8,
+}
+
+impl Pfx {
+    /// returns a new prefix from the given address and length. If the length is greater than 32
+    /// then [`Error::InvalidLength`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Pfx};
+    /// let prefix = Pfx::new(Addr::new(10, 224, 24, 1), 24).unwrap();
+    /// assert_eq!("10.224.24.1/24", prefix.to_string());
+    /// assert!(Pfx::new(Addr::new(10, 224, 24, 1), 33).is_err());
+    /// ```
+    pub const fn new(address: Addr, length: u8) -> Result<Self> {
+        match length {
+            0..=32 => Ok(Self { address, length }),
+            _ => Err(Error::InvalidLength),
+        }
+    }
+
+    // returns the bits of the address and the length in a const context
+    pub(super) const fn to_parts(self) -> (u32, u8) {
+        (self.address.to_bits(), self.length)
+    }
+}
+
+impl Pfx {
+    /// parses a prefix in CIDR notation in a const context, returning [`None`] if it is invalid.
+    /// The [`prefix!`](crate::prefix) macro uses this to check literals at compile time.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Pfx};
+    /// const PRIVATE: Option<Pfx> = Pfx::parse_const("10.0.0.0/8");
+    /// assert_eq!(Pfx::new(Addr::new(10, 0, 0, 0), 8).ok(), PRIVATE);
+    /// assert_eq!(None, Pfx::parse_const("10.0.0.0/33"));
+    /// ```
+    pub const fn parse_const(s: &str) -> Option<Self> {
+        let bytes = s.as_bytes();
+        let (bits, consumed) = match addr::parse_partial(bytes) {
+            Ok(parsed) => parsed,
+            Err(_) => return None,
+        };
+        if consumed + 1 >= bytes.len() || bytes[consumed] != b'/' {
+            return None;
+        }
+        let mut length = 0u8;
+        let mut i = consumed + 1;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() || i - consumed > 2 {
+                return None;
+            }
+            length = length * 10 + (bytes[i] - b'0');
+            i += 1;
+        }
+        match length {
+            0..=32 => Some(Self {
+                address: Addr::from_bits(bits),
+                length,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl Prefix for Pfx {
+    type Address = Addr;
+
+    fn address(&self) -> Self::Address {
+        self.address
+    }
+
+    fn length(&self) -> u8 {
+        self.length
+    }
+
+    unsafe fn new_unchecked(address: Self::Address, length: u8) -> Self {
+        Self { address, length }
+    }
+}
+
+impl From<ipnet::Ipv4Net> for Pfx {
+    fn from(value: ipnet::Ipv4Net) -> Self {
+        Self {
+            address: value.addr().into(),
+            length: value.prefix_len(),
+        }
+    }
+}
+
+impl From<Pfx> for ipnet::Ipv4Net {
+    fn from(value: Pfx) -> Self {
+        // the length was checked when the prefix was created
+        unsafe { Self::new(value.address.into(), value.length).unwrap_unchecked() }
+    }
+}
+
+// iterates over every address in the prefix, e.g. `for address in prefix { ... }`
+impl IntoIterator for Pfx {
+    type Item = Addr;
+    type IntoIter = Addresses<Addr>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.addresses()
+    }
+}
+
+impl fmt::Display for Pfx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = [0u8; super::MAX_PREFIX_LEN];
+        // the buffer is always big enough
+        let len = format::write_prefix(self.address.octets(), self.length, &mut buf).unwrap();
+        f.pad(format::as_str(&buf, len))
+    }
+}
+
+impl fmt::Debug for Pfx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for Pfx {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (address, consumed) = addr::parse_partial(s.as_bytes())
+            .map_err(|(kind, offset)| Error::parse_error_at(kind, offset, s))?;
+        let start = consumed + 1;
+        let length = match s[consumed..].strip_prefix('/') {
+            Some(length) => length,
+            None => {
+                return Err(Error::parse_error_at(
+                    ParseErrorKind::ExpectedSlash,
+                    consumed,
+                    s,
+                ))
+            }
+        };
+        let length: PrefixLength = match length.contains('.') {
+            true => length
+                .parse::<Netmask>()
+                .map_err(|e| e.within(start, s))?
+                .into(),
+            false => length.parse().map_err(|e: Error| e.within(start, s))?,
+        };
+        let address = Addr::from_bits(address);
+        Ok(Self::from_address_prefix_length(address, length))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Pfx {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Pfx {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,118 @@
+use std::{fmt, str::FromStr};
+
+use super::{Netmask, Wildcard};
+use crate::{Error, ParseErrorKind, Result};
+
+/// A prefix length which is guaranteed to be valid for IPv4, i.e. from 0 to 32.
+///
+/// Methods which take a PrefixLength, like [`super::Prefix::from_address_prefix_length`], don't
+/// need to check it again and so can't fail with [`Error::InvalidLength`]. A constant length can
+/// be checked at compile time with [`PrefixLength::of`].
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::PrefixLength;
+/// let length: PrefixLength = "24".parse().unwrap();
+/// assert_eq!(24, length.get());
+/// assert_eq!(length, PrefixLength::of::<24>());
+///
+/// assert!(PrefixLength::new(33).is_err());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct PrefixLength(u8);
+
+impl PrefixLength {
+    /// the shortest prefix length, /0, which matches every address
+    pub const MIN: Self = Self(0);
+
+    /// the longest prefix length, /32, which matches a single address
+    pub const MAX: Self = Self(32);
+
+    /// returns the given length if it is valid. If it is greater than 32 then
+    /// [`Error::InvalidLength`] is returned.
+    pub const fn new(length: u8) -> Result<Self> {
+        match length {
+            0..=32 => Ok(Self(length)),
+            _ => Err(Error::InvalidLength),
+        }
+    }
+
+    /// returns the given constant length, checking it at compile time.
+    ///
+    /// ```compile_fail
+    /// # use addrs::ipv4::PrefixLength;
+    /// let length = PrefixLength::of::<33>();
+    /// ```
+    pub const fn of<const LENGTH: u8>() -> Self {
+        const { assert!(LENGTH <= 32, "prefix length is more than 32") };
+        Self(LENGTH)
+    }
+
+    /// returns the length as a plain integer
+    pub const fn get(&self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<u8> for PrefixLength {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl From<PrefixLength> for u8 {
+    fn from(value: PrefixLength) -> Self {
+        value.0
+    }
+}
+
+impl From<Netmask> for PrefixLength {
+    fn from(value: Netmask) -> Self {
+        Self(value.length())
+    }
+}
+
+impl From<PrefixLength> for Netmask {
+    fn from(value: PrefixLength) -> Self {
+        // the length was checked when it was created
+        unsafe { Netmask::from_length(value.0).unwrap_unchecked() }
+    }
+}
+
+impl From<Wildcard> for PrefixLength {
+    fn from(value: Wildcard) -> Self {
+        Self(value.length())
+    }
+}
+
+impl From<PrefixLength> for Wildcard {
+    fn from(value: PrefixLength) -> Self {
+        // the length was checked when it was created
+        unsafe { Wildcard::from_length(value.0).unwrap_unchecked() }
+    }
+}
+
+impl fmt::Display for PrefixLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Debug for PrefixLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for PrefixLength {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            true => Self::new(s.parse().or(Err(Error::InvalidLength))?),
+            false => Err(Error::parse_error_at(ParseErrorKind::BadLength, 0, s)),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use std::{fmt, io::BufRead};
+
+use super::Prefix;
+use crate::Error;
+
+/// An error from [`parse_prefixes`] along with the line where it happened.
+#[derive(Debug, Clone)]
+pub struct LineError {
+    line: usize,
+    error: Error,
+}
+
+impl LineError {
+    /// returns the line number, starting from 1
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// returns the error. Failures to read are returned as an [`Error::ParseError`] wrapping the
+    /// [`std::io::Error`].
+    pub fn error(&self) -> &Error {
+        &self.error
+    }
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+impl std::error::Error for LineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// parses a prefix per line from the given reader, as in the prefix list files exported from
+/// routers and registries. Blank lines and comments, which start with `#` and run to the end of
+/// the line, are skipped and surrounding whitespace is ignored. Each line is parsed with
+/// [`Prefix::parse`]. Errors are returned along with their line number and parsing continues with
+/// the next line, except after a failure to read, which ends the iteration.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{parse_prefixes, Pfx};
+/// let input = "# bogons\n10.0.0.0/8\n\n172.16.0.0/12  # private\nbogus\n";
+/// let results: Vec<_> = parse_prefixes::<Pfx, _>(input.as_bytes()).collect();
+/// assert_eq!(3, results.len());
+/// assert_eq!("10.0.0.0/8", results[0].as_ref().unwrap().to_string());
+/// assert_eq!("172.16.0.0/12", results[1].as_ref().unwrap().to_string());
+/// assert_eq!(5, results[2].as_ref().unwrap_err().line());
+/// ```
+pub fn parse_prefixes<P, R>(reader: R) -> impl Iterator<Item = Result<P, LineError>>
+where
+    P: Prefix,
+    <P as std::str::FromStr>::Err: std::error::Error + Send + Sync + 'static,
+    R: BufRead,
+{
+    reader
+        .lines()
+        // a reader that fails may keep failing forever so stop at the first failure
+        .scan(false, |failed, line| match *failed {
+            true => None,
+            false => {
+                *failed = line.is_err();
+                Some(line)
+            }
+        })
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err((i, Error::parse_error(e)))),
+            };
+            let content = line.split('#').next().unwrap_or_default().trim();
+            match content.is_empty() {
+                true => None,
+                false => Some(P::parse(content).map_err(|e| (i, e))),
+            }
+        })
+        .map(|result| result.map_err(|(i, error)| LineError { line: i + 1, error }))
+}
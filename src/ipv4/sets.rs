@@ -0,0 +1,272 @@
+//! Well-known blocks of the IPv4 address space as constant sets, so that they don't need to be
+//! copied into every project. The blocks come from the IANA IPv4 Special-Purpose Address Registry
+//! (<https://www.iana.org/assignments/iana-ipv4-special-registry>) and the RFCs that it cites.
+//!
+//! Each one is a [`Blocks`](crate::ipv4::sets::Blocks), which implements
+//! [`Set`](crate::ipv4::Set) so addresses and prefixes can be tested against it directly. Collect
+//! one into an [`IpSet`](crate::ipv4::IpSet) to combine it with other sets.
+//!
+//! # Example
+//! ```
+//! # use addrs::ipv4::{sets, Addr, IpSet, Pfx, Set};
+//! assert!(sets::BOGONS.contains(&Addr::new(192, 168, 1, 1)));
+//! assert!(!sets::BOGONS.contains(&Addr::new(8, 8, 8, 8)));
+//! assert!(sets::PRIVATE.contains(&"10.1.0.0/16".parse::<Pfx>().unwrap()));
+//!
+//! let bogons: IpSet = sets::BOGONS.iter().copied().collect();
+//! assert!(bogons.contains_set(&sets::PRIVATE));
+//! ```
+
+use std::slice;
+
+use super::{aligned_prefixes, covering_prefixes, Addr, Address, Pfx, Prefix, Set, UI};
+use crate::{prefix, Error, Result};
+
+/// the private address space from [RFC 1918]
+///
+/// [RFC 1918]: https://www.rfc-editor.org/rfc/rfc1918
+pub const PRIVATE: Blocks = set(&[
+    prefix!("10.0.0.0/8"),
+    prefix!("172.16.0.0/12"),
+    prefix!("192.168.0.0/16"),
+]);
+
+/// the loopback addresses from [RFC 1122]
+///
+/// [RFC 1122]: https://www.rfc-editor.org/rfc/rfc1122
+pub const LOOPBACK: Blocks = set(&[prefix!("127.0.0.0/8")]);
+
+/// the link local addresses from [RFC 3927]
+///
+/// [RFC 3927]: https://www.rfc-editor.org/rfc/rfc3927
+pub const LINK_LOCAL: Blocks = set(&[prefix!("169.254.0.0/16")]);
+
+/// the shared address space for carrier-grade NAT from [RFC 6598]
+///
+/// [RFC 6598]: https://www.rfc-editor.org/rfc/rfc6598
+pub const SHARED: Blocks = set(&[prefix!("100.64.0.0/10")]);
+
+/// the blocks reserved for documentation, TEST-NET-1, 2 and 3, from [RFC 5737]
+///
+/// [RFC 5737]: https://www.rfc-editor.org/rfc/rfc5737
+pub const DOCUMENTATION: Blocks = set(&[
+    prefix!("192.0.2.0/24"),
+    prefix!("198.51.100.0/24"),
+    prefix!("203.0.113.0/24"),
+]);
+
+/// the block reserved for benchmarking from [RFC 2544]
+///
+/// [RFC 2544]: https://www.rfc-editor.org/rfc/rfc2544
+pub const BENCHMARKING: Blocks = set(&[prefix!("198.18.0.0/15")]);
+
+/// the multicast addresses from [RFC 5771]
+///
+/// [RFC 5771]: https://www.rfc-editor.org/rfc/rfc5771
+pub const MULTICAST: Blocks = set(&[prefix!("224.0.0.0/4")]);
+
+/// the addresses reserved for future use from [RFC 1112], which include the limited broadcast
+/// address
+///
+/// [RFC 1112]: https://www.rfc-editor.org/rfc/rfc1112
+pub const RESERVED: Blocks = set(&[prefix!("240.0.0.0/4")]);
+
+/// every block in the IANA IPv4 Special-Purpose Address Registry. Entries of the registry which
+/// are inside of other entries, like `192.0.0.8/32` or `255.255.255.255/32`, are covered by the
+/// larger block.
+pub const SPECIAL_PURPOSE: Blocks = set(&[
+    prefix!("0.0.0.0/8"),
+    prefix!("10.0.0.0/8"),
+    prefix!("100.64.0.0/10"),
+    prefix!("127.0.0.0/8"),
+    prefix!("169.254.0.0/16"),
+    prefix!("172.16.0.0/12"),
+    prefix!("192.0.0.0/24"),
+    prefix!("192.0.2.0/24"),
+    prefix!("192.31.196.0/24"),
+    prefix!("192.52.193.0/24"),
+    prefix!("192.88.99.0/24"),
+    prefix!("192.168.0.0/16"),
+    prefix!("192.175.48.0/24"),
+    prefix!("198.18.0.0/15"),
+    prefix!("198.51.100.0/24"),
+    prefix!("203.0.113.0/24"),
+    prefix!("240.0.0.0/4"),
+]);
+
+/// the "martians" which should never appear as a source or destination on the public internet,
+/// the usual list of unallocated bogons used in route filters. It includes the multicast space.
+pub const BOGONS: Blocks = set(&[
+    prefix!("0.0.0.0/8"),
+    prefix!("10.0.0.0/8"),
+    prefix!("100.64.0.0/10"),
+    prefix!("127.0.0.0/8"),
+    prefix!("169.254.0.0/16"),
+    prefix!("172.16.0.0/12"),
+    prefix!("192.0.0.0/24"),
+    prefix!("192.0.2.0/24"),
+    prefix!("192.168.0.0/16"),
+    prefix!("198.18.0.0/15"),
+    prefix!("198.51.100.0/24"),
+    prefix!("203.0.113.0/24"),
+    prefix!("224.0.0.0/4"),
+    prefix!("240.0.0.0/4"),
+]);
+
+/// A constant set of IPv4 addresses backed by a slice of prefixes, like the well-known blocks in
+/// this module. The prefixes must be aligned, sorted and must not overlap so that the set can be
+/// built in a const context without allocating.
+///
+/// # Example
+/// ```
+/// # use addrs::{prefix, ipv4::{sets::Blocks, Addr, Set}};
+/// const LAB: Blocks = match Blocks::new(&[prefix!("10.1.0.0/16"), prefix!("10.2.0.0/16")]) {
+///     Some(set) => set,
+///     None => panic!("not a set"),
+/// };
+/// assert!(LAB.contains(&Addr::new(10, 2, 3, 4)));
+/// assert_eq!(2, LAB.num_prefixes(16).unwrap());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Blocks(&'static [Pfx]);
+
+impl Blocks {
+    /// returns a set of the given prefixes or [`None`] if any of them isn't aligned or if they
+    /// aren't sorted without overlapping
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::{prefix, ipv4::sets::Blocks};
+    /// assert!(Blocks::new(&[prefix!("10.0.0.0/8"), prefix!("192.168.0.0/16")]).is_some());
+    /// assert!(Blocks::new(&[prefix!("192.168.0.0/16"), prefix!("10.0.0.0/8")]).is_none());
+    /// assert!(Blocks::new(&[prefix!("10.0.0.0/8"), prefix!("10.1.0.0/16")]).is_none());
+    /// assert!(Blocks::new(&[prefix!("10.0.0.1/8")]).is_none());
+    /// ```
+    pub const fn new(prefixes: &'static [Pfx]) -> Option<Self> {
+        // the first address that the next prefix may start at
+        let mut next = 0u64;
+        let mut i = 0;
+        while i < prefixes.len() {
+            let (network, length) = prefixes[i].to_parts();
+            let (network, size) = (network as u64, 1u64 << (32 - length));
+            if network < next || network % size != 0 {
+                return None;
+            }
+            next = network + size;
+            i += 1;
+        }
+        Some(Self(prefixes))
+    }
+
+    /// returns the prefixes in the set, in order
+    pub const fn as_slice(&self) -> &'static [Pfx] {
+        self.0
+    }
+
+    /// returns an iterator over the prefixes in the set, in order
+    pub fn iter(&self) -> slice::Iter<'static, Pfx> {
+        self.0.iter()
+    }
+
+    // returns the runs of adjacent prefixes, each as its first and last address
+    fn runs(&self) -> impl Iterator<Item = (UI, UI)> {
+        let mut prefixes = self.0.iter().peekable();
+        std::iter::from_fn(move || {
+            let prefix = prefixes.next()?;
+            let (first, mut last) = (prefix.network().to_bits(), prefix.broadcast().to_bits());
+            while let Some(next) =
+                prefixes.next_if(|p| Some(p.network().to_bits()) == last.checked_add(1))
+            {
+                last = next.broadcast().to_bits();
+            }
+            Some((first, last))
+        })
+    }
+
+    // returns the largest aligned blocks that together cover the set, in order
+    fn blocks(&self) -> impl Iterator<Item = Pfx> {
+        self.runs()
+            .flat_map(|(first, last)| covering_prefixes(first, last))
+            .map(|(network, length)| unsafe { Pfx::new_unchecked(network.into(), length) })
+    }
+}
+
+impl Set for Blocks {
+    type Address = Addr;
+
+    fn num_prefixes(&self, length: u8) -> Result<u32> {
+        if Self::Address::BITS < length {
+            return Err(Error::InvalidLength);
+        }
+        let mut count = 0u32;
+        for prefix in self.blocks() {
+            count = count
+                .checked_add(prefix.num_prefixes(length)?)
+                .ok_or(Error::TooMany)?;
+        }
+        Ok(count)
+    }
+
+    fn num_prefixes_u64(&self, length: u8) -> Result<u64> {
+        if Self::Address::BITS < length {
+            return Err(Error::InvalidLength);
+        }
+        let mut count = 0;
+        for prefix in self.blocks() {
+            count += prefix.num_prefixes_u64(length)?;
+        }
+        Ok(count)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn contains<P: Prefix<Address = Addr>>(&self, other: &P) -> bool {
+        let (network, broadcast) = (other.network().to_bits(), other.broadcast().to_bits());
+        self.runs()
+            .any(|(first, last)| first <= network && broadcast <= last)
+    }
+
+    fn overlaps<P: Prefix<Address = Addr>>(&self, other: &P) -> bool {
+        let (network, broadcast) = (other.network().to_bits(), other.broadcast().to_bits());
+        self.runs()
+            .any(|(first, last)| first <= broadcast && network <= last)
+    }
+
+    fn coarsen<P: Prefix<Address = Addr>>(&self, length: u8) -> Result<Vec<P>> {
+        let mut coarse = vec![];
+        for (first, last) in self.runs() {
+            coarse.extend(super::coarsen::<P>(first, last, length)?);
+        }
+        // neighboring runs may fall in the same coarse prefix
+        coarse.dedup();
+        Ok(coarse)
+    }
+
+    fn prefixes<P: Prefix<Address = Addr>>(&self, length: u8) -> Result<impl Iterator<Item = P>> {
+        let blocks = self.blocks().map(|p| (p.network().to_bits(), p.length()));
+        aligned_prefixes(blocks, length)
+    }
+
+    fn iter_addresses(&self) -> impl Iterator<Item = Addr> {
+        self.0.iter().flat_map(Prefix::addresses)
+    }
+}
+
+impl IntoIterator for Blocks {
+    type Item = &'static Pfx;
+    type IntoIter = slice::Iter<'static, Pfx>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// returns a set of the given prefixes in a const context, failing to compile if they don't form one
+const fn set(prefixes: &'static [Pfx]) -> Blocks {
+    match Blocks::new(prefixes) {
+        Some(set) => set,
+        None => panic!("the prefixes must be aligned, sorted and not overlap"),
+    }
+}
@@ -0,0 +1,104 @@
+use std::{iter::FusedIterator, marker::PhantomData};
+
+use super::{Address, UI};
+
+/// Iterates over every address in a prefix exactly once, in a pseudorandom order determined by a
+/// seed. Use [`Prefix::shuffled_addresses`](super::Prefix::shuffled_addresses) to get one. The
+/// order comes from a keyed bijection over the host bits, so nothing needs to be remembered about
+/// the addresses already visited. It is good enough to spread out probes but not suitable for
+/// cryptographic purposes. It is an [`ExactSizeIterator`] on targets where [`usize`] is wide
+/// enough to count all 2<sup>32</sup> addresses.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{Pfx, Prefix};
+/// let prefix: Pfx = "10.0.0.0/29".parse().unwrap();
+/// let mut addresses: Vec<_> = prefix.shuffled_addresses(42).collect();
+/// assert_eq!(8, addresses.len());
+/// addresses.sort();
+/// assert_eq!(prefix.into_iter().collect::<Vec<_>>(), addresses);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Shuffled<A: Address> {
+    network: UI,
+    // the number of host bits and a mask of them
+    bits: u32,
+    mask: u64,
+    // the multiplier, which is always odd, and the offset for each round of mixing
+    rounds: [(u64, u64); 3],
+    // the next index to permute and the total number of addresses
+    next: u64,
+    end: u64,
+    address: PhantomData<A>,
+}
+
+impl<A: Address> Shuffled<A> {
+    pub(super) fn new(network: A, length: u8, seed: u64) -> Self {
+        let bits = u32::from(A::BITS - length);
+        let mut state = seed;
+        let rounds = [(); 3].map(|_| (splitmix64(&mut state) | 1, splitmix64(&mut state)));
+        Self {
+            network: network.into(),
+            bits,
+            mask: (1 << bits) - 1,
+            rounds,
+            next: 0,
+            end: 1 << bits,
+            address: PhantomData,
+        }
+    }
+
+    // maps an index to a host part; each step is a bijection on the host bits so the whole thing
+    // is too
+    fn permute(&self, mut x: u64) -> u64 {
+        let shift = self.bits.div_ceil(2).max(1);
+        for (multiplier, offset) in self.rounds {
+            x = x.wrapping_mul(multiplier) & self.mask;
+            x ^= x >> shift;
+            x = x.wrapping_add(offset) & self.mask;
+        }
+        x
+    }
+}
+
+impl<A: Address> Iterator for Shuffled<A> {
+    type Item = A;
+
+    fn next(&mut self) -> Option<A> {
+        match self.next < self.end {
+            true => {
+                let host = self.permute(self.next) as UI;
+                self.next += 1;
+                Some(A::from(self.network | host))
+            }
+            false => None,
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<A> {
+        self.next = self.next.saturating_add(n as u64).min(self.end);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.end - self.next) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+// every count fits when usize has at least 64 bits
+#[cfg(target_pointer_width = "64")]
+impl<A: Address> ExactSizeIterator for Shuffled<A> {}
+
+impl<A: Address> FusedIterator for Shuffled<A> {}
+
+// derives a stream of well mixed values from the seed
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
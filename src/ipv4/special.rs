@@ -0,0 +1,35 @@
+// Support for classifying prefixes against the blocks in the `sets` module.
+
+use super::{sets, Pfx, Prefix, Set, MASKS, UI};
+use crate::prefix;
+
+// returns the blocks whose addresses are not globally reachable, the same ones excluded by
+// `std::net::Ipv4Addr::is_global`. These are the bogons except for multicast, which is global.
+pub(super) fn not_global() -> impl Iterator<Item = &'static Pfx> {
+    sets::BOGONS
+        .iter()
+        .filter(|block| !sets::MULTICAST.contains(*block))
+}
+
+// globally reachable addresses inside of the blocks above
+pub(super) const GLOBAL: &[Pfx] = &[prefix!("192.0.0.9/32"), prefix!("192.0.0.10/32")];
+
+// returns true if the prefix is wholly contained in one of the blocks
+pub(super) fn contains(blocks: &[Pfx], network: UI, length: u8) -> bool {
+    blocks.iter().any(|block| {
+        let len = block.length();
+        len <= length && network & MASKS[len as usize] == block.address().to_bits()
+    })
+}
+
+// returns true if the prefix has any address in common with one of the blocks
+pub(super) fn overlaps<'a>(
+    blocks: impl IntoIterator<Item = &'a Pfx>,
+    network: UI,
+    length: u8,
+) -> bool {
+    blocks.into_iter().any(|block| {
+        let mask = MASKS[block.length().min(length) as usize];
+        network & mask == block.address().to_bits() & mask
+    })
+}
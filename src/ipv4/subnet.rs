@@ -0,0 +1,135 @@
+use std::{fmt, str::FromStr};
+
+use super::{Addr, Pfx, Prefix};
+use crate::{Error, Result};
+
+/// A prefix whose host bits are guaranteed to be zero so that its address is always equal to its
+/// network address.
+///
+/// The invariant is enforced when a Subnet is created so algorithms that rely on it don't need to
+/// mask the address over and over again. Use [`Subnet::new`] to reject addresses with host bits
+/// set or [`Subnet::masked`] to clear them.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{Addr, Prefix, Subnet};
+/// let subnet: Subnet = "10.224.24.0/24".parse().unwrap();
+/// assert_eq!(subnet.network(), subnet.address());
+///
+/// assert!("10.224.24.1/24".parse::<Subnet>().is_err());
+/// assert_eq!(subnet, Subnet::masked(Addr::new(10, 224, 24, 1), 24).unwrap());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Subnet(Pfx);
+
+impl Subnet {
+    /// returns a new subnet from the given address and length. If any host bits are set in the
+    /// address then [`Error::HostBitsSet`] is returned. If the length is greater than 32 then
+    /// [`Error::InvalidLength`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Subnet};
+    /// assert!(Subnet::new(Addr::new(10, 224, 24, 0), 24).is_ok());
+    /// assert!(Subnet::new(Addr::new(10, 224, 24, 1), 24).is_err());
+    /// ```
+    pub fn new(address: Addr, length: u8) -> Result<Self> {
+        Pfx::new(address, length)?.try_into()
+    }
+
+    /// returns a new subnet from the given address and length, clearing any host bits set in the
+    /// address. If the length is greater than 32 then [`Error::InvalidLength`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::{Addr, Subnet};
+    /// let subnet = Subnet::masked(Addr::new(10, 224, 24, 1), 24).unwrap();
+    /// assert_eq!("10.224.24.0/24", subnet.to_string());
+    /// ```
+    pub fn masked(address: Addr, length: u8) -> Result<Self> {
+        Ok(Self::from_prefix_masked(Pfx::new(address, length)?))
+    }
+
+    fn from_prefix_masked<P: Prefix<Address = Addr>>(prefix: P) -> Self {
+        Self(unsafe { Pfx::new_unchecked(prefix.network(), prefix.length()) })
+    }
+}
+
+impl Prefix for Subnet {
+    type Address = Addr;
+
+    fn address(&self) -> Self::Address {
+        self.0.address()
+    }
+
+    fn length(&self) -> u8 {
+        self.0.length()
+    }
+
+    /// clears any host bits in the given address to maintain the invariant
+    unsafe fn new_unchecked(ip: Self::Address, length: u8) -> Self {
+        Self::from_prefix_masked(Pfx::new_unchecked(ip, length))
+    }
+
+    fn network(&self) -> Self::Address {
+        self.0.address()
+    }
+}
+
+impl TryFrom<Pfx> for Subnet {
+    type Error = Error;
+
+    fn try_from(value: Pfx) -> Result<Self> {
+        match value.host() == Addr::UNSPECIFIED {
+            true => Ok(Self(value)),
+            false => Err(Error::HostBitsSet),
+        }
+    }
+}
+
+impl From<Subnet> for Pfx {
+    fn from(value: Subnet) -> Self {
+        value.0
+    }
+}
+
+impl fmt::Display for Subnet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Debug for Subnet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for Subnet {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.parse::<Pfx>()?.try_into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Subnet {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Subnet {
+    fn deserialize<D: serde::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        Pfx::deserialize(deserializer)?
+            .try_into()
+            .map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,164 @@
+//! Helpers to pack addresses and prefixes into the type-length-value byte layouts used by DHCP
+//! options, relay agent suboptions (e.g. option 82) and vendor specific suboptions.
+//!
+//! Each `put_*` function appends to a [`Vec<u8>`]. Each `get_*` function reads from the front of
+//! a byte slice and also returns the number of bytes it consumed so that the caller can continue
+//! with the rest of the buffer. When the input is truncated or malformed, [`Error::ParseError`]
+//! is returned.
+//!
+//! # Example
+//! ```
+//! # use addrs::ipv4::{tlv, Addr};
+//! // a relay agent link selection suboption (5) carrying a single address
+//! let mut buf = vec![];
+//! tlv::put_option(5, &Addr::new(10, 0, 0, 1).octets(), &mut buf).unwrap();
+//! assert_eq!(vec![5, 4, 10, 0, 0, 1], buf);
+//!
+//! let (code, value, consumed) = tlv::get_option(&buf).unwrap();
+//! assert_eq!((5, 6), (code, consumed));
+//! let (addresses, _) = tlv::get_addresses::<Addr>(value).unwrap();
+//! assert_eq!(vec![Addr::new(10, 0, 0, 1)], addresses);
+//! ```
+
+use super::{Address, Prefix};
+use crate::{Error, Result};
+
+/// appends a type-length-value option: a code byte, a length byte and then the value. If the
+/// value is longer than 255 bytes, [`Error::TooMany`] is returned and nothing is appended.
+pub fn put_option(code: u8, value: &[u8], buf: &mut Vec<u8>) -> Result<()> {
+    let length: u8 = value.len().try_into().or(Err(Error::TooMany))?;
+    buf.extend([code, length]);
+    buf.extend(value);
+    Ok(())
+}
+
+/// reads a type-length-value option from the front of the buffer and returns its code, its value
+/// and the number of bytes consumed
+pub fn get_option(buf: &[u8]) -> Result<(u8, &[u8], usize)> {
+    match buf {
+        [code, length, rest @ ..] => {
+            let length = *length as usize;
+            let value = rest.get(..length).ok_or(Error::ParseError(None))?;
+            Ok((*code, value, length + 2))
+        }
+        _ => Err(Error::ParseError(None)),
+    }
+}
+
+/// appends the addresses, each in network order, without any framing. The value of an option
+/// holding a list of addresses is in this format.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{tlv, Addr};
+/// let mut buf = vec![];
+/// tlv::put_addresses([Addr::new(10, 0, 0, 1), Addr::new(10, 0, 0, 2)], &mut buf);
+/// assert_eq!(vec![10, 0, 0, 1, 10, 0, 0, 2], buf);
+/// ```
+pub fn put_addresses<A, I>(addresses: I, buf: &mut Vec<u8>)
+where
+    A: Address,
+    I: IntoIterator<Item = A>,
+{
+    for address in addresses {
+        buf.extend(address.octets());
+    }
+}
+
+/// reads the whole buffer as a list of addresses in network order. The buffer length must be a
+/// multiple of 4.
+pub fn get_addresses<A: Address>(buf: &[u8]) -> Result<(Vec<A>, usize)> {
+    let chunks = buf.chunks_exact(4);
+    if !chunks.remainder().is_empty() {
+        return Err(Error::ParseError(None));
+    }
+    let addresses = chunks
+        .map(|chunk| A::from([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect();
+    Ok((addresses, buf.len()))
+}
+
+/// appends a length-prefixed list of addresses: a byte with the number of bytes that follow and
+/// then each address in network order. If there are more than 63 addresses, [`Error::TooMany`] is
+/// returned and nothing is appended.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{tlv, Addr};
+/// let mut buf = vec![];
+/// tlv::put_address_list([Addr::new(10, 0, 0, 1), Addr::new(10, 0, 0, 2)], &mut buf).unwrap();
+/// assert_eq!(vec![8, 10, 0, 0, 1, 10, 0, 0, 2], buf);
+///
+/// let (addresses, consumed) = tlv::get_address_list::<Addr>(&buf).unwrap();
+/// assert_eq!(vec![Addr::new(10, 0, 0, 1), Addr::new(10, 0, 0, 2)], addresses);
+/// assert_eq!(9, consumed);
+/// ```
+pub fn put_address_list<A, I>(addresses: I, buf: &mut Vec<u8>) -> Result<()>
+where
+    A: Address,
+    I: IntoIterator<Item = A>,
+{
+    let start = buf.len();
+    buf.push(0);
+    put_addresses(addresses, buf);
+    match u8::try_from(buf.len() - start - 1) {
+        Ok(length) => {
+            buf[start] = length;
+            Ok(())
+        }
+        Err(_) => {
+            buf.truncate(start);
+            Err(Error::TooMany)
+        }
+    }
+}
+
+/// reads a length-prefixed list of addresses from the front of the buffer and returns the
+/// addresses and the number of bytes consumed
+pub fn get_address_list<A: Address>(buf: &[u8]) -> Result<(Vec<A>, usize)> {
+    let (length, rest) = buf.split_first().ok_or(Error::ParseError(None))?;
+    let list = rest
+        .get(..*length as usize)
+        .ok_or(Error::ParseError(None))?;
+    let (addresses, consumed) = get_addresses(list)?;
+    Ok((addresses, consumed + 1))
+}
+
+/// appends the prefix in the compact encoding of [RFC 3442]: a byte with the prefix length
+/// followed by only the significant octets of the network address. Host bits are not encoded.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{tlv, Pfx};
+/// let prefix: Pfx = "10.17.0.0/16".parse().unwrap();
+/// let mut buf = vec![];
+/// tlv::put_prefix(&prefix, &mut buf);
+/// assert_eq!(vec![16, 10, 17], buf);
+///
+/// let (decoded, consumed) = tlv::get_prefix::<Pfx>(&buf).unwrap();
+/// assert_eq!(prefix, decoded);
+/// assert_eq!(3, consumed);
+/// ```
+///
+/// [RFC 3442]: https://www.rfc-editor.org/rfc/rfc3442
+pub fn put_prefix<P: Prefix>(prefix: &P, buf: &mut Vec<u8>) {
+    let significant = prefix.length().div_ceil(8) as usize;
+    buf.push(prefix.length());
+    buf.extend(&prefix.network().octets()[..significant]);
+}
+
+/// reads a prefix in the compact encoding of [RFC 3442] from the front of the buffer and returns
+/// it with the number of bytes consumed. If the length byte is more than 32,
+/// [`Error::InvalidLength`] is returned.
+///
+/// [RFC 3442]: https://www.rfc-editor.org/rfc/rfc3442
+pub fn get_prefix<P: Prefix>(buf: &[u8]) -> Result<(P, usize)> {
+    let (length, rest) = buf.split_first().ok_or(Error::ParseError(None))?;
+    let significant = length.div_ceil(8) as usize;
+    if significant > 4 {
+        return Err(Error::InvalidLength);
+    }
+    let mut octets = [0u8; 4];
+    octets[..significant].copy_from_slice(rest.get(..significant).ok_or(Error::ParseError(None))?);
+    Ok((P::new(octets.into(), *length)?, significant + 1))
+}
@@ -0,0 +1,159 @@
+use super::{Address, AddressRange, Block, Prefix, Set, UI};
+use crate::{Error, Result};
+
+/// Walks the addresses of a set in order and keeps a checkpoint, the last address it returned, so
+/// that a long-running scan can be saved and resumed later, e.g. after a restart, without starting
+/// over. The set can be a prefix, a range, an [`IpSet`](super::IpSet) or any other [`Set`]. The
+/// checkpoint is a plain address so it can be stored however the caller likes.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{Addr, AddressRange, IpSet, Pfx, Walker};
+/// let prefix: Pfx = "10.0.0.0/30".parse().unwrap();
+/// let range = AddressRange::from_prefix(&prefix);
+///
+/// let mut walker = Walker::new(range);
+/// assert_eq!(Some(Addr::new(10, 0, 0, 0)), walker.next());
+/// assert_eq!(Some(Addr::new(10, 0, 0, 1)), walker.next());
+/// let checkpoint = walker.checkpoint().unwrap().to_string();
+///
+/// // later...
+/// let walker = Walker::resume(range, Some(checkpoint.parse().unwrap())).unwrap();
+/// assert_eq!(
+///     vec![Addr::new(10, 0, 0, 2), Addr::new(10, 0, 0, 3)],
+///     walker.collect::<Vec<_>>()
+/// );
+///
+/// // any set can be walked, skipping the gaps between its prefixes
+/// let set: IpSet = ["10.0.0.0/31", "10.0.0.8/32"]
+///     .iter()
+///     .map(|s| s.parse::<Pfx>().unwrap())
+///     .collect();
+/// assert_eq!(
+///     vec![Addr::new(10, 0, 0, 0), Addr::new(10, 0, 0, 1), Addr::new(10, 0, 0, 8)],
+///     Walker::new(set).collect::<Vec<_>>()
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Walker<S: Set = AddressRange> {
+    set: S,
+    checkpoint: Option<S::Address>,
+    // the last address of the block of the set that the checkpoint is in, so that the rest of the
+    // block can be walked without searching the set again
+    end: Option<S::Address>,
+}
+
+impl<S: Set> Walker<S> {
+    /// returns a walker starting at the first address of the set
+    pub fn new(set: S) -> Self {
+        Self {
+            set,
+            checkpoint: None,
+            end: None,
+        }
+    }
+
+    /// returns a walker that continues after the given checkpoint, as returned by
+    /// [`Walker::checkpoint`]. A checkpoint of [`None`] starts from the beginning. If the
+    /// checkpoint isn't in the set, [`Error::NotContained`] is returned.
+    pub fn resume(set: S, checkpoint: Option<S::Address>) -> Result<Self> {
+        match checkpoint {
+            Some(a) if !set.contains(&a) => Err(Error::NotContained),
+            _ => Ok(Self {
+                set,
+                checkpoint,
+                end: None,
+            }),
+        }
+    }
+
+    /// returns the last address returned by the walker or [`None`] if it hasn't started
+    pub fn checkpoint(&self) -> Option<S::Address> {
+        self.checkpoint
+    }
+
+    /// returns the set being walked
+    pub fn set(&self) -> &S {
+        &self.set
+    }
+
+    /// returns true if every address in the set has been returned
+    pub fn is_done(&self) -> bool {
+        self.peek().is_none()
+    }
+
+    // returns the address after the checkpoint along with the last address of its block
+    fn peek(&self) -> Option<(S::Address, S::Address)> {
+        match (self.checkpoint, self.end) {
+            (Some(last), Some(end)) if last < end => Some(((last.into() + 1).into(), end)),
+            (None, _) => next_block(&self.set, all(), 0),
+            (Some(last), _) => next_block(&self.set, all(), last.into().checked_add(1)?),
+        }
+    }
+}
+
+impl<S: Set> Iterator for Walker<S> {
+    type Item = S::Address;
+
+    fn next(&mut self) -> Option<S::Address> {
+        let (next, end) = self.peek()?;
+        self.checkpoint = Some(next);
+        self.end = Some(end);
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match self.checkpoint {
+            None => count_from(&self.set, all(), 0),
+            Some(last) => match last.into().checked_add(1) {
+                Some(from) => count_from(&self.set, all(), from),
+                None => 0,
+            },
+        };
+        match usize::try_from(remaining) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+fn all<A: Address>() -> Block<A> {
+    unsafe { Block::new_unchecked(0.into(), 0) }
+}
+
+// returns the first address of the set in the block that isn't before `from`, along with the last
+// address of the part of the block that the set wholly covers from there
+fn next_block<S>(set: &S, block: Block<S::Address>, from: UI) -> Option<(S::Address, S::Address)>
+where
+    S: Set + ?Sized,
+{
+    if block.broadcast().into() < from || !set.overlaps(&block) {
+        return None;
+    }
+    if set.contains(&block) {
+        let first = block.network().into().max(from);
+        return Some((first.into(), block.broadcast()));
+    }
+    // the set only partly covers the block so it can't be a single address and must have halves
+    let (left, right) = block.halves()?;
+    next_block(set, left, from).or_else(|| next_block(set, right, from))
+}
+
+// returns the number of addresses of the set in the block that aren't before `from`
+fn count_from<S>(set: &S, block: Block<S::Address>, from: UI) -> u64
+where
+    S: Set + ?Sized,
+{
+    let last: UI = block.broadcast().into();
+    if last < from || !set.overlaps(&block) {
+        return 0;
+    }
+    if set.contains(&block) {
+        let first = block.network().into().max(from);
+        return u64::from(last - first) + 1;
+    }
+    match block.halves() {
+        Some((left, right)) => count_from(set, left, from) + count_from(set, right, from),
+        None => 0,
+    }
+}
@@ -0,0 +1,133 @@
+use std::{fmt, str::FromStr};
+
+use super::{Addr, Address, Netmask, MASKS};
+use crate::{Error, Result};
+
+/// A wildcard, or hostmask, is the inverse of a [`Netmask`]: anywhere from 0 to 32 left-most bits
+/// are all 0s followed by all 1s on the right. It is commonly found in Cisco ACL configurations,
+/// e.g. `0.0.0.255`. Like a Netmask, it is guaranteed to be valid.
+///
+/// # Example
+/// ```
+/// # use addrs::ipv4::{Netmask, Wildcard};
+/// let wildcard: Wildcard = "0.0.3.255".parse().unwrap();
+/// assert_eq!(22, wildcard.length());
+/// assert_eq!("255.255.252.0", Netmask::from(wildcard).to_string());
+///
+/// assert!("0.0.255.0".parse::<Wildcard>().is_err());
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Wildcard(u8);
+
+impl Wildcard {
+    /// returns the wildcard with the given number of leading 0s. If the length is greater than 32
+    /// then [`Error::InvalidLength`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Wildcard;
+    /// assert_eq!("0.0.0.63", Wildcard::from_length(26).unwrap().to_string());
+    /// assert!(Wildcard::from_length(33).is_err());
+    /// ```
+    pub const fn from_length(length: u8) -> Result<Self> {
+        match length {
+            0..=32 => Ok(Self(length)),
+            _ => Err(Error::InvalidLength),
+        }
+    }
+
+    /// returns the wildcard equal to the given address. If the address isn't a valid wildcard then
+    /// [`Error::InvalidMask`] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Wildcard;
+    /// # use std::net::Ipv4Addr;
+    /// let wildcard = Wildcard::new(Ipv4Addr::new(0, 0, 0, 255)).unwrap();
+    /// assert_eq!(24, wildcard.length());
+    /// assert!(Wildcard::new(Ipv4Addr::new(255, 255, 255, 0)).is_err());
+    /// ```
+    pub fn new<A: Address>(wildcard: A) -> Result<Self> {
+        Ok(Netmask::new(!wildcard)?.into())
+    }
+
+    /// returns the number of leading 0s in the wildcard which is the corresponding prefix length
+    pub const fn length(&self) -> u8 {
+        self.0
+    }
+
+    /// returns the wildcard as an address of any type
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::ipv4::Wildcard;
+    /// # use std::net::Ipv4Addr;
+    /// let wildcard: Ipv4Addr = Wildcard::from_length(20).unwrap().address();
+    /// assert_eq!(Ipv4Addr::new(0, 0, 15, 255), wildcard);
+    /// ```
+    pub fn address<A: Address>(&self) -> A {
+        (!MASKS[self.0 as usize]).into()
+    }
+}
+
+impl From<Netmask> for Wildcard {
+    fn from(value: Netmask) -> Self {
+        Self(value.length())
+    }
+}
+
+impl From<Wildcard> for Netmask {
+    fn from(value: Wildcard) -> Self {
+        // the length of a wildcard is always valid
+        unsafe { Netmask::from_length(value.0).unwrap_unchecked() }
+    }
+}
+
+impl From<Wildcard> for u32 {
+    fn from(value: Wildcard) -> Self {
+        !MASKS[value.0 as usize]
+    }
+}
+
+impl From<Wildcard> for Addr {
+    fn from(value: Wildcard) -> Self {
+        value.address()
+    }
+}
+
+impl TryFrom<u32> for Wildcard {
+    type Error = Error;
+
+    fn try_from(value: u32) -> Result<Self> {
+        Self::new(Addr::from(value))
+    }
+}
+
+impl TryFrom<Addr> for Wildcard {
+    type Error = Error;
+
+    fn try_from(value: Addr) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl fmt::Display for Wildcard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.address::<Addr>(), f)
+    }
+}
+
+impl fmt::Debug for Wildcard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl FromStr for Wildcard {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let wildcard: Addr = s.parse()?;
+        Self::new(wildcard)
+    }
+}
@@ -18,7 +18,10 @@
 //! [gonet]: https://pkg.go.dev/net#IP
 
 /// enumerates the possible errors from methods in this crate
-#[derive(Debug)]
+///
+/// It is [`Clone`], [`Send`] and [`Sync`] so that it composes with other error types in
+/// multi-threaded and async code.
+#[derive(Debug, Clone)]
 pub enum Error {
     /// returned when an invalid length was given (i.e. >32 for IPv4 and >128 for IPv6)
     InvalidLength,
@@ -26,10 +29,131 @@ pub enum Error {
     InvalidMask,
     /// returned when counting addresses or prefixes overflows
     TooMany,
-    /// returned when parsing a prefix from a string fails
-    ParseError(Option<Box<dyn std::error::Error>>),
+    /// returned when a prefix has bits set in the host part of its address where that isn't allowed
+    HostBitsSet,
+    /// returned when a buffer is too small to hold the result
+    BufferTooSmall,
+    /// returned when the first address of a range is greater than the last
+    InvalidRange,
+    /// returned when a prefix or address must be contained in another but isn't
+    NotContained,
+    /// returned when there is nothing left to allocate
+    Exhausted,
+    /// returned when parsing a prefix from a string fails. Where it is known, the cause is kept
+    /// as the [`source`](std::error::Error::source). See [`Error::parse_details`].
+    ParseError(Option<std::sync::Arc<dyn std::error::Error + Send + Sync>>),
 }
 
+impl Error {
+    // wraps the cause of a parsing failure
+    pub(crate) fn parse_error<E: std::error::Error + Send + Sync + 'static>(e: E) -> Self {
+        Error::ParseError(Some(std::sync::Arc::new(e)))
+    }
+
+    // describes a parsing failure at the given byte offset of the input
+    pub(crate) fn parse_error_at(kind: ParseErrorKind, offset: usize, input: &str) -> Self {
+        Self::parse_error(ParseErrorDetails {
+            kind,
+            offset,
+            input: input.to_string(),
+        })
+    }
+
+    // moves the details of a parsing failure in part of a string to the whole string, given the
+    // byte offset where the part starts
+    pub(crate) fn within(self, start: usize, input: &str) -> Self {
+        match self.parse_details() {
+            Some(details) => Self::parse_error_at(details.kind, start + details.offset, input),
+            None => self,
+        }
+    }
+
+    /// returns where and why parsing failed, if known. These details are available when parsing
+    /// the types provided by this crate, like [`ipv4::Addr`] and [`ipv4::Pfx`].
+    ///
+    /// # Example
+    /// ```
+    /// # use addrs::{ipv4::Pfx, ParseErrorKind};
+    /// let error = "10.0.0.256/24".parse::<Pfx>().unwrap_err();
+    /// let details = error.parse_details().unwrap();
+    /// assert_eq!(ParseErrorKind::BadOctet, details.kind());
+    /// assert_eq!(7, details.offset());
+    /// assert_eq!("bad octet at byte 7 of \"10.0.0.256/24\"", details.to_string());
+    /// ```
+    pub fn parse_details(&self) -> Option<&ParseErrorDetails> {
+        match self {
+            Error::ParseError(Some(e)) => e.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// categorizes what was wrong with a string that failed to parse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// an octet is missing, isn't a decimal number from 0 to 255, or has a leading zero
+    BadOctet,
+    /// a '.' was expected between octets
+    ExpectedDot,
+    /// a '/' was expected between an address and its length or mask
+    ExpectedSlash,
+    /// a prefix length isn't a decimal number
+    BadLength,
+    /// the input continues after a complete value
+    TrailingCharacters,
+}
+
+impl std::fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::BadOctet => write!(f, "bad octet"),
+            ParseErrorKind::ExpectedDot => write!(f, "expected '.'"),
+            ParseErrorKind::ExpectedSlash => write!(f, "expected '/'"),
+            ParseErrorKind::BadLength => write!(f, "bad prefix length"),
+            ParseErrorKind::TrailingCharacters => write!(f, "unexpected trailing characters"),
+        }
+    }
+}
+
+/// describes where and why parsing a string failed so that tools can point out the problem.
+/// It is found as the source of an [`Error::ParseError`]; see [`Error::parse_details`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseErrorDetails {
+    kind: ParseErrorKind,
+    offset: usize,
+    input: String,
+}
+
+impl ParseErrorDetails {
+    /// returns what was wrong
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// returns the byte offset into the input where the problem was found
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// returns the whole string that failed to parse
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl std::fmt::Display for ParseErrorDetails {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} at byte {} of {:?}",
+            self.kind, self.offset, self.input
+        )
+    }
+}
+
+impl std::error::Error for ParseErrorDetails {}
+
 /// returned from methods in this crate
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -42,6 +166,11 @@ impl std::fmt::Display for Error {
             Error::InvalidMask => write!(f, "invalid netmask"),
             Error::ParseError(_) => write!(f, "prefix parsing failed"),
             Error::TooMany => write!(f, "too many to count"),
+            Error::HostBitsSet => write!(f, "host bits are set in the address"),
+            Error::BufferTooSmall => write!(f, "buffer is too small"),
+            Error::InvalidRange => write!(f, "first address is greater than the last"),
+            Error::NotContained => write!(f, "not contained"),
+            Error::Exhausted => write!(f, "no addresses are left"),
         }
     }
 }
@@ -49,10 +178,7 @@ impl std::fmt::Display for Error {
 impl std::error::Error for Error {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Error::ParseError(s) => match s {
-                Some(e) => Some(&**e),
-                None => None,
-            },
+            Error::ParseError(Some(e)) => Some(&**e),
             _ => None,
         }
     }
@@ -60,3 +186,8 @@ impl std::error::Error for Error {
 
 /// work with IPv4 address, prefixes, etc.
 pub mod ipv4;
+
+/// unsigned integer abstraction for generic address math
+pub mod ui;
+
+mod macros;
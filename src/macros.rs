@@ -0,0 +1,53 @@
+/// Creates an [`ipv4::Addr`](crate::ipv4::Addr) from a string literal which is checked at
+/// compile time, so it can be used in constants and statics without `unwrap()`.
+///
+/// # Example
+/// ```
+/// # use addrs::{addr, ipv4::Addr};
+/// static GATEWAY: Addr = addr!("192.168.0.1");
+/// assert_eq!(Addr::new(192, 168, 0, 1), GATEWAY);
+/// ```
+/// An invalid literal fails to compile.
+/// ```compile_fail
+/// # use addrs::{addr, ipv4::Addr};
+/// const GATEWAY: Addr = addr!("192.168.0.256");
+/// ```
+#[macro_export]
+macro_rules! addr {
+    ($s:literal) => {{
+        const ADDR: $crate::ipv4::Addr = match $crate::ipv4::Addr::parse_const($s) {
+            Some(address) => address,
+            None => panic!(concat!("invalid IPv4 address: ", $s)),
+        };
+        ADDR
+    }};
+}
+
+/// Creates an [`ipv4::Pfx`](crate::ipv4::Pfx) from a string literal in CIDR notation which is
+/// checked at compile time, so it can be used in constants and statics without `unwrap()`.
+///
+/// # Example
+/// ```
+/// # use addrs::{prefix, ipv4::{Pfx, Prefix}};
+/// static PRIVATE: [Pfx; 3] = [
+///     prefix!("10.0.0.0/8"),
+///     prefix!("172.16.0.0/12"),
+///     prefix!("192.168.0.0/16"),
+/// ];
+/// assert_eq!(12, PRIVATE[1].length());
+/// ```
+/// An invalid literal fails to compile.
+/// ```compile_fail
+/// # use addrs::{prefix, ipv4::Pfx};
+/// const PRIVATE: Pfx = prefix!("10.0.0.0/33");
+/// ```
+#[macro_export]
+macro_rules! prefix {
+    ($s:literal) => {{
+        const PREFIX: $crate::ipv4::Pfx = match $crate::ipv4::Pfx::parse_const($s) {
+            Some(prefix) => prefix,
+            None => panic!(concat!("invalid IPv4 prefix: ", $s)),
+        };
+        PREFIX
+    }};
+}
@@ -0,0 +1,142 @@
+use std::{fmt, hash::Hash, ops};
+
+/// Abstracts over the unsigned integer types that back IP addresses, e.g. [`u32`] for IPv4, so
+/// that generic code can do address math without naming the integer type.
+///
+/// It exposes the arithmetic and bit operations that address math needs. It is implemented for
+/// all of the primitive unsigned integer types, including [`crate::ipv4::UI`].
+///
+/// # Example
+/// ```
+/// # use addrs::ui::Int;
+/// // returns the network part of an address for the given prefix length
+/// fn network<I: Int>(address: I, length: u32) -> I {
+///     match I::MAX.checked_shl(I::BITS - length) {
+///         Some(mask) => address & mask,
+///         None => I::ZERO,
+///     }
+/// }
+/// assert_eq!(0x0ae01800u32, network(0x0ae01801u32, 24));
+/// assert_eq!(0u32, network(0x0ae01801u32, 0));
+/// ```
+pub trait Int:
+    Copy
+    + Eq
+    + Ord
+    + Hash
+    + fmt::Debug
+    + fmt::Display
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::BitAnd<Output = Self>
+    + ops::BitOr<Output = Self>
+    + ops::BitXor<Output = Self>
+    + ops::Not<Output = Self>
+    + ops::Shl<u32, Output = Self>
+    + ops::Shr<u32, Output = Self>
+    + Send
+    + Sync
+    + Unpin
+{
+    /// the size of the integer in bits
+    const BITS: u32;
+    /// the integer with all bits set to 0
+    const ZERO: Self;
+    /// the integer 1
+    const ONE: Self;
+    /// the integer with all bits set to 1
+    const MAX: Self;
+
+    /// returns the number of leading 1s
+    fn leading_ones(self) -> u32;
+    /// returns the number of leading 0s
+    fn leading_zeros(self) -> u32;
+    /// returns the number of trailing 1s
+    fn trailing_ones(self) -> u32;
+    /// returns the number of trailing 0s
+    fn trailing_zeros(self) -> u32;
+    /// returns the number of 1s
+    fn count_ones(self) -> u32;
+    /// shifts left by `rhs` bits, returning None if `rhs` is not less than [`Int::BITS`]
+    fn checked_shl(self, rhs: u32) -> Option<Self>;
+    /// shifts right by `rhs` bits, returning None if `rhs` is not less than [`Int::BITS`]
+    fn checked_shr(self, rhs: u32) -> Option<Self>;
+    /// adds `rhs`, returning None on overflow
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// subtracts `rhs`, returning None on underflow
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// raises to the power `exp`, returning None on overflow
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+    /// adds `rhs`, wrapping around at the boundary of the type
+    fn wrapping_add(self, rhs: Self) -> Self;
+    /// subtracts `rhs`, wrapping around at the boundary of the type
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    /// adds `rhs`, saturating at [`Int::MAX`]
+    fn saturating_add(self, rhs: Self) -> Self;
+    /// subtracts `rhs`, saturating at [`Int::ZERO`]
+    fn saturating_sub(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($t:ty)*) => {
+        $(
+            impl Int for $t {
+                const BITS: u32 = <$t>::BITS;
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MAX: Self = <$t>::MAX;
+
+                fn leading_ones(self) -> u32 {
+                    self.leading_ones()
+                }
+                fn leading_zeros(self) -> u32 {
+                    self.leading_zeros()
+                }
+                fn trailing_ones(self) -> u32 {
+                    self.trailing_ones()
+                }
+                fn trailing_zeros(self) -> u32 {
+                    self.trailing_zeros()
+                }
+                fn count_ones(self) -> u32 {
+                    self.count_ones()
+                }
+                fn checked_shl(self, rhs: u32) -> Option<Self> {
+                    self.checked_shl(rhs)
+                }
+                fn checked_shr(self, rhs: u32) -> Option<Self> {
+                    self.checked_shr(rhs)
+                }
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    self.checked_add(rhs)
+                }
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    self.checked_sub(rhs)
+                }
+                fn checked_pow(self, exp: u32) -> Option<Self> {
+                    self.checked_pow(exp)
+                }
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    self.wrapping_add(rhs)
+                }
+                fn wrapping_sub(self, rhs: Self) -> Self {
+                    self.wrapping_sub(rhs)
+                }
+                fn saturating_add(self, rhs: Self) -> Self {
+                    self.saturating_add(rhs)
+                }
+                fn saturating_sub(self, rhs: Self) -> Self {
+                    self.saturating_sub(rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_int! { u8 u16 u32 u64 u128 usize }
+
+// the integer behind addresses must always implement the trait
+const _: fn() = || {
+    fn int<I: Int>() {}
+    int::<crate::ipv4::UI>();
+};
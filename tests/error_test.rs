@@ -0,0 +1,39 @@
+use std::error::Error as _;
+
+use addrs::{ipv4::Pfx, Error};
+
+#[test]
+fn send_sync() {
+    fn check<T: Send + Sync + Clone + 'static>() {}
+    check::<Error>();
+}
+
+#[test]
+fn clone_keeps_source() {
+    let error = "10.0.0.0/bogus".parse::<Pfx>().unwrap_err();
+    let cloned = error.clone();
+    assert_eq!(error.to_string(), cloned.to_string());
+    assert_eq!(
+        error.source().map(ToString::to_string),
+        cloned.source().map(ToString::to_string)
+    );
+    assert!(cloned.source().is_some());
+}
+
+#[test]
+fn no_source() {
+    assert!(Error::InvalidLength.source().is_none());
+    assert!(Error::ParseError(None).source().is_none());
+}
+
+#[test]
+fn parse_details() {
+    assert!(Error::InvalidLength.parse_details().is_none());
+    assert!(Error::ParseError(None).parse_details().is_none());
+
+    let error = "10.0.0.0/bogus".parse::<Pfx>().unwrap_err();
+    assert_eq!(
+        Some("bad prefix length at byte 9 of \"10.0.0.0/bogus\"".to_string()),
+        error.source().map(ToString::to_string)
+    );
+}
@@ -0,0 +1,144 @@
+use addrs::ipv4::{Addr, Address, Prefix, Set};
+use addrs::ParseErrorKind;
+use std::{net::Ipv4Addr, str::FromStr};
+
+#[test]
+fn constants() {
+    assert_eq!("0.0.0.0", Addr::UNSPECIFIED.to_string());
+    assert_eq!("255.255.255.255", Addr::BROADCAST.to_string());
+    assert_eq!("127.0.0.1", Addr::LOCALHOST.to_string());
+}
+
+#[test]
+fn const_new() {
+    const ADDRESS: Addr = Addr::new(10, 224, 24, 1);
+    assert_eq!(0x0ae01801, ADDRESS.to_bits());
+    assert_eq!([10, 224, 24, 1], ADDRESS.octets());
+    assert_eq!(ADDRESS, Addr::from_bits(0x0ae01801));
+}
+
+#[test]
+fn size() {
+    assert_eq!(4, std::mem::size_of::<Addr>());
+    assert_eq!(32, <Addr as Address>::BITS);
+}
+
+fn ordering(a: Addr, b: Addr) {
+    assert!(a < b);
+    assert_eq!(Ipv4Addr::from(a) < Ipv4Addr::from(b), a < b);
+}
+
+runner::tests! { ordering {
+    last_octet(Addr::new(10, 0, 0, 1), Addr::new(10, 0, 0, 2));
+    first_octet(Addr::new(9, 255, 255, 255), Addr::new(10, 0, 0, 0));
+    extremes(Addr::UNSPECIFIED, Addr::BROADCAST);
+} }
+
+fn from_str(s: &str) {
+    // the parser must accept exactly what std does
+    let expected = Ipv4Addr::from_str(s).ok().map(Addr::from);
+    assert_eq!(expected, s.parse::<Addr>().ok());
+}
+
+runner::tests! { from_str {
+    basic("10.224.24.1");
+    zero("0.0.0.0");
+    max("255.255.255.255");
+    three_digits("100.200.250.199");
+    too_big("10.224.24.256");
+    way_too_big("10.224.24.1000");
+    three_octets("10.224.24");
+    five_octets("10.224.24.1.5");
+    leading_zero("10.224.024.1");
+    leading_zeros("10.224.000.1");
+    empty_octet("10..24.1");
+    trailing_dot("10.224.24.1.");
+    leading_dot(".10.224.24.1");
+    plus("10.+224.24.1");
+    minus("10.-224.24.1");
+    space(" 10.224.24.1");
+    trailing_space("10.224.24.1 ");
+    prefix("10.224.24.1/32");
+    hex("0x0a.224.24.1");
+    empty("");
+    non_ascii("10.224.24.１");
+} }
+
+fn from_str_error(kind: ParseErrorKind, offset: usize, s: &str) {
+    let error = s.parse::<Addr>().unwrap_err();
+    let details = error.parse_details().unwrap();
+    assert_eq!(
+        (kind, offset, s),
+        (details.kind(), details.offset(), details.input())
+    );
+}
+
+runner::tests! { from_str_error {
+    too_big(ParseErrorKind::BadOctet, 10, "10.224.24.256");
+    four_digits(ParseErrorKind::BadOctet, 7, "10.224.1000.1");
+    leading_zero(ParseErrorKind::BadOctet, 3, "10.024.24.1");
+    empty(ParseErrorKind::BadOctet, 0, "");
+    empty_octet(ParseErrorKind::BadOctet, 3, "10..24.1");
+    truncated(ParseErrorKind::ExpectedDot, 9, "10.224.24");
+    wrong_separator(ParseErrorKind::ExpectedDot, 6, "10.224:24.1");
+    trailing(ParseErrorKind::TrailingCharacters, 11, "10.224.24.1/32");
+} }
+
+#[test]
+fn format() {
+    let address = Addr::new(10, 224, 24, 1);
+    assert_eq!("10.224.24.1", address.to_string());
+    assert_eq!("10.224.24.1", format!("{address:?}"));
+}
+
+#[test]
+fn format_integer() {
+    let address = Addr::new(10, 0, 0, 1);
+    assert_eq!("a000001", format!("{address:x}"));
+    assert_eq!("0a000001", format!("{address:08x}"));
+    assert_eq!("0x0A000001", format!("{address:#010X}"));
+    assert_eq!("1200000001", format!("{address:o}"));
+    assert_eq!(
+        "00001010000000000000000000000001",
+        format!("{address:032b}")
+    );
+    assert_eq!(
+        format!("{:08x}", address.as_bits()),
+        format!("{address:08x}")
+    );
+}
+
+#[test]
+fn format_flags() {
+    let address = Addr::new(10, 0, 0, 1);
+    assert_eq!("    10.0.0.1", format!("{address:>12}"));
+    assert_eq!("10.0.0.1    |", format!("{address:<12}|"));
+    assert_eq!("__10.0.0.1__", format!("{address:_^12}"));
+}
+
+#[test]
+fn conversions() {
+    let address = Addr::new(10, 224, 24, 1);
+    assert_eq!(address, Addr::from(0x0ae01801u32));
+    assert_eq!(address, Addr::from([10, 224, 24, 1]));
+    assert_eq!(address, Addr::from(Ipv4Addr::new(10, 224, 24, 1)));
+    assert_eq!(Ipv4Addr::new(10, 224, 24, 1), Ipv4Addr::from(address));
+    assert_eq!([10, 224, 24, 1], <[u8; 4]>::from(address));
+}
+
+#[test]
+fn bit_ops() {
+    let address = Addr::new(10, 224, 24, 1);
+    let mask = Addr::new(255, 255, 255, 0);
+    assert_eq!(Addr::new(10, 224, 24, 0), address & mask);
+    assert_eq!(Addr::new(255, 255, 255, 1), address | mask);
+    assert_eq!(Addr::new(0, 0, 0, 255), !mask);
+}
+
+#[test]
+fn as_prefix_and_set() {
+    let address = Addr::new(10, 224, 24, 1);
+    assert_eq!(32, address.length());
+    assert_eq!(1, address.num_addresses().unwrap());
+    assert!(address.contains(&address));
+}
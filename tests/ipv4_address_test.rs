@@ -77,3 +77,382 @@ fn address_set_contains() {
     let ip: util::Address = [10, 224, 24, 1].into();
     assert!(ip.contains(&ip));
 }
+
+#[test]
+fn address_parse() {
+    use ipv4::Prefix;
+    assert_eq!(
+        util::a("10.224.24.1"),
+        util::Address::parse("10.224.24.1").unwrap()
+    );
+    assert!(util::Address::parse("10.224.24.256").is_err());
+    assert!(util::Address::parse("10.224.24.1/32").is_err());
+}
+
+fn address_format_into(expected: Result<&str, addrs::Error>, address: &str, size: usize) {
+    let mut buf = [0u8; 32];
+    let actual = ipv4::Address::format_into(&util::a(address), &mut buf[..size]);
+    util::assert_result(expected, actual);
+}
+
+runner::tests! { address_format_into {
+    zero(Ok("0.0.0.0"), "0.0.0.0", 7);
+    longest(Ok("255.255.255.255"), "255.255.255.255", ipv4::MAX_ADDRESS_LEN);
+    mixed(Ok("10.0.100.1"), "10.0.100.1", 20);
+    too_small(Err(addrs::Error::BufferTooSmall), "255.255.255.255", ipv4::MAX_ADDRESS_LEN - 1);
+    too_small_at_dot(Err(addrs::Error::BufferTooSmall), "10.0.0.1", 2);
+    empty(Err(addrs::Error::BufferTooSmall), "0.0.0.0", 0);
+} }
+
+fn parse_with(expected: Option<&str>, s: &str, mode: ipv4::ParseMode) {
+    let expected = expected.map(util::a);
+    assert_eq!(
+        expected,
+        <util::Address as ipv4::Address>::parse_with(s, mode).ok()
+    );
+}
+
+runner::tests! { parse_with {
+    strict(Some("10.0.0.1"), "10.0.0.1", ipv4::ParseMode::Strict);
+    strict_leading_zero(None, "10.0.0.01", ipv4::ParseMode::Strict);
+    strict_partial(None, "10.1", ipv4::ParseMode::Strict);
+    strict_hex(None, "0xa.0.0.1", ipv4::ParseMode::Strict);
+    lenient_full(Some("10.0.0.1"), "10.0.0.1", ipv4::ParseMode::Lenient);
+    lenient_octal(Some("8.0.0.1"), "010.0.0.1", ipv4::ParseMode::Lenient);
+    lenient_octal_bad_digit(None, "09.0.0.1", ipv4::ParseMode::Lenient);
+    lenient_zero(Some("0.0.0.0"), "0", ipv4::ParseMode::Lenient);
+    lenient_hex(Some("10.0.0.1"), "0x0a.0.0.1", ipv4::ParseMode::Lenient);
+    lenient_hex_upper(Some("10.0.0.255"), "0XA.0.0.0xFF", ipv4::ParseMode::Lenient);
+    lenient_hex_empty(None, "0x.0.0.1", ipv4::ParseMode::Lenient);
+    lenient_one_part(Some("10.0.0.1"), "167772161", ipv4::ParseMode::Lenient);
+    lenient_one_part_hex(Some("10.0.0.1"), "0xa000001", ipv4::ParseMode::Lenient);
+    lenient_one_part_too_big(None, "4294967296", ipv4::ParseMode::Lenient);
+    lenient_two_parts(Some("10.0.0.1"), "10.1", ipv4::ParseMode::Lenient);
+    lenient_two_parts_max(Some("10.255.255.255"), "10.16777215", ipv4::ParseMode::Lenient);
+    lenient_two_parts_too_big(None, "10.16777216", ipv4::ParseMode::Lenient);
+    lenient_three_parts(Some("10.1.1.0"), "10.1.256", ipv4::ParseMode::Lenient);
+    lenient_three_parts_too_big(None, "10.1.65536", ipv4::ParseMode::Lenient);
+    lenient_leading_too_big(None, "256.1", ipv4::ParseMode::Lenient);
+    lenient_five_parts(None, "10.0.0.0.1", ipv4::ParseMode::Lenient);
+    lenient_empty(None, "", ipv4::ParseMode::Lenient);
+    lenient_empty_part(None, "10..1", ipv4::ParseMode::Lenient);
+    lenient_trailing_dot(None, "10.0.0.1.", ipv4::ParseMode::Lenient);
+    lenient_sign(None, "+10.0.0.1", ipv4::ParseMode::Lenient);
+    lenient_space(None, "10.0.0.1 ", ipv4::ParseMode::Lenient);
+} }
+
+fn hilbert_d(expected: Result<(u16, u16), addrs::Error>, address: &str, order: u8) {
+    util::assert_result(expected, ipv4::Address::hilbert_d(&util::a(address), order));
+}
+
+runner::tests! { hilbert_d {
+    order_0(Ok((0, 0)), "255.255.255.255", 0);
+    order_1_first(Ok((0, 0)), "63.255.255.255", 1);
+    order_1_second(Ok((0, 1)), "64.0.0.0", 1);
+    order_1_third(Ok((1, 1)), "128.0.0.0", 1);
+    order_1_last(Ok((1, 0)), "192.0.0.0", 1);
+    order_2(Ok((1, 0)), "16.0.0.0", 2);
+    order_16_first(Ok((0, 0)), "0.0.0.0", 16);
+    order_16_second(Ok((1, 0)), "0.0.0.1", 16);
+    order_16_last(Ok((65535, 0)), "255.255.255.255", 16);
+    too_big(Err(addrs::Error::InvalidLength), "10.0.0.0", 17);
+} }
+
+#[test]
+fn hilbert_d_walk() {
+    // every cell is visited exactly once and each step moves to a neighboring cell
+    for order in 0..=5u8 {
+        let cells = 1u32 << (2 * order);
+        let mut seen = std::collections::HashSet::new();
+        let mut previous: Option<(u16, u16)> = None;
+        for d in 0..cells {
+            let address = util::Address::from(d.checked_shl(32 - 2 * order as u32).unwrap_or(0));
+            let (x, y) = ipv4::Address::hilbert_d(&address, order).unwrap();
+            assert!(seen.insert((x, y)));
+            if let Some((px, py)) = previous {
+                assert_eq!(1, px.abs_diff(x) + py.abs_diff(y));
+            }
+            previous = Some((x, y));
+            let back = <util::Address as ipv4::Address>::from_hilbert_d(order, (x, y)).unwrap();
+            assert_eq!(address, back);
+        }
+    }
+}
+
+#[test]
+fn from_hilbert_d() {
+    let from = <util::Address as ipv4::Address>::from_hilbert_d;
+    assert_eq!(util::a("255.255.255.255"), from(16, (65535, 0)).unwrap());
+    assert_eq!(util::a("0.0.0.0"), from(0, (0, 0)).unwrap());
+    assert!(matches!(from(0, (1, 0)), Err(addrs::Error::NotContained)));
+    assert!(matches!(from(4, (0, 16)), Err(addrs::Error::NotContained)));
+    assert!(matches!(from(17, (0, 0)), Err(addrs::Error::InvalidLength)));
+}
+
+#[test]
+fn as_bits() {
+    let address = util::a("255.255.252.0");
+    let bits = ipv4::Address::as_bits(&address);
+    assert_eq!("fffffc00", format!("{bits:x}"));
+    assert_eq!("FFFFFC00", format!("{bits:X}"));
+    assert_eq!("37777776000", format!("{bits:o}"));
+    assert_eq!("11111111111111111111110000000000", format!("{bits:b}"));
+    assert_eq!("0x0000fffffc00", format!("{bits:#014x}"));
+    assert_eq!("  fffffc00", format!("{bits:>10x}"));
+}
+
+#[test]
+fn to_ptr_name() {
+    assert_eq!(
+        "4.3.2.1.in-addr.arpa",
+        ipv4::Address::to_ptr_name(&util::a("1.2.3.4"))
+    );
+    assert_eq!(
+        "0.0.0.0.in-addr.arpa",
+        ipv4::Address::to_ptr_name(&util::a("0.0.0.0"))
+    );
+    assert_eq!(
+        "255.255.255.255.in-addr.arpa",
+        ipv4::Address::to_ptr_name(&util::a("255.255.255.255"))
+    );
+}
+
+fn from_ptr_name(expected: Option<&str>, s: &str) {
+    let expected = expected.map(util::a);
+    assert_eq!(
+        expected,
+        <util::Address as ipv4::Address>::from_ptr_name(s).ok()
+    );
+}
+
+runner::tests! { from_ptr_name {
+    basic(Some("1.2.3.4"), "4.3.2.1.in-addr.arpa");
+    trailing_dot(Some("1.2.3.4"), "4.3.2.1.in-addr.arpa.");
+    upper_case(Some("10.0.0.255"), "255.0.0.10.IN-ADDR.ARPA");
+    too_few(None, "3.2.1.in-addr.arpa");
+    too_many(None, "5.4.3.2.1.in-addr.arpa");
+    classless(None, "4.0/26.2.1.in-addr.arpa");
+    bad_octet(None, "256.3.2.1.in-addr.arpa");
+    plus(None, "+4.3.2.1.in-addr.arpa");
+    empty_label(None, "4..2.1.in-addr.arpa");
+    wrong_suffix(None, "4.3.2.1.ip6.arpa");
+    no_suffix(None, "4.3.2.1");
+    suffix_only(None, "in-addr.arpa");
+    empty(None, "");
+} }
+
+fn integer_string(address: &str, s: &str) {
+    let address = util::a(address);
+    assert_eq!(s, ipv4::Address::to_integer_string(&address));
+    assert_eq!(
+        address,
+        <util::Address as ipv4::Address>::parse_integer(s).unwrap()
+    );
+}
+
+runner::tests! { integer_string {
+    zero("0.0.0.0", "0");
+    one("0.0.0.1", "1");
+    private("192.168.0.1", "3232235521");
+    max("255.255.255.255", "4294967295");
+} }
+
+fn parse_integer_invalid(s: &str) {
+    assert!(<util::Address as ipv4::Address>::parse_integer(s).is_err());
+}
+
+runner::tests! { parse_integer_invalid {
+    empty("");
+    too_big("4294967296");
+    plus("+1");
+    minus("-1");
+    dotted("10.0.0.1");
+    hex("0xa000001");
+    space(" 1");
+} }
+
+fn parse_partial(expected: Option<(&str, usize)>, bytes: &[u8]) {
+    let expected = expected.map(|(a, consumed)| (util::a(a), consumed));
+    assert_eq!(
+        expected,
+        <util::Address as ipv4::Address>::parse_partial(bytes).ok()
+    );
+}
+
+runner::tests! { parse_partial {
+    whole(Some(("10.0.0.1", 8)), b"10.0.0.1");
+    port(Some(("10.0.0.1", 8)), b"10.0.0.1:53");
+    space(Some(("255.255.255.255", 15)), b"255.255.255.255 rest");
+    extra_octet(Some(("10.0.0.1", 8)), b"10.0.0.1.5");
+    not_utf8(Some(("10.0.0.1", 8)), b"10.0.0.1\xff");
+    four_digits(None, b"10.0.0.1000");
+    truncated(None, b"10.0.0");
+    empty(None, b"");
+    leading_space(None, b" 10.0.0.1");
+} }
+
+#[test]
+fn cmp_numeric() {
+    fn check<A: ipv4::Address + std::fmt::Debug>(parse: impl Fn(&str) -> A) {
+        let sorted = [
+            "0.0.0.0",
+            "0.0.0.1",
+            "0.0.0.255",
+            "0.0.1.0",
+            "9.255.255.255",
+            "10.0.0.0",
+            "127.255.255.255",
+            "128.0.0.0",
+            "255.255.255.254",
+            "255.255.255.255",
+        ]
+        .map(parse);
+        for (i, a) in sorted.iter().enumerate() {
+            for (j, b) in sorted.iter().enumerate() {
+                assert_eq!(i.cmp(&j), a.cmp_numeric(b), "{a:?} {b:?}");
+                assert_eq!(a.cmp(b), a.cmp_numeric(b), "{a:?} {b:?}");
+            }
+        }
+    }
+    check(|s| s.parse::<std::net::Ipv4Addr>().unwrap());
+    check(|s| s.parse::<ipv4::Addr>().unwrap());
+    check(util::a);
+}
+
+fn checked_add(expected: Option<&str>, address: &str, offset: u32) {
+    use ipv4::Address;
+    assert_eq!(expected.map(util::a), util::a(address).checked_add(offset));
+}
+
+runner::tests! { checked_add {
+    zero(Some("10.0.0.1"), "10.0.0.1", 0);
+    carry(Some("10.0.1.4"), "10.0.0.255", 5);
+    to_max(Some("255.255.255.255"), "255.255.255.254", 1);
+    overflow(None, "255.255.255.255", 1);
+    everything(Some("255.255.255.255"), "0.0.0.0", u32::MAX);
+} }
+
+fn checked_sub(expected: Option<&str>, address: &str, offset: u32) {
+    use ipv4::Address;
+    assert_eq!(expected.map(util::a), util::a(address).checked_sub(offset));
+}
+
+runner::tests! { checked_sub {
+    zero(Some("10.0.0.1"), "10.0.0.1", 0);
+    borrow(Some("10.0.0.255"), "10.0.1.0", 1);
+    to_min(Some("0.0.0.0"), "0.0.0.1", 1);
+    underflow(None, "0.0.0.0", 1);
+} }
+
+#[test]
+fn saturating_and_wrapping_add() {
+    use ipv4::Address;
+    let ip = util::a("255.255.255.250");
+    assert_eq!(util::a("255.255.255.252"), ip.saturating_add(2));
+    assert_eq!(util::a("255.255.255.255"), ip.saturating_add(10));
+    assert_eq!(util::a("255.255.255.252"), ip.wrapping_add(2));
+    assert_eq!(util::a("0.0.0.4"), ip.wrapping_add(10));
+}
+
+fn distance_to(expected: u32, a: &str, b: &str) {
+    use ipv4::Address;
+    let (a, b) = (util::a(a), util::a(b));
+    assert_eq!(expected, a.distance_to(&b));
+    assert_eq!(expected, b.distance_to(&a));
+}
+
+runner::tests! { distance_to {
+    same(0, "10.0.0.1", "10.0.0.1");
+    adjacent(1, "10.0.0.255", "10.0.1.0");
+    prefix(255, "10.0.0.0", "10.0.0.255");
+    everything(u32::MAX, "0.0.0.0", "255.255.255.255");
+} }
+
+#[test]
+fn bits() {
+    use ipv4::Address;
+    let ip = util::a("10.224.24.1");
+    let expected = format!("{:032b}", u32::from(ip));
+    let actual: String = (0..32)
+        .map(|n| match ip.bit(n) {
+            true => '1',
+            false => '0',
+        })
+        .collect();
+    assert_eq!(expected, actual);
+}
+
+#[test]
+#[should_panic]
+fn bit_out_of_range() {
+    use ipv4::Address;
+    util::a("10.224.24.1").bit(32);
+}
+
+fn with_bit(expected: &str, address: &str, n: u8, value: bool) {
+    use ipv4::Address;
+    let actual = util::a(address).with_bit(n, value);
+    assert_eq!(util::a(expected), actual);
+    assert_eq!(value, actual.bit(n));
+}
+
+runner::tests! { with_bit {
+    set_first("128.0.0.0", "0.0.0.0", 0, true);
+    set_last("0.0.0.1", "0.0.0.0", 31, true);
+    clear_first("127.255.255.255", "255.255.255.255", 0, false);
+    clear_last("255.255.255.254", "255.255.255.255", 31, false);
+    already_set("10.0.0.0", "10.0.0.0", 4, true);
+    already_clear("10.0.0.0", "10.0.0.0", 5, false);
+} }
+
+fn common_prefix_len(expected: u8, a: &str, b: &str) {
+    use ipv4::Address;
+    let (a, b) = (util::a(a), util::a(b));
+    assert_eq!(expected, a.common_prefix_len(&b));
+    assert_eq!(expected, b.common_prefix_len(&a));
+}
+
+runner::tests! { common_prefix_len {
+    same(32, "10.0.0.1", "10.0.0.1");
+    last_bit(31, "10.0.0.0", "10.0.0.1");
+    octet(23, "10.0.0.1", "10.0.1.1");
+    first_bit(0, "0.0.0.0", "128.0.0.0");
+    extremes(0, "0.0.0.0", "255.255.255.255");
+} }
+
+#[test]
+fn constants() {
+    fn check<A: ipv4::Address + std::fmt::Debug>() {
+        assert_eq!(A::from(0), A::UNSPECIFIED);
+        assert_eq!(A::from(u32::MAX), A::BROADCAST);
+        assert_eq!(A::from([127, 0, 0, 1]), A::LOCALHOST);
+        assert_eq!(A::UNSPECIFIED, A::MIN);
+        assert_eq!(A::BROADCAST, A::MAX);
+    }
+    check::<std::net::Ipv4Addr>();
+    check::<ipv4::Addr>();
+    check::<util::Address>();
+}
+
+fn classify(address: &str) {
+    use ipv4::Prefix;
+    let std: std::net::Ipv4Addr = address.parse().unwrap();
+    let ip = util::a(address);
+    assert_eq!(std.is_private(), ip.is_private());
+    assert_eq!(std.is_loopback(), ip.is_loopback());
+    assert_eq!(std.is_link_local(), ip.is_link_local());
+    assert_eq!(std.is_documentation(), ip.is_documentation());
+    assert_eq!(std.is_multicast(), ip.is_multicast());
+}
+
+runner::tests! { classify {
+    private("172.16.0.1");
+    not_private("172.32.0.1");
+    loopback("127.255.255.255");
+    link_local("169.254.1.1");
+    documentation("203.0.113.5");
+    multicast("239.1.2.3");
+    public("8.8.8.8");
+    unspecified("0.0.0.0");
+    broadcast("255.255.255.255");
+} }
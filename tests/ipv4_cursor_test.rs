@@ -0,0 +1,61 @@
+use addrs::ipv4::{Addr, AddressRange, Cursor};
+
+fn pool(first: &str, last: &str) -> AddressRange {
+    AddressRange::new(first.parse().unwrap(), last.parse().unwrap()).unwrap()
+}
+
+#[test]
+fn allocate() {
+    let mut cursor = Cursor::new(pool("10.0.0.254", "10.0.1.1"));
+    assert_eq!(4, cursor.remaining());
+    assert_eq!(None, cursor.last());
+    let mut previous = None;
+    for _ in 0..4 {
+        let address = cursor.allocate().unwrap();
+        assert!(previous < Some(address));
+        assert_eq!(Some(address), cursor.last());
+        previous = Some(address);
+    }
+    assert_eq!(Some(Addr::new(10, 0, 1, 1)), cursor.last());
+    assert_eq!(0, cursor.remaining());
+    assert!(matches!(cursor.allocate(), Err(addrs::Error::Exhausted)));
+    assert!(matches!(cursor.allocate(), Err(addrs::Error::Exhausted)));
+    assert_eq!(Some(Addr::new(10, 0, 1, 1)), cursor.last());
+}
+
+#[test]
+fn resume() {
+    let p = pool("10.0.0.1", "10.0.0.5");
+    let mut cursor = Cursor::new(p);
+    cursor.allocate().unwrap();
+    cursor.allocate().unwrap();
+
+    let mut resumed = Cursor::resume(p, cursor.last()).unwrap();
+    assert_eq!(3, resumed.remaining());
+    assert_eq!(cursor.allocate().unwrap(), resumed.allocate().unwrap());
+
+    let mut fresh = Cursor::resume(p, None).unwrap();
+    assert_eq!(Addr::new(10, 0, 0, 1), fresh.allocate().unwrap());
+
+    let mut done = Cursor::resume(p, Some(Addr::new(10, 0, 0, 5))).unwrap();
+    assert!(matches!(done.allocate(), Err(addrs::Error::Exhausted)));
+}
+
+#[test]
+fn resume_outside() {
+    let p = pool("10.0.0.1", "10.0.0.5");
+    assert!(matches!(
+        Cursor::resume(p, Some(Addr::new(10, 0, 0, 0))),
+        Err(addrs::Error::NotContained)
+    ));
+    assert!(Cursor::resume(p, Some(Addr::new(10, 0, 0, 6))).is_err());
+}
+
+#[test]
+fn whole_address_space() {
+    let cursor = Cursor::new(pool("0.0.0.0", "255.255.255.255"));
+    assert_eq!(1 << 32, cursor.remaining());
+    let mut cursor = Cursor::resume(cursor.pool(), Some(Addr::new(255, 255, 255, 254))).unwrap();
+    assert_eq!(Addr::BROADCAST, cursor.allocate().unwrap());
+    assert!(cursor.allocate().is_err());
+}
@@ -0,0 +1,73 @@
+use addrs::ipv4::{AddressRange, Prefix};
+
+mod util;
+
+fn prefix(range: &str, cidr: &str, wildcard: &str, prefix: &str) {
+    let rendering = util::p(prefix).display();
+    assert_eq!(range, rendering.as_range().to_string());
+    assert_eq!(cidr, rendering.as_cidr().to_string());
+    assert_eq!(wildcard, rendering.as_wildcard().to_string());
+}
+
+runner::tests! { prefix {
+    basic("10.0.0.0-10.0.0.255", "10.0.0.0/24", "10.0.0.0 0.0.0.255", "10.0.0.0/24");
+    host_bits("10.0.0.0-10.0.0.255", "10.0.0.7/24", "10.0.0.0 0.0.0.255", "10.0.0.7/24");
+    host("10.0.0.7-10.0.0.7", "10.0.0.7/32", "10.0.0.7 0.0.0.0", "10.0.0.7/32");
+    all("0.0.0.0-255.255.255.255", "0.0.0.0/0", "0.0.0.0 255.255.255.255", "0.0.0.0/0");
+} }
+
+fn range(cidr: &str, wildcard: &str, first: &str, last: &str) {
+    let range = AddressRange::new(util::a(first), util::a(last)).unwrap();
+    let rendering = range.display();
+    assert_eq!(range.to_string(), rendering.as_range().to_string());
+    assert_eq!(cidr, rendering.as_cidr().to_string());
+    assert_eq!(wildcard, rendering.as_wildcard().to_string());
+}
+
+runner::tests! { range {
+    prefix("10.0.0.0/24", "10.0.0.0 0.0.0.255", "10.0.0.0", "10.0.0.255");
+    single("10.0.0.5/32", "10.0.0.5 0.0.0.0", "10.0.0.5", "10.0.0.5");
+    two("10.0.0.0/25, 10.0.0.128/26", "10.0.0.0 0.0.0.127, 10.0.0.128 0.0.0.63", "10.0.0.0", "10.0.0.191");
+    unaligned(
+        "10.0.0.1/32, 10.0.0.2/31, 10.0.0.4/30, 10.0.0.8/31",
+        "10.0.0.1 0.0.0.0, 10.0.0.2 0.0.0.1, 10.0.0.4 0.0.0.3, 10.0.0.8 0.0.0.1",
+        "10.0.0.1",
+        "10.0.0.9"
+    );
+    all("0.0.0.0/0", "0.0.0.0 255.255.255.255", "0.0.0.0", "255.255.255.255");
+    top("255.255.255.254/31", "255.255.255.254 0.0.0.1", "255.255.255.254", "255.255.255.255");
+} }
+
+#[test]
+fn range_split_all() {
+    let range = AddressRange::new(util::a("0.0.0.1"), util::a("255.255.255.255")).unwrap();
+    let cidr = range.display().as_cidr().to_string();
+    let cidrs: Vec<&str> = cidr.split(", ").collect();
+    assert_eq!(32, cidrs.len());
+    assert_eq!("0.0.0.1/32", cidrs[0]);
+    assert_eq!("1.0.0.0/8", cidrs[24]);
+    assert_eq!("128.0.0.0/1", cidrs[31]);
+}
+
+#[test]
+fn padding() {
+    let prefix = util::p("10.0.0.0/24");
+    let rendering = prefix.display();
+    assert_eq!(
+        "  10.0.0.0-10.0.0.255",
+        format!("{:>21}", rendering.as_range())
+    );
+    assert_eq!(
+        "         10.0.0.0/24",
+        format!("{:>20}", rendering.as_cidr())
+    );
+    assert_eq!(
+        "10.0.0.0 0.0.0.255  |",
+        format!("{:<20}|", rendering.as_wildcard())
+    );
+    let range = AddressRange::new(util::a("10.0.0.0"), util::a("10.0.0.191")).unwrap();
+    assert_eq!(
+        "  10.0.0.0/25, 10.0.0.128/26",
+        format!("{:>28}", range.display().as_cidr())
+    );
+}
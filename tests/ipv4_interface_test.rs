@@ -0,0 +1,70 @@
+use addrs::ipv4::{Addr, Interface, Pfx, Prefix};
+
+fn i(s: &str) -> Interface {
+    s.parse().expect("bad interface")
+}
+
+fn a(s: &str) -> Addr {
+    s.parse().expect("bad ip")
+}
+
+#[test]
+fn keeps_host_bits() {
+    let interface = i("10.224.24.77/24");
+    assert_eq!(a("10.224.24.77"), interface.address());
+    assert_eq!(24, interface.length());
+    assert_eq!("10.224.24.77/24", interface.to_string());
+    assert_eq!("10.224.24.77/24", format!("{interface:?}"));
+}
+
+#[test]
+fn new() {
+    assert_eq!(
+        i("10.224.24.77/24"),
+        Interface::new(a("10.224.24.77"), 24).unwrap()
+    );
+    assert!(Interface::new(a("10.224.24.77"), 33).is_err());
+}
+
+fn subnet(expected: &str, interface: &str) {
+    assert_eq!(expected, i(interface).subnet().to_string());
+}
+
+runner::tests! { subnet {
+    zero("0.0.0.0/0", "10.224.24.77/0");
+    size_24("10.224.24.0/24", "10.224.24.77/24");
+    size_31("10.224.24.76/31", "10.224.24.77/31");
+    size_32("10.224.24.77/32", "10.224.24.77/32");
+} }
+
+fn peer(expected: Option<&str>, interface: &str) {
+    assert_eq!(expected.map(a), i(interface).peer());
+}
+
+runner::tests! { peer {
+    lower(Some("10.224.24.77"), "10.224.24.76/31");
+    upper(Some("10.224.24.76"), "10.224.24.77/31");
+    size_30(None, "10.224.24.77/30");
+    size_32(None, "10.224.24.77/32");
+} }
+
+fn hosts(first: &str, last: &str, interface: &str) {
+    let interface = i(interface);
+    assert_eq!(a(first), interface.first_host());
+    assert_eq!(a(last), interface.last_host());
+}
+
+runner::tests! { hosts {
+    zero("0.0.0.1", "255.255.255.254", "10.224.24.77/0");
+    size_24("10.224.24.1", "10.224.24.254", "10.224.24.77/24");
+    size_30("10.224.24.77", "10.224.24.78", "10.224.24.77/30");
+    size_31("10.224.24.76", "10.224.24.77", "10.224.24.77/31");
+    size_32("10.224.24.77", "10.224.24.77", "10.224.24.77/32");
+} }
+
+#[test]
+fn conversions() {
+    let prefix: Pfx = "10.224.24.77/24".parse().unwrap();
+    let interface = Interface::from(prefix);
+    assert_eq!(prefix, interface.into());
+}
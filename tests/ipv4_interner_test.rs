@@ -0,0 +1,48 @@
+use addrs::ipv4::{Addr, Interner, Pfx};
+
+#[test]
+fn intern() {
+    let mut interner = Interner::new();
+    let prefixes: Vec<Pfx> = ["10.0.0.0/8", "10.0.0.0/16", "10.0.0.0/8", "192.168.0.0/24"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let ids: Vec<_> = prefixes
+        .iter()
+        .map(|p| interner.intern(*p).unwrap())
+        .collect();
+    assert_eq!(ids[0], ids[2]);
+    assert_ne!(ids[0], ids[1]);
+    assert_eq!(
+        vec![0, 1, 0, 2],
+        ids.iter().map(|id| id.index()).collect::<Vec<_>>()
+    );
+    assert_eq!(3, interner.len());
+    for (prefix, id) in prefixes.iter().zip(&ids) {
+        assert_eq!(Some(*prefix), interner.resolve(*id));
+        assert_eq!(Some(*id), interner.id(prefix));
+    }
+}
+
+#[test]
+fn resolve_foreign() {
+    let mut big = Interner::new();
+    big.intern(Addr::new(10, 0, 0, 1)).unwrap();
+    let id = big.intern(Addr::new(10, 0, 0, 2)).unwrap();
+
+    let small: Interner<Addr> = Interner::new();
+    assert!(small.is_empty());
+    assert_eq!(None, small.resolve(id));
+    assert_eq!(None, small.id(&Addr::new(10, 0, 0, 2)));
+}
+
+#[test]
+fn iter() {
+    let mut interner = Interner::with_capacity(2);
+    let b = interner.intern(Addr::new(10, 0, 0, 2)).unwrap();
+    let a = interner.intern(Addr::new(10, 0, 0, 1)).unwrap();
+    assert_eq!(
+        vec![(b, Addr::new(10, 0, 0, 2)), (a, Addr::new(10, 0, 0, 1))],
+        interner.iter().collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,549 @@
+use addrs::ipv4::{self, IpSet, Prefix, Set};
+
+mod util;
+
+fn set(prefixes: &[&str]) -> IpSet<util::Prefix> {
+    let mut set = IpSet::new();
+    for prefix in prefixes {
+        set.insert(&util::p(prefix));
+    }
+    set
+}
+
+fn prefixes(set: &IpSet<util::Prefix>) -> Vec<String> {
+    set.iter().map(|p| p.to_string()).collect()
+}
+
+fn insert(expected: Vec<&str>, inserted: Vec<&str>) {
+    assert_eq!(expected, prefixes(&set(&inserted)));
+    // the order of insertion doesn't matter
+    let mut reversed = inserted.clone();
+    reversed.reverse();
+    assert_eq!(expected, prefixes(&set(&reversed)));
+}
+
+runner::tests! { insert {
+    empty(vec![], vec![]);
+    one(vec!["10.0.0.0/24"], vec!["10.0.0.0/24"]);
+    host_bits(vec!["10.0.0.0/24"], vec!["10.0.0.1/24"]);
+    duplicate(vec!["10.0.0.0/24"], vec!["10.0.0.0/24", "10.0.0.0/24"]);
+    siblings(vec!["10.0.0.0/24"], vec!["10.0.0.0/25", "10.0.0.128/25"]);
+    cascade(vec!["10.0.0.0/22"], vec!["10.0.0.0/24", "10.0.3.0/24", "10.0.1.0/24", "10.0.2.0/24"]);
+    contained(vec!["10.0.0.0/16"], vec!["10.0.0.0/16", "10.0.20.0/24"]);
+    disjoint(vec!["10.0.0.0/24", "10.0.2.0/24"], vec!["10.0.2.0/24", "10.0.0.0/24"]);
+    adjacent_not_siblings(vec!["10.0.1.0/24", "10.0.2.0/24"], vec!["10.0.1.0/24", "10.0.2.0/24"]);
+    far_apart(vec!["1.0.0.0/8", "200.0.0.0/8"], vec!["200.0.0.0/8", "1.0.0.0/8"]);
+    addresses(vec!["10.0.0.0/31", "10.0.0.2/32"], vec!["10.0.0.2/32", "10.0.0.0/32", "10.0.0.1/32"]);
+    everything(vec!["0.0.0.0/0"], vec!["0.0.0.0/1", "10.0.0.0/8", "128.0.0.0/1"]);
+} }
+
+fn remove(expected: Vec<&str>, inserted: Vec<&str>, removed: Vec<&str>) {
+    let mut set = set(&inserted);
+    for prefix in removed {
+        set.remove(&util::p(prefix));
+    }
+    assert_eq!(expected, prefixes(&set));
+}
+
+runner::tests! { remove {
+    all(vec![], vec!["10.0.0.0/24"], vec!["10.0.0.0/24"]);
+    supernet(vec![], vec!["10.0.0.0/24", "10.0.2.0/24"], vec!["10.0.0.0/16"]);
+    missing(vec!["10.0.0.0/24"], vec!["10.0.0.0/24"], vec!["10.0.1.0/24"]);
+    half(vec!["10.0.0.128/25"], vec!["10.0.0.0/24"], vec!["10.0.0.0/25"]);
+    hole(
+        vec!["10.0.0.0/26", "10.0.0.64/27", "10.0.0.96/28", "10.0.0.112/29", "10.0.0.120/30",
+             "10.0.0.124/31", "10.0.0.127/32", "10.0.0.128/25"],
+        vec!["10.0.0.0/24"],
+        vec!["10.0.0.126/32"]
+    );
+    part_of_split(vec!["10.0.0.0/24", "10.0.3.0/24"], vec!["10.0.0.0/24", "10.0.2.0/23"], vec!["10.0.2.0/24"]);
+    everything(vec!["0.0.0.0/1"], vec!["0.0.0.0/0"], vec!["128.0.0.0/1"]);
+} }
+
+#[test]
+fn remove_then_insert() {
+    let mut set = set(&["10.0.0.0/24"]);
+    set.remove(&util::p("10.0.0.7/32"));
+    assert_eq!(8, prefixes(&set).len());
+    set.insert(&util::a("10.0.0.7"));
+    assert_eq!(vec!["10.0.0.0/24"], prefixes(&set));
+}
+
+#[test]
+fn ranges() {
+    let mut set: IpSet<util::Prefix> = IpSet::new();
+    set.insert_range(&(util::a("10.0.0.5")..=util::a("10.0.0.12")));
+    assert_eq!(
+        vec!["10.0.0.5/32", "10.0.0.6/31", "10.0.0.8/30", "10.0.0.12/32"],
+        prefixes(&set)
+    );
+    set.insert_range(&(util::a("10.0.0.0")..=util::a("10.0.0.4")));
+    set.insert_range(&(util::a("10.0.0.13")..=util::a("10.0.0.15")));
+    assert_eq!(vec!["10.0.0.0/28"], prefixes(&set));
+    set.remove_range(&(util::a("10.0.0.1")..=util::a("10.0.0.14")));
+    assert_eq!(vec!["10.0.0.0/32", "10.0.0.15/32"], prefixes(&set));
+    // empty ranges do nothing
+    set.insert_range(&(util::a("10.0.0.9")..=util::a("10.0.0.8")));
+    set.remove_range(&(util::a("10.0.0.15")..=util::a("10.0.0.0")));
+    assert_eq!(vec!["10.0.0.0/32", "10.0.0.15/32"], prefixes(&set));
+}
+
+#[test]
+fn from_ranges() {
+    let range = util::a("10.0.0.5")..=util::a("10.0.0.27");
+    let set = IpSet::<util::Prefix>::from(range.clone());
+    assert_eq!(
+        vec![
+            "10.0.0.5/32",
+            "10.0.0.6/31",
+            "10.0.0.8/29",
+            "10.0.0.16/29",
+            "10.0.0.24/30"
+        ],
+        prefixes(&set)
+    );
+    let address_range = ipv4::AddressRange::try_from(range).unwrap();
+    assert_eq!(set, IpSet::from(address_range));
+
+    let mut set: IpSet<util::Prefix> = [
+        util::a("10.0.0.0")..=util::a("10.0.0.4"),
+        util::a("10.0.0.9")..=util::a("10.0.0.8"),
+        util::a("10.0.0.5")..=util::a("10.0.0.7"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(vec!["10.0.0.0/29"], prefixes(&set));
+    set.extend([ipv4::AddressRange::try_from(util::a("10.0.0.8")..=util::a("10.0.0.15")).unwrap()]);
+    assert_eq!(vec!["10.0.0.0/28"], prefixes(&set));
+}
+
+fn contains(expected: bool, prefix: &str) {
+    let set = set(&["10.0.0.0/24", "10.0.2.0/23", "10.0.5.0/32"]);
+    assert_eq!(expected, set.contains(&util::p(prefix)));
+}
+
+runner::tests! { contains {
+    whole(true, "10.0.0.0/24");
+    part(true, "10.0.0.128/25");
+    address(true, "10.0.3.255/32");
+    single(true, "10.0.5.0/32");
+    next_to_single(false, "10.0.5.1/32");
+    gap(false, "10.0.1.0/24");
+    spans_gap(false, "10.0.0.0/22");
+    split_node(false, "10.0.0.0/21");
+    outside(false, "11.0.0.0/24");
+    everything(false, "0.0.0.0/0");
+} }
+
+fn overlaps(expected: bool, prefix: &str) {
+    let set = set(&["10.0.0.0/24", "10.0.2.0/23", "10.0.5.0/32"]);
+    assert_eq!(expected, set.overlaps(&util::p(prefix)));
+}
+
+runner::tests! { overlaps {
+    whole(true, "10.0.0.0/24");
+    part(true, "10.0.0.128/25");
+    spans_gap(true, "10.0.0.0/22");
+    everything(true, "0.0.0.0/0");
+    single(true, "10.0.4.0/23");
+    next_to_single(false, "10.0.5.1/32");
+    gap(false, "10.0.1.0/24");
+    outside(false, "11.0.0.0/24");
+} }
+
+#[test]
+fn set_trait() {
+    let set = set(&["10.0.0.0/24", "10.0.2.0/23", "10.0.5.0/32"]);
+    assert!(!set.is_empty());
+    assert_eq!(256 + 512 + 1, set.num_addresses().unwrap());
+    assert_eq!(3, set.num_prefixes(24).unwrap());
+    assert_eq!(1, set.num_prefixes(23).unwrap());
+    assert!(set.num_prefixes(33).is_err());
+    assert_eq!(
+        vec![util::p("10.0.0.0/22"), util::p("10.0.4.0/22")],
+        set.coarsen::<util::Prefix>(22).unwrap()
+    );
+
+    let empty: IpSet<util::Prefix> = IpSet::new();
+    assert!(empty.is_empty());
+    assert_eq!(0, empty.num_addresses().unwrap());
+    assert!(empty.num_prefixes(33).is_err());
+
+    let all = self::set(&["0.0.0.0/0"]);
+    assert!(!all.is_empty());
+    assert!(all.num_addresses().is_err());
+    assert_eq!(1 << 32, all.num_addresses_u64());
+    assert_eq!(1 << 8, all.num_prefixes_u64(8).unwrap());
+    assert!(all.num_prefixes_u64(33).is_err());
+}
+
+#[test]
+fn equality() {
+    assert_eq!(
+        set(&["10.0.0.0/25", "10.0.0.128/25"]),
+        set(&["10.0.0.0/24"])
+    );
+    assert_ne!(set(&["10.0.0.0/25"]), set(&["10.0.0.0/24"]));
+    assert_eq!(IpSet::<util::Prefix>::default(), set(&[]));
+}
+
+#[test]
+fn format() {
+    let set: IpSet = ["10.0.2.0/24", "10.0.0.0/24"]
+        .iter()
+        .fold(IpSet::new(), |mut set, p| {
+            set.insert(&p.parse::<ipv4::Pfx>().unwrap());
+            set
+        });
+    assert_eq!("{10.0.0.0/24, 10.0.2.0/24}", format!("{set:?}"));
+    assert_eq!("{}", format!("{:?}", IpSet::<ipv4::Pfx>::new()));
+}
+
+#[test]
+fn into_iter() {
+    let set = set(&["10.0.2.0/24", "10.0.0.0/24"]);
+    let mut count = 0;
+    for prefix in &set {
+        assert_eq!(24, prefix.length());
+        count += 1;
+    }
+    assert_eq!(2, count);
+}
+
+fn rng(mut seed: u64) -> impl FnMut() -> u64 {
+    move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    }
+}
+
+// compares the set against a bitmap of the addresses in 10.0.0.0/24 after random changes
+#[test]
+fn random() {
+    let mut rng = rng(0x5eed);
+    let mut set: IpSet<util::Prefix> = IpSet::new();
+    let mut bitmap = [false; 256];
+    for _ in 0..2000 {
+        let r = rng();
+        let length = 24 + (r % 9) as u8;
+        let offset = (r >> 8) as u32 & 0xff;
+        let prefix = util::p(&format!("10.0.0.{offset}/{length}"));
+        let insert = r >> 40 & 1 == 0;
+        match insert {
+            true => set.insert(&prefix),
+            false => set.remove(&prefix),
+        }
+        for address in prefix.addresses() {
+            bitmap[(u32::from(address) & 0xff) as usize] = insert;
+        }
+
+        let mut expected = IpSet::<util::Prefix>::new();
+        for (i, _) in bitmap.iter().enumerate().filter(|(_, b)| **b) {
+            expected.insert(&util::a(&format!("10.0.0.{i}")));
+        }
+        assert_eq!(expected, set);
+
+        let prefixes: Vec<util::Prefix> = set.iter().collect();
+        for (i, in_set) in bitmap.iter().enumerate() {
+            let address = util::a(&format!("10.0.0.{i}"));
+            assert_eq!(*in_set, set.contains(&address));
+        }
+        for pair in prefixes.windows(2) {
+            // in order and never siblings that should have been merged
+            assert!(pair[0].broadcast() < pair[1].network());
+            assert!(pair[0].merge(&pair[1]).is_none());
+        }
+    }
+}
+
+fn algebra(
+    union: Vec<&str>,
+    intersection: Vec<&str>,
+    difference: Vec<&str>,
+    a: Vec<&str>,
+    b: Vec<&str>,
+) {
+    let (a, b) = (set(&a), set(&b));
+    assert_eq!(union, prefixes(&a.union(&b)));
+    assert_eq!(union, prefixes(&(&b | &a)));
+    assert_eq!(intersection, prefixes(&a.intersection(&b)));
+    assert_eq!(intersection, prefixes(&(&b & &a)));
+    assert_eq!(difference, prefixes(&a.difference(&b)));
+    assert_eq!(difference, prefixes(&(&a - &b)));
+}
+
+runner::tests! { algebra {
+    empty(vec![], vec![], vec![], vec![], vec![]);
+    empty_right(vec!["10.0.0.0/24"], vec![], vec!["10.0.0.0/24"], vec!["10.0.0.0/24"], vec![]);
+    empty_left(vec!["10.0.0.0/24"], vec![], vec![], vec![], vec!["10.0.0.0/24"]);
+    same(vec!["10.0.0.0/24"], vec!["10.0.0.0/24"], vec![], vec!["10.0.0.0/24"], vec!["10.0.0.0/24"]);
+    siblings(vec!["10.0.0.0/24"], vec![], vec!["10.0.0.0/25"], vec!["10.0.0.0/25"], vec!["10.0.0.128/25"]);
+    contains(
+        vec!["10.0.0.0/16"],
+        vec!["10.0.1.0/24", "10.0.4.0/23"],
+        vec!["10.0.0.0/24", "10.0.2.0/23", "10.0.6.0/23", "10.0.8.0/21", "10.0.16.0/20", "10.0.32.0/19", "10.0.64.0/18", "10.0.128.0/17"],
+        vec!["10.0.0.0/16"],
+        vec!["10.0.1.0/24", "10.0.4.0/23"]
+    );
+    interleaved(
+        vec!["10.0.0.0/22"],
+        vec![],
+        vec!["10.0.0.0/24", "10.0.2.0/24"],
+        vec!["10.0.0.0/24", "10.0.2.0/24"],
+        vec!["10.0.1.0/24", "10.0.3.0/24"]
+    );
+    partial(
+        vec!["10.0.0.0/22"],
+        vec!["10.0.1.0/24"],
+        vec!["10.0.0.0/24"],
+        vec!["10.0.0.0/23"],
+        vec!["10.0.1.0/24", "10.0.2.0/23"]
+    );
+} }
+
+// compares set algebra against bitmaps of the addresses in 10.0.0.0/24
+#[test]
+fn random_algebra() {
+    fn random_set(rng: &mut impl FnMut() -> u64) -> (IpSet<util::Prefix>, [bool; 256]) {
+        let mut set = IpSet::new();
+        let mut bitmap = [false; 256];
+        for _ in 0..(rng() % 20) {
+            let r = rng();
+            let length = 24 + (r % 9) as u8;
+            let prefix = util::p(&format!("10.0.0.{}/{length}", (r >> 8) & 0xff));
+            let insert = r >> 40 & 3 != 0;
+            match insert {
+                true => set.insert(&prefix),
+                false => set.remove(&prefix),
+            }
+            for address in prefix.addresses() {
+                bitmap[(u32::from(address) & 0xff) as usize] = insert;
+            }
+        }
+        (set, bitmap)
+    }
+
+    fn from_bitmap(bitmap: impl Iterator<Item = bool>) -> IpSet<util::Prefix> {
+        let mut set = IpSet::new();
+        for (i, _) in bitmap.enumerate().filter(|(_, b)| *b) {
+            set.insert(&util::a(&format!("10.0.0.{i}")));
+        }
+        set
+    }
+
+    let mut rng = rng(0xa16eb7a);
+    for _ in 0..500 {
+        let (a, a_bits) = random_set(&mut rng);
+        let (b, b_bits) = random_set(&mut rng);
+        let bits = || a_bits.iter().zip(b_bits.iter());
+        assert_eq!(from_bitmap(bits().map(|(a, b)| *a || *b)), a.union(&b));
+        assert_eq!(
+            from_bitmap(bits().map(|(a, b)| *a && *b)),
+            a.intersection(&b)
+        );
+        assert_eq!(
+            from_bitmap(bits().map(|(a, b)| *a && !*b)),
+            a.difference(&b)
+        );
+    }
+}
+
+fn relations(subset: bool, superset: bool, disjoint: bool, a: Vec<&str>, b: Vec<&str>) {
+    let (a, b) = (set(&a), set(&b));
+    assert_eq!(subset, a.is_subset(&b));
+    assert_eq!(superset, a.is_superset(&b));
+    assert_eq!(superset, b.is_subset(&a));
+    assert_eq!(disjoint, a.is_disjoint(&b));
+    assert_eq!(disjoint, b.is_disjoint(&a));
+}
+
+runner::tests! { relations {
+    empty(true, true, true, vec![], vec![]);
+    empty_left(true, false, true, vec![], vec!["10.0.0.0/24"]);
+    empty_right(false, true, true, vec!["10.0.0.0/24"], vec![]);
+    same(true, true, false, vec!["10.0.0.0/24"], vec!["10.0.0.0/24"]);
+    half(true, false, false, vec!["10.0.0.128/25"], vec!["10.0.0.0/24"]);
+    siblings(false, false, true, vec!["10.0.0.0/25"], vec!["10.0.0.128/25"]);
+    partial(false, false, false, vec!["10.0.0.0/23"], vec!["10.0.1.0/24", "10.0.2.0/24"]);
+    split_in_full(true, false, false, vec!["10.0.0.0/24", "10.0.2.0/24"], vec!["10.0.0.0/22"]);
+    split_in_split(true, false, false, vec!["10.0.0.0/25", "10.0.2.128/25"], vec!["10.0.0.0/24", "10.0.2.0/24"]);
+    interleaved(false, false, true, vec!["10.0.0.0/24", "10.0.2.0/24"], vec!["10.0.1.0/24", "10.0.3.0/24"]);
+    far_apart(false, false, true, vec!["1.0.0.0/8"], vec!["200.0.0.0/8"]);
+} }
+
+// compares the relations against bitmaps of the addresses in 10.0.0.0/24
+#[test]
+fn random_relations() {
+    let mut rng = rng(0xd15701);
+    for _ in 0..2000 {
+        let mut bitmaps = [[false; 16]; 2];
+        let mut sets = [IpSet::<util::Prefix>::new(), IpSet::new()];
+        for (set, bitmap) in sets.iter_mut().zip(bitmaps.iter_mut()) {
+            let r = rng();
+            for (i, b) in bitmap.iter_mut().enumerate() {
+                *b = r >> i & 1 == 1 && r >> (i + 16) & 1 == 1;
+                if *b {
+                    set.insert(&util::a(&format!("10.0.0.{i}")));
+                }
+            }
+        }
+        let [a, b] = &sets;
+        let pairs = || bitmaps[0].iter().zip(bitmaps[1].iter());
+        assert_eq!(pairs().all(|(a, b)| !a || *b), a.is_subset(b));
+        assert_eq!(pairs().all(|(a, b)| !a || !b), a.is_disjoint(b));
+        assert_eq!(a.is_subset(b), b.contains_set(a));
+    }
+}
+
+fn contains_set(expected: bool, set: &[&str], from: &str, to: &str) {
+    let set = self::set(set);
+    let range = util::a(from)..=util::a(to);
+    assert_eq!(expected, set.contains_set(&range));
+    let other = IpSet::<util::Prefix>::from(range);
+    assert_eq!(expected, set.contains_set(&other));
+    assert_eq!(expected, other.is_subset(&set));
+}
+
+runner::tests! { contains_set {
+    empty_range(true, &[], "10.0.0.1", "10.0.0.0");
+    empty_set(false, &[], "10.0.0.0", "10.0.0.0");
+    exact(true, &["10.0.0.0/24"], "10.0.0.0", "10.0.0.255");
+    inside(true, &["10.0.0.0/24"], "10.0.0.3", "10.0.0.200");
+    spans_two(true, &["10.0.0.0/24", "10.0.1.0/25"], "10.0.0.200", "10.0.1.100");
+    past_end(false, &["10.0.0.0/24", "10.0.1.0/25"], "10.0.0.200", "10.0.1.200");
+    hole(false, &["10.0.0.0/25", "10.0.0.129/32", "10.0.0.130/31"], "10.0.0.0", "10.0.0.131");
+    all(true, &["0.0.0.0/0"], "0.0.0.0", "255.255.255.255");
+    almost_all(false, &["0.0.0.0/1", "128.0.0.0/2"], "0.0.0.0", "255.255.255.255");
+} }
+
+fn set_eq(expected: bool, a: Vec<&str>, b: Vec<&str>) {
+    let mut set: IpSet<ipv4::Pfx> = IpSet::new();
+    for prefix in a {
+        set.insert(&prefix.parse::<ipv4::Pfx>().unwrap());
+    }
+    let a = set;
+    let mut other: IpSet<ipv4::Subnet> = IpSet::new();
+    for prefix in b {
+        other.insert(&prefix.parse::<ipv4::Subnet>().unwrap());
+    }
+    assert_eq!(expected, a.set_eq(&other));
+    assert_eq!(expected, other.set_eq(&a));
+}
+
+runner::tests! { set_eq {
+    empty(true, vec![], vec![]);
+    same(true, vec!["10.0.0.0/24"], vec!["10.0.0.0/24"]);
+    halves(true, vec!["10.0.0.0/25", "10.0.0.128/25"], vec!["10.0.0.0/24"]);
+    overlapping(true, vec!["10.0.0.0/24", "10.0.0.0/25", "10.0.1.0/24"], vec!["10.0.0.0/23"]);
+    addresses(true, vec!["10.0.0.0/32", "10.0.0.1/32", "10.0.0.2/32", "10.0.0.3/32"], vec!["10.0.0.0/30"]);
+    missing_half(false, vec!["10.0.0.0/25"], vec!["10.0.0.0/24"]);
+    extra(false, vec!["10.0.0.0/24", "10.0.5.0/32"], vec!["10.0.0.0/24"]);
+    empty_other(false, vec!["10.0.0.0/24"], vec![]);
+} }
+
+#[test]
+fn collect() {
+    let set: IpSet<util::Prefix> = ["10.0.1.0/24", "10.0.0.0/24", "10.0.0.0/25", "10.0.1.0/24"]
+        .into_iter()
+        .map(util::p)
+        .collect();
+    assert_eq!(vec!["10.0.0.0/23"], prefixes(&set));
+
+    let set: IpSet<util::Prefix> = ["10.0.0.1", "10.0.0.0", "10.0.0.3"]
+        .into_iter()
+        .map(util::a)
+        .collect();
+    assert_eq!(vec!["10.0.0.0/31", "10.0.0.3/32"], prefixes(&set));
+
+    let set: IpSet<util::Prefix> = std::iter::empty::<util::Prefix>().collect();
+    assert!(set.is_empty());
+}
+
+#[test]
+fn extend() {
+    let mut set = set(&["10.0.0.0/24"]);
+    set.extend(["10.0.1.0/25", "10.0.1.128/25"].into_iter().map(util::p));
+    assert_eq!(vec!["10.0.0.0/23"], prefixes(&set));
+    set.extend([util::a("10.0.2.0")]);
+    assert_eq!(vec!["10.0.0.0/23", "10.0.2.0/32"], prefixes(&set));
+}
+
+#[test]
+fn set_prefixes() {
+    let set = set(&["10.0.0.0/24", "10.0.2.0/23", "10.0.5.0/32"]);
+    let prefixes: Vec<util::Prefix> = set.prefixes(24).unwrap().collect();
+    assert_eq!(
+        vec![
+            util::p("10.0.0.0/24"),
+            util::p("10.0.2.0/24"),
+            util::p("10.0.3.0/24")
+        ],
+        prefixes
+    );
+    assert_eq!(
+        set.num_prefixes(25).unwrap() as usize,
+        set.prefixes::<util::Prefix>(25).unwrap().count()
+    );
+    assert_eq!(0, set.prefixes::<util::Prefix>(22).unwrap().count());
+    assert!(set.prefixes::<util::Prefix>(33).is_err());
+}
+
+#[test]
+fn addresses() {
+    let set = set(&["10.0.0.0/31", "10.0.0.4/32", "10.0.1.0/24"]);
+    let addresses: Vec<util::Address> = set.iter_addresses().collect();
+    assert_eq!(259, addresses.len());
+    assert_eq!(
+        vec![
+            util::a("10.0.0.0"),
+            util::a("10.0.0.1"),
+            util::a("10.0.0.4")
+        ],
+        addresses[..3]
+    );
+    assert_eq!(Some(&util::a("10.0.1.255")), addresses.last());
+    assert_eq!(None, IpSet::<util::Prefix>::new().iter_addresses().next());
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_address() {
+    use rand::SeedableRng;
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0x5eed);
+    assert_eq!(None, IpSet::<util::Prefix>::new().random_address(&mut rng));
+
+    // every address should be picked about as often, even though they are in blocks of
+    // different sizes
+    let set = set(&["10.0.0.0/31", "10.0.1.4/30"]);
+    let addresses: Vec<util::Address> = set.iter_addresses().collect();
+    let mut counts = vec![0; addresses.len()];
+    for _ in 0..6000 {
+        let address = set.random_address(&mut rng).unwrap();
+        let i = addresses.iter().position(|a| *a == address).unwrap();
+        counts[i] += 1;
+    }
+    for count in counts {
+        assert!((800..1200).contains(&count), "{count}");
+    }
+}
+
+#[test]
+fn address_type() {
+    let mut set: IpSet<util::Address> = IpSet::new();
+    set.insert(&util::p("10.0.0.0/24"));
+    set.insert(&util::p("10.0.1.0/31"));
+    assert_eq!(258, set.num_addresses().unwrap());
+    assert_eq!(129, set.num_prefixes(31).unwrap());
+    assert_eq!(258, set.iter().count());
+    assert_eq!(258, set.iter_addresses().count());
+    assert_eq!(Some(util::a("10.0.0.0")), set.iter().next());
+    assert_eq!(Some(util::a("10.0.1.1")), set.iter().last());
+    let coarse: Vec<util::Prefix> = set.coarsen(24).unwrap();
+    assert_eq!(vec![util::p("10.0.0.0/24"), util::p("10.0.1.0/24")], coarse);
+    let prefixes: Vec<util::Prefix> = set.prefixes(24).unwrap().collect();
+    assert_eq!(vec![util::p("10.0.0.0/24")], prefixes);
+    assert!(format!("{set:?}").starts_with("{10.0.0.0, 10.0.0.1, 10.0.0.2, "));
+}
@@ -0,0 +1,72 @@
+use addrs::{
+    ipv4::{Addr, Netmask, Prefix},
+    Result,
+};
+
+mod util;
+
+fn from_length(expected: Result<&str>, length: u8) {
+    let expected = expected.map(|s| s.parse::<Netmask>().unwrap());
+    util::assert_result(expected, Netmask::from_length(length));
+}
+
+runner::tests! { from_length {
+    zero(Ok("0.0.0.0"), 0);
+    one(Ok("128.0.0.0"), 1);
+    twentytwo(Ok("255.255.252.0"), 22);
+    thirtytwo(Ok("255.255.255.255"), 32);
+    invalid_length(Err(addrs::Error::InvalidLength), 33);
+} }
+
+fn new(expected: Result<u8>, mask: &str) {
+    let mask: Addr = mask.parse().unwrap();
+    util::assert_result(expected, Netmask::new(mask).map(|m| m.length()));
+}
+
+runner::tests! { new {
+    zero(Ok(0), "0.0.0.0");
+    twentytwo(Ok(22), "255.255.252.0");
+    thirtytwo(Ok(32), "255.255.255.255");
+    hole(Err(addrs::Error::InvalidMask), "255.255.0.255");
+    inverted(Err(addrs::Error::InvalidMask), "0.0.0.255");
+    not_contiguous(Err(addrs::Error::InvalidMask), "255.253.0.0");
+} }
+
+#[test]
+fn from_str() {
+    util::assert_result(
+        Ok(24),
+        "255.255.255.0".parse::<Netmask>().map(|m| m.length()),
+    );
+    util::assert_result(
+        Err(addrs::Error::InvalidMask),
+        "255.0.255.0".parse::<Netmask>().map(|m| m.length()),
+    );
+    util::assert_result(
+        Err(addrs::Error::ParseError(None)),
+        "bogus".parse::<Netmask>().map(|m| m.length()),
+    );
+}
+
+#[test]
+fn conversions() {
+    let mask = Netmask::from_length(20).unwrap();
+    assert_eq!(0xfffff000u32, mask.into());
+    assert_eq!(Addr::new(255, 255, 240, 0), mask.into());
+    assert_eq!(util::a("255.255.240.0"), mask.address());
+    assert_eq!(mask, Netmask::try_from(0xfffff000u32).unwrap());
+    assert_eq!(
+        mask,
+        Netmask::try_from(Addr::new(255, 255, 240, 0)).unwrap()
+    );
+    assert!(Netmask::try_from(0xfffff001u32).is_err());
+    assert_eq!("255.255.240.0", mask.to_string());
+    assert_eq!("255.255.240.0", format!("{mask:?}"));
+}
+
+#[test]
+fn from_address_netmask() {
+    let mask = Netmask::from_length(24).unwrap();
+    let prefix = util::Prefix::from_address_netmask(util::a("192.168.1.1"), mask);
+    assert_eq!(util::p("192.168.1.1/24"), prefix);
+}
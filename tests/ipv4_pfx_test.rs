@@ -0,0 +1,157 @@
+use addrs::{
+    ipv4::{Addr, Pfx, Prefix, Set},
+    ParseErrorKind, Result,
+};
+
+mod util;
+
+#[test]
+fn size() {
+    assert!(std::mem::size_of::<Pfx>() <= 8);
+}
+
+fn new(expected: Result<Pfx>, address: Addr, length: u8) {
+    util::assert_result(expected, Pfx::new(address, length));
+}
+
+runner::tests! { new {
+    zero(Ok("0.0.0.0/0".parse().unwrap()), Addr::UNSPECIFIED, 0);
+    basic(Ok("10.224.24.1/24".parse().unwrap()), Addr::new(10, 224, 24, 1), 24);
+    host_route(Ok("10.224.24.1/32".parse().unwrap()), Addr::new(10, 224, 24, 1), 32);
+    invalid_length(Err(addrs::Error::InvalidLength), Addr::new(10, 224, 24, 1), 33);
+} }
+
+fn from_str(expected: Result<(Addr, u8)>, s: &str) {
+    let expected = expected.map(|(address, length)| Pfx::new(address, length).unwrap());
+    util::assert_result(expected, s.parse());
+}
+
+runner::tests! { from_str {
+    basic(Ok((Addr::new(10, 224, 24, 1), 27)), "10.224.24.1/27");
+    zero(Ok((Addr::UNSPECIFIED, 0)), "0.0.0.0/0");
+    host_route(Ok((Addr::BROADCAST, 32)), "255.255.255.255/32");
+    invalid_length(Err(addrs::Error::InvalidLength), "10.224.24.1/33");
+    mask(Ok((Addr::new(10, 224, 24, 1), 24)), "10.224.24.1/255.255.255.0");
+    zero_mask(Ok((Addr::new(10, 224, 24, 1), 0)), "10.224.24.1/0.0.0.0");
+    full_mask(Ok((Addr::new(10, 224, 24, 1), 32)), "10.224.24.1/255.255.255.255");
+    invalid_mask(Err(addrs::Error::InvalidMask), "10.224.24.1/255.255.0.255");
+    bad_mask(Err(addrs::Error::ParseError(None)), "10.224.24.1/255.255.0");
+    no_length(Err(addrs::Error::ParseError(None)), "10.224.24.1");
+    empty_length(Err(addrs::Error::ParseError(None)), "10.224.24.1/");
+    plus_length(Err(addrs::Error::ParseError(None)), "10.224.24.1/+24");
+    bad_address(Err(addrs::Error::ParseError(None)), "10.224.24/24");
+    ipv6(Err(addrs::Error::ParseError(None)), "2001::1/64");
+    bogus(Err(addrs::Error::ParseError(None)), "bogus");
+} }
+
+fn from_str_error(kind: ParseErrorKind, offset: usize, s: &str) {
+    let error = s.parse::<Pfx>().unwrap_err();
+    let details = error.parse_details().unwrap();
+    assert_eq!(
+        (kind, offset, s),
+        (details.kind(), details.offset(), details.input())
+    );
+}
+
+runner::tests! { from_str_error {
+    bad_address(ParseErrorKind::BadOctet, 7, "10.224.256.0/24");
+    short_address(ParseErrorKind::ExpectedDot, 9, "10.224.24/24");
+    no_length(ParseErrorKind::ExpectedSlash, 11, "10.224.24.1");
+    garbage(ParseErrorKind::ExpectedSlash, 11, "10.224.24.1x/24");
+    empty_length(ParseErrorKind::BadLength, 12, "10.224.24.1/");
+    plus_length(ParseErrorKind::BadLength, 12, "10.224.24.1/+24");
+    bad_mask(ParseErrorKind::BadOctet, 20, "10.224.24.1/255.255.256.0");
+    trailing_mask(ParseErrorKind::TrailingCharacters, 25, "10.224.24.1/255.255.255.0/");
+} }
+
+#[test]
+fn format() {
+    let prefix: Pfx = "10.224.24.1/27".parse().unwrap();
+    assert_eq!("10.224.24.1/27", prefix.to_string());
+    assert_eq!("10.224.24.1/27", format!("{prefix:?}"));
+}
+
+#[test]
+fn format_flags() {
+    let prefix: Pfx = "10.0.0.0/8".parse().unwrap();
+    assert_eq!("  10.0.0.0/8", format!("{prefix:>12}"));
+    assert_eq!("10.0.0.0/8  |", format!("{prefix:<12}|"));
+    assert_eq!("*10.0.0.0/8*", format!("{prefix:*^12}"));
+    assert_eq!("10.0.0.0/8", format!("{prefix:4}"));
+    let subnet: addrs::ipv4::Subnet = "10.0.0.0/8".parse().unwrap();
+    assert_eq!("  10.0.0.0/8", format!("{subnet:>12}"));
+}
+
+#[test]
+fn ordering() {
+    let mut prefixes: Vec<Pfx> = ["10.0.0.0/24", "9.0.0.0/8", "10.0.0.0/8", "10.0.0.1/32"]
+        .into_iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    prefixes.sort();
+    let prefixes: Vec<_> = prefixes.iter().map(Pfx::to_string).collect();
+    assert_eq!(
+        vec!["9.0.0.0/8", "10.0.0.0/8", "10.0.0.0/24", "10.0.0.1/32"],
+        prefixes
+    );
+}
+
+#[test]
+fn hash() {
+    let prefixes: std::collections::HashSet<Pfx> = ["10.0.0.0/24", "10.0.0.0/24", "10.0.0.0/25"]
+        .into_iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    assert_eq!(2, prefixes.len());
+}
+
+#[test]
+fn ipnet() {
+    let net: ipnet::Ipv4Net = "10.224.24.1/27".parse().unwrap();
+    let prefix = Pfx::from(net);
+    assert_eq!("10.224.24.1/27", prefix.to_string());
+    assert_eq!(net, prefix.into());
+}
+
+#[test]
+fn prefix_and_set() {
+    let prefix: Pfx = "10.224.24.1/24".parse().unwrap();
+    assert_eq!(Addr::new(10, 224, 24, 0), prefix.network());
+    assert_eq!(Addr::new(10, 224, 24, 255), prefix.broadcast());
+    assert_eq!(256, prefix.num_addresses().unwrap());
+    assert!(prefix.contains(&Addr::new(10, 224, 24, 77)));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let prefix: Pfx = "10.224.24.1/27".parse().unwrap();
+    let json = serde_json::to_string(&prefix).unwrap();
+    assert_eq!("\"10.224.24.1/27\"", json);
+    assert_eq!(prefix, serde_json::from_str(&json).unwrap());
+    assert!(serde_json::from_str::<Pfx>("\"10.224.24.1/33\"").is_err());
+
+    let address = Addr::new(10, 224, 24, 1);
+    let json = serde_json::to_string(&address).unwrap();
+    assert_eq!("\"10.224.24.1\"", json);
+    assert_eq!(address, serde_json::from_str(&json).unwrap());
+}
+
+#[test]
+fn into_iter() {
+    let prefix: Pfx = "10.0.0.0/30".parse().unwrap();
+    let mut addresses = vec![];
+    for address in prefix {
+        addresses.push(address);
+    }
+    assert_eq!(
+        vec![
+            Addr::new(10, 0, 0, 0),
+            Addr::new(10, 0, 0, 1),
+            Addr::new(10, 0, 0, 2),
+            Addr::new(10, 0, 0, 3)
+        ],
+        addresses
+    );
+    assert_eq!(256, "10.0.0.0/24".parse::<Pfx>().unwrap().into_iter().len());
+}
@@ -0,0 +1,69 @@
+use addrs::{
+    ipv4::{Netmask, Prefix, PrefixLength, Wildcard},
+    Result,
+};
+
+mod util;
+
+fn new(expected: Result<u8>, length: u8) {
+    assert_eq!(
+        PrefixLength::new(length).ok(),
+        PrefixLength::try_from(length).ok()
+    );
+    util::assert_result(expected, PrefixLength::new(length).map(u8::from));
+}
+
+runner::tests! { new {
+    zero(Ok(0), 0);
+    twentyfour(Ok(24), 24);
+    thirtytwo(Ok(32), 32);
+    invalid_length(Err(addrs::Error::InvalidLength), 33);
+    max(Err(addrs::Error::InvalidLength), u8::MAX);
+} }
+
+fn from_str(expected: Result<u8>, s: &str) {
+    util::assert_result(expected, s.parse::<PrefixLength>().map(|l| l.get()));
+}
+
+runner::tests! { from_str {
+    zero(Ok(0), "0");
+    thirtytwo(Ok(32), "32");
+    invalid_length(Err(addrs::Error::InvalidLength), "33");
+    plus(Err(addrs::Error::ParseError(None)), "+3");
+    negative(Err(addrs::Error::ParseError(None)), "-3");
+    bogus(Err(addrs::Error::ParseError(None)), "bogus");
+} }
+
+#[test]
+fn constants() {
+    assert_eq!(0, PrefixLength::MIN.get());
+    assert_eq!(32, PrefixLength::MAX.get());
+    const LENGTH: PrefixLength = PrefixLength::of::<24>();
+    assert_eq!(24, LENGTH.get());
+    assert_eq!("24", LENGTH.to_string());
+    assert_eq!("24", format!("{LENGTH:?}"));
+}
+
+#[test]
+fn mask_conversions() {
+    let length = PrefixLength::of::<20>();
+    assert_eq!("255.255.240.0", Netmask::from(length).to_string());
+    assert_eq!("0.0.15.255", Wildcard::from(length).to_string());
+    assert_eq!(length, Netmask::from_length(20).unwrap().into());
+    assert_eq!(length, Wildcard::from_length(20).unwrap().into());
+}
+
+#[test]
+fn prefix() {
+    let prefix = util::Prefix::from_address_prefix_length(util::a("10.0.0.1"), PrefixLength::MAX);
+    assert_eq!(util::p("10.0.0.1/32"), prefix);
+    assert_eq!(PrefixLength::MAX, prefix.prefix_length());
+}
+
+#[test]
+#[should_panic]
+fn prefix_length_invalid() {
+    // an implementation could return an invalid length; it must not be trusted blindly
+    let prefix: util::Prefix = unsafe { Prefix::new_unchecked(util::a("10.0.0.0"), 33) };
+    prefix.prefix_length();
+}
@@ -0,0 +1,87 @@
+use addrs::ipv4::{parse_prefixes, Pfx};
+
+#[test]
+fn parse() {
+    let input = "\
+# a comment
+10.0.0.0/8
+   192.168.0.0/16\t
+
+172.16.0.0/255.240.0.0 # mask notation
+100.64.0.0/10#tight comment
+";
+    let prefixes: Vec<String> = parse_prefixes::<Pfx, _>(input.as_bytes())
+        .map(|p| p.unwrap().to_string())
+        .collect();
+    assert_eq!(
+        vec![
+            "10.0.0.0/8",
+            "192.168.0.0/16",
+            "172.16.0.0/12",
+            "100.64.0.0/10"
+        ],
+        prefixes
+    );
+}
+
+#[test]
+fn errors() {
+    let input = "10.0.0.0/8\n10.0.0.0/33\n\nbogus # nope\n192.168.0.0/16";
+    let results: Vec<_> = parse_prefixes::<Pfx, _>(input.as_bytes()).collect();
+    assert_eq!(4, results.len());
+    let error = results[1].as_ref().unwrap_err();
+    assert_eq!(2, error.line());
+    assert!(matches!(error.error(), addrs::Error::ParseError(Some(_))));
+    assert_eq!("line 2: prefix parsing failed", error.to_string());
+    assert_eq!(4, results[2].as_ref().unwrap_err().line());
+    assert!(results[3].is_ok());
+}
+
+#[test]
+fn addresses() {
+    let input = "10.0.0.1\n10.0.0.2\n";
+    let addresses: Vec<std::net::Ipv4Addr> = parse_prefixes(input.as_bytes())
+        .map(Result::unwrap)
+        .collect();
+    assert_eq!(2, addresses.len());
+}
+
+#[test]
+fn read_error() {
+    let input: &[u8] = b"10.0.0.0/8\n\xff\n";
+    let results: Vec<_> = parse_prefixes::<Pfx, _>(input).collect();
+    assert_eq!(2, results.len());
+    let error = results[1].as_ref().unwrap_err();
+    assert_eq!(2, error.line());
+    assert!(matches!(error.error(), addrs::Error::ParseError(Some(_))));
+}
+
+// a reader that fails every read after returning its input
+struct Failing<'a>(&'a [u8]);
+
+impl std::io::Read for Failing<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self.0.is_empty() {
+            true => Err(std::io::Error::other("broken")),
+            false => self.0.read(buf),
+        }
+    }
+}
+
+#[test]
+fn failing_reader() {
+    let reader = std::io::BufReader::new(Failing(b"10.0.0.0/8\n"));
+    let results: Vec<_> = parse_prefixes::<Pfx, _>(reader).take(10).collect();
+    assert_eq!(2, results.len());
+    assert!(results[0].is_ok());
+    assert_eq!(2, results[1].as_ref().unwrap_err().line());
+}
+
+#[test]
+fn empty() {
+    assert_eq!(0, parse_prefixes::<Pfx, _>("".as_bytes()).count());
+    assert_eq!(
+        0,
+        parse_prefixes::<Pfx, _>("\n  \n# only\n".as_bytes()).count()
+    );
+}
@@ -3,6 +3,8 @@ use addrs::{
     Result,
 };
 
+use std::net::Ipv4Addr;
+
 mod util;
 
 #[test]
@@ -97,6 +99,26 @@ fn num_prefixes(expected: Result<u32>, prefix: util::Prefix, length: u8) {
     util::assert_result(expected, prefix.num_prefixes(length));
 }
 
+fn num_prefixes_u64(expected: Result<u64>, prefix: util::Prefix, length: u8) {
+    util::assert_result(expected, prefix.num_prefixes_u64(length));
+}
+
+runner::tests! { num_prefixes_u64 {
+    same_size(Ok(1), util::p("203.0.113.0/24"), 24);
+    too_big(Ok(0), util::p("203.0.113.0/24"), 23);
+    size_26(Ok(4), util::p("203.0.113.0/24"), 26);
+    bad_length(Err(addrs::Error::InvalidLength), util::p("0.0.0.0/0"), 33);
+    all(Ok(1 << 32), util::p("0.0.0.0/0"), 32);
+    all_zero(Ok(1), util::p("0.0.0.0/0"), 0);
+} }
+
+#[test]
+fn num_addresses_u64() {
+    assert_eq!(1 << 32, util::p("0.0.0.0/0").num_addresses_u64());
+    assert_eq!(0x00100000, util::p("172.16.0.0/12").num_addresses_u64());
+    assert_eq!(1, util::a("172.16.244.117").num_addresses_u64());
+}
+
 runner::tests! { num_prefixes {
     same_size(Ok(1), util::p("203.0.113.0/24"), 24);
     too_big(Ok(0), util::p("203.0.113.0/24"), 23);
@@ -112,6 +134,8 @@ fn from_address_length(expected: Result<util::Prefix>, address: util::Address, l
 
 runner::tests! { from_address_length {
     basic(Ok(util::p("192.168.1.1/24")), util::a("192.168.1.1"), 24);
+    zero(Ok(util::p("192.168.1.1/0")), util::a("192.168.1.1"), 0);
+    host_route(Ok(util::p("192.168.1.1/32")), util::a("192.168.1.1"), 32);
     invalid_length(Err(addrs::Error::InvalidLength), util::a("192.168.1.1"), 33);
 } }
 
@@ -160,3 +184,1090 @@ runner::tests! { contains {
     same_host_route_32(util::a("1.2.3.4"), util::a("1.2.3.4"));
     ignore_host_part_32(util::p("1.2.3.4/24"), util::a("1.2.3.5"));
 } }
+
+fn overlaps(expected: bool, a: &str, b: &str) {
+    let (a, b) = (util::p(a), util::p(b));
+    assert_eq!(expected, a.overlaps(&b));
+    assert_eq!(expected, b.overlaps(&a));
+    assert_eq!(expected, a.contains(&b) || b.contains(&a));
+}
+
+runner::tests! { overlaps {
+    same(true, "10.0.0.0/24", "10.0.0.0/24");
+    contains(true, "10.0.0.0/16", "10.0.20.0/24");
+    all(true, "0.0.0.0/0", "1.2.3.4/32");
+    ignore_host_part(true, "10.0.0.1/24", "10.0.0.200/25");
+    siblings(false, "10.0.0.0/25", "10.0.0.128/25");
+    disjoint(false, "10.0.0.0/24", "10.0.2.0/23");
+} }
+
+fn canonicalize(expected: &str, prefix: &str) {
+    let prefix = util::p(prefix);
+    let canonical = prefix.canonicalize();
+    assert_eq!(util::p(expected), canonical);
+    assert!(canonical.is_aligned());
+    assert_eq!(expected == prefix.to_string(), prefix.is_aligned());
+}
+
+runner::tests! { canonicalize {
+    aligned("10.224.24.0/24", "10.224.24.0/24");
+    host_bits("10.224.24.0/24", "10.224.24.1/24");
+    all("0.0.0.0/0", "255.255.255.255/0");
+    host_route("10.224.24.1/32", "10.224.24.1/32");
+    p2p("10.224.24.116/31", "10.224.24.117/31");
+} }
+
+fn first_last_host(first: &str, last: &str, prefix: &str) {
+    let prefix = util::p(prefix);
+    assert_eq!(util::a(first), prefix.first_host());
+    assert_eq!(util::a(last), prefix.last_host());
+}
+
+runner::tests! { first_last_host {
+    size_24("10.0.0.1", "10.0.0.254", "10.0.0.77/24");
+    size_30("10.0.0.5", "10.0.0.6", "10.0.0.7/30");
+    size_31("10.0.0.6", "10.0.0.7", "10.0.0.7/31");
+    size_32("10.0.0.7", "10.0.0.7", "10.0.0.7/32");
+    all("0.0.0.1", "255.255.255.254", "0.0.0.0/0");
+    zero_31("0.0.0.0", "0.0.0.1", "0.0.0.0/31");
+    extreme_32("255.255.255.255", "255.255.255.255", "255.255.255.255/32");
+} }
+
+fn num_hosts(expected: u32, prefix: &str) {
+    let prefix = util::p(prefix);
+    assert_eq!(expected, prefix.num_hosts());
+    assert_eq!(expected as usize, prefix.hosts().size_hint().0);
+}
+
+runner::tests! { num_hosts {
+    all(0xfffffffe, "0.0.0.0/0");
+    size_24(254, "10.0.0.0/24");
+    size_30(2, "10.0.0.0/30");
+    size_31(2, "10.0.0.0/31");
+    size_32(1, "10.0.0.0/32");
+} }
+
+fn hosts(expected: Vec<&str>, prefix: &str) {
+    let expected: Vec<util::Address> = expected.into_iter().map(util::a).collect();
+    assert_eq!(expected, util::p(prefix).hosts().collect::<Vec<_>>());
+}
+
+runner::tests! { hosts {
+    size_29(vec!["10.0.0.1", "10.0.0.2", "10.0.0.3", "10.0.0.4", "10.0.0.5", "10.0.0.6"], "10.0.0.0/29");
+    size_30(vec!["10.0.0.5", "10.0.0.6"], "10.0.0.7/30");
+    size_31(vec!["10.0.0.6", "10.0.0.7"], "10.0.0.7/31");
+    size_32(vec!["10.0.0.7"], "10.0.0.7/32");
+    extreme_31(vec!["255.255.255.254", "255.255.255.255"], "255.255.255.255/31");
+    zero_31(vec!["0.0.0.0", "0.0.0.1"], "0.0.0.0/31");
+} }
+
+#[test]
+fn hosts_all() {
+    let prefix = util::p("0.0.0.0/0");
+    let mut hosts = prefix.hosts();
+    assert_eq!(Some(util::a("0.0.0.1")), hosts.next());
+    assert_eq!((0xfffffffd, Some(0xfffffffd)), hosts.size_hint());
+}
+
+fn addresses(expected: Vec<&str>, prefix: &str) {
+    let expected: Vec<util::Address> = expected.into_iter().map(util::a).collect();
+    let prefix = util::p(prefix);
+    assert_eq!(expected, prefix.addresses().collect::<Vec<_>>());
+    assert_eq!(expected.len(), prefix.addresses().len());
+    assert_eq!(expected, prefix.iter_addresses().collect::<Vec<_>>());
+    let mut reversed = expected.clone();
+    reversed.reverse();
+    assert_eq!(reversed, prefix.addresses().rev().collect::<Vec<_>>());
+}
+
+runner::tests! { addresses {
+    size_30(vec!["10.0.0.4", "10.0.0.5", "10.0.0.6", "10.0.0.7"], "10.0.0.7/30");
+    size_31(vec!["10.0.0.6", "10.0.0.7"], "10.0.0.7/31");
+    size_32(vec!["10.0.0.7"], "10.0.0.7/32");
+    extreme(vec!["255.255.255.254", "255.255.255.255"], "255.255.255.255/31");
+} }
+
+#[test]
+fn addresses_all() {
+    let mut addresses = util::p("0.0.0.0/0").addresses();
+    assert_eq!(1 << 32, addresses.len());
+    assert_eq!(Some(util::a("0.0.0.0")), addresses.next());
+    assert_eq!(Some(util::a("255.255.255.255")), addresses.next_back());
+    assert_eq!(Some(util::a("10.0.0.0")), addresses.nth(0x09ffffff));
+    assert_eq!(Some(util::a("10.0.0.1")), addresses.next());
+    assert_eq!(None, addresses.nth(usize::MAX));
+    assert_eq!(None, addresses.next_back());
+    assert_eq!(0, addresses.len());
+}
+
+fn shuffled_addresses(prefix: &str, seed: u64) {
+    let prefix = util::p(prefix);
+    let shuffled = prefix.shuffled_addresses(seed);
+    assert_eq!(prefix.num_addresses_u64() as usize, shuffled.len());
+    let mut addresses: Vec<util::Address> = shuffled.collect();
+    assert_eq!(
+        addresses,
+        prefix.shuffled_addresses(seed).collect::<Vec<_>>()
+    );
+    addresses.sort();
+    assert_eq!(prefix.addresses().collect::<Vec<_>>(), addresses);
+}
+
+runner::tests! { shuffled_addresses {
+    host("10.0.0.7/32", 1);
+    pair("10.0.0.7/31", 1);
+    four("10.0.0.7/30", 2);
+    odd_bits("10.0.0.0/27", 3);
+    class_c("10.0.0.0/24", 4);
+    class_c_zero_seed("10.0.0.0/24", 0);
+    large("10.1.0.0/15", 5);
+    extreme("255.255.255.0/24", u64::MAX);
+} }
+
+#[test]
+fn shuffled_addresses_order() {
+    let prefix = util::p("10.0.0.0/24");
+    let a: Vec<_> = prefix.shuffled_addresses(1).collect();
+    let b: Vec<_> = prefix.shuffled_addresses(2).collect();
+    assert_ne!(a, b);
+    assert_ne!(prefix.addresses().collect::<Vec<_>>(), a);
+
+    let all = util::p("0.0.0.0/0");
+    let mut shuffled = all.shuffled_addresses(1);
+    assert_eq!(1 << 32, shuffled.len());
+    assert!(shuffled.nth(0xffffffff).is_some());
+    assert!(shuffled.next().is_none());
+}
+
+fn classify(expected: &[&str], prefix: &str) {
+    let prefix = util::p(prefix);
+    let actual: Vec<&str> = [
+        ("private", prefix.is_private()),
+        ("loopback", prefix.is_loopback()),
+        ("link_local", prefix.is_link_local()),
+        ("shared", prefix.is_shared()),
+        ("documentation", prefix.is_documentation()),
+        ("benchmarking", prefix.is_benchmarking()),
+        ("multicast", prefix.is_multicast()),
+        ("reserved", prefix.is_reserved()),
+        ("global", prefix.is_global()),
+    ]
+    .into_iter()
+    .filter_map(|(name, is)| is.then_some(name))
+    .collect();
+    assert_eq!(expected, actual);
+}
+
+runner::tests! { classify {
+    ten(&["private"], "10.0.0.0/8");
+    ten_subnet(&["private"], "10.20.30.0/24");
+    ten_supernet(&[], "10.0.0.0/7");
+    one_seventy_two(&["private"], "172.31.255.255/32");
+    one_seventy_two_outside(&["global"], "172.32.0.0/16");
+    one_ninety_two(&["private"], "192.168.1.0/24");
+    loopback(&["loopback"], "127.0.0.1/32");
+    link_local(&["link_local"], "169.254.0.0/16");
+    shared(&["shared"], "100.64.0.0/10");
+    shared_outside(&["global"], "100.128.0.0/16");
+    test_net_1(&["documentation"], "192.0.2.0/24");
+    test_net_2(&["documentation"], "198.51.100.7/32");
+    test_net_3(&["documentation"], "203.0.113.128/25");
+    benchmarking(&["benchmarking"], "198.19.255.0/24");
+    multicast(&["multicast", "global"], "224.0.0.1/32");
+    reserved(&["reserved"], "240.0.0.0/4");
+    broadcast(&["reserved"], "255.255.255.255/32");
+    this_network(&[], "0.0.0.0/8");
+    protocol_assignments(&[], "192.0.0.0/24");
+    pcp_anycast(&["global"], "192.0.0.9/32");
+    nat_anycast(&["global"], "192.0.0.10/32");
+    around_anycast(&[], "192.0.0.8/31");
+    public(&["global"], "8.8.8.0/24");
+    partly_private(&[], "8.0.0.0/4");
+    all(&[], "0.0.0.0/0");
+} }
+
+fn p2p_pair(expected: Option<(util::Address, util::Address)>, prefix: util::Prefix) {
+    assert_eq!(expected, prefix.p2p_pair())
+}
+
+runner::tests! { p2p_pair {
+    lower(Some((util::a("10.224.24.116"), util::a("10.224.24.117"))), util::p("10.224.24.116/31"));
+    upper(Some((util::a("10.224.24.116"), util::a("10.224.24.117"))), util::p("10.224.24.117/31"));
+    extreme(Some((util::a("255.255.255.254"), util::a("255.255.255.255"))), util::p("255.255.255.255/31"));
+    size_30(None, util::p("10.224.24.116/30"));
+    size_32(None, util::p("10.224.24.117/32"));
+} }
+
+fn parse(expected: Result<util::Prefix>, s: &str) {
+    util::assert_result(expected, util::Prefix::parse(s));
+}
+
+runner::tests! { parse {
+    success(Ok(util::p("10.224.24.1/27")), "10.224.24.1/27");
+    host_route(Ok(util::p("10.224.24.1/32")), "10.224.24.1/32");
+    bad_length(Err(addrs::Error::ParseError(None)), "10.224.24.1/33");
+    ipv6(Err(addrs::Error::ParseError(None)), "2001::1/64");
+    bogus(Err(addrs::Error::ParseError(None)), "bogus");
+    mask(Ok(util::p("10.224.24.1/24")), "10.224.24.1/255.255.255.0");
+    zero_mask(Ok(util::p("10.224.24.1/0")), "10.224.24.1/0.0.0.0");
+    invalid_mask(Err(addrs::Error::InvalidMask), "10.224.24.1/255.0.255.0");
+    bad_mask_address(Err(addrs::Error::ParseError(None)), "10.224.24/255.255.255.0");
+} }
+
+#[test]
+fn parse_mask_like_length() {
+    // a mask is only accepted where the equivalent length would be
+    assert!(Ipv4Addr::parse("1.2.3.4/24").is_err());
+    assert!(Ipv4Addr::parse("1.2.3.4/255.255.255.0").is_err());
+    assert!(ipv4::Subnet::parse("10.0.0.1/24").is_err());
+    assert!(ipv4::Subnet::parse("10.0.0.1/255.255.255.0").is_err());
+    assert_eq!(
+        ipv4::Subnet::parse("10.0.0.0/24").unwrap(),
+        ipv4::Subnet::parse("10.0.0.0/255.255.255.0").unwrap()
+    );
+}
+
+fn are_contiguous(expected: Vec<(&str, &str)>, prefixes: Vec<&str>) {
+    let expected: Vec<_> = expected
+        .into_iter()
+        .map(|(from, to)| util::a(from)..=util::a(to))
+        .collect();
+    let actual = ipv4::are_contiguous(prefixes.into_iter().map(util::p));
+    match expected.is_empty() {
+        true => assert!(actual.is_ok()),
+        false => assert_eq!(expected, actual.unwrap_err()),
+    }
+}
+
+runner::tests! { are_contiguous {
+    empty(vec![], vec![]);
+    single(vec![], vec!["10.0.0.0/24"]);
+    tiled(vec![], vec!["10.0.0.0/25", "10.0.0.128/26", "10.0.0.192/26"]);
+    overlapping(vec![], vec!["10.0.0.0/24", "10.0.0.0/25", "10.0.0.128/25", "10.0.1.0/24"]);
+    host_bits(vec![], vec!["10.0.0.1/25", "10.0.0.129/25"]);
+    whole_space(vec![], vec!["0.0.0.0/1", "128.0.0.0/1"]);
+    after_end(vec![], vec!["0.0.0.0/0", "255.255.255.255/32"]);
+    one_gap(vec![("10.0.0.64", "10.0.0.127")], vec!["10.0.0.0/26", "10.0.0.128/25"]);
+    two_gaps(
+        vec![("10.0.0.64", "10.0.0.127"), ("10.0.1.0", "10.0.1.255")],
+        vec!["10.0.0.0/26", "10.0.0.128/25", "10.0.2.0/24"]);
+    gap_after_containing(vec![("10.0.1.0", "10.0.1.255")], vec!["10.0.0.0/24", "10.0.0.4/30", "10.0.2.0/24"]);
+} }
+
+fn is_partitioned_by(
+    expected_overlaps: Vec<(&str, &str)>,
+    expected_outside: Vec<&str>,
+    expected_missing: Vec<(&str, &str)>,
+    parent: &str,
+    children: Vec<&str>,
+) {
+    let result = util::p(parent).is_partitioned_by(children.into_iter().map(util::p));
+    if expected_overlaps.is_empty() && expected_outside.is_empty() && expected_missing.is_empty() {
+        assert!(result.is_ok());
+        return;
+    }
+    let report = result.unwrap_err();
+    let overlaps: Vec<_> = expected_overlaps
+        .into_iter()
+        .map(|(a, b)| (util::p(a), util::p(b)))
+        .collect();
+    assert_eq!(overlaps, report.overlaps);
+    let outside: Vec<_> = expected_outside.into_iter().map(util::p).collect();
+    assert_eq!(outside, report.outside);
+    let missing: Vec<_> = expected_missing
+        .into_iter()
+        .map(|(from, to)| util::a(from)..=util::a(to))
+        .collect();
+    assert_eq!(missing, report.missing);
+}
+
+runner::tests! { is_partitioned_by {
+    itself(vec![], vec![], vec![], "10.0.0.0/24", vec!["10.0.0.0/24"]);
+    halves(vec![], vec![], vec![], "10.0.0.0/24", vec!["10.0.0.128/25", "10.0.0.0/25"]);
+    whole_space(vec![], vec![], vec![], "0.0.0.0/0", vec!["128.0.0.0/1", "0.0.0.0/2", "64.0.0.0/2"]);
+    host_bits(vec![], vec![], vec![], "10.0.0.1/24", vec!["10.0.0.1/25", "10.0.0.129/25"]);
+    empty(vec![], vec![], vec![("10.0.0.0", "10.0.0.255")], "10.0.0.0/24", vec![]);
+    missing_middle(
+        vec![], vec![], vec![("10.0.0.64", "10.0.0.127")],
+        "10.0.0.0/24", vec!["10.0.0.0/26", "10.0.0.128/25"]);
+    missing_ends(
+        vec![], vec![], vec![("10.0.0.0", "10.0.0.63"), ("10.0.0.128", "10.0.0.255")],
+        "10.0.0.0/24", vec!["10.0.0.64/26"]);
+    missing_end_of_space(
+        vec![], vec![], vec![("255.255.255.128", "255.255.255.255")],
+        "255.255.255.0/24", vec!["255.255.255.0/25"]);
+    duplicate(
+        vec![("10.0.0.0/25", "10.0.0.0/25")], vec![], vec![],
+        "10.0.0.0/24", vec!["10.0.0.0/25", "10.0.0.128/25", "10.0.0.0/25"]);
+    nested(
+        vec![
+            ("10.0.0.0/25", "10.0.0.0/26"),
+            ("10.0.0.0/25", "10.0.0.0/30"),
+            ("10.0.0.0/26", "10.0.0.0/30"),
+            ("10.0.0.0/25", "10.0.0.64/27"),
+        ],
+        vec![], vec![],
+        "10.0.0.0/24", vec!["10.0.0.0/30", "10.0.0.64/27", "10.0.0.0/26", "10.0.0.0/25", "10.0.0.128/25"]);
+    outside(
+        vec![], vec!["10.0.1.0/24"], vec![],
+        "10.0.0.0/24", vec!["10.0.0.0/24", "10.0.1.0/24"]);
+    containing_parent(
+        vec![], vec!["10.0.0.0/23"], vec![("10.0.0.0", "10.0.0.255")],
+        "10.0.0.0/24", vec!["10.0.0.0/23"]);
+} }
+
+fn mask_for_length(expected: Result<util::Address>, length: u8) {
+    util::assert_result(expected, ipv4::mask_for_length(length));
+}
+
+runner::tests! { mask_for_length {
+    zero(Ok(util::a("0.0.0.0")), 0);
+    one(Ok(util::a("128.0.0.0")), 1);
+    twentytwo(Ok(util::a("255.255.252.0")), 22);
+    thirtytwo(Ok(util::a("255.255.255.255")), 32);
+    invalid_length(Err(addrs::Error::InvalidLength), 33);
+} }
+
+#[test]
+fn masks_match_prefix_mask() {
+    for (length, mask) in ipv4::MASKS.iter().enumerate() {
+        let prefix =
+            util::Prefix::from_address_mask(util::a("10.224.24.1"), (*mask).into()).unwrap();
+        assert_eq!(length as u8, prefix.length());
+        assert_eq!(*mask, prefix.mask().into());
+    }
+}
+
+fn format_cidr_into(expected: Result<&str>, prefix: &str, size: usize) {
+    let mut buf = [0u8; 32];
+    util::assert_result(expected, util::p(prefix).format_cidr_into(&mut buf[..size]));
+}
+
+runner::tests! { format_cidr_into {
+    zero(Ok("0.0.0.0/0"), "0.0.0.0/0", 9);
+    longest(Ok("255.255.255.255/32"), "255.255.255.255/32", ipv4::MAX_PREFIX_LEN);
+    host_bits(Ok("10.224.24.1/27"), "10.224.24.1/27", 20);
+    too_small(Err(addrs::Error::BufferTooSmall), "255.255.255.255/32", ipv4::MAX_PREFIX_LEN - 1);
+    too_small_at_slash(Err(addrs::Error::BufferTooSmall), "10.0.0.1/8", 8);
+} }
+
+fn carve(expected: Result<Vec<&str>>, parent: &str, child: &str) {
+    let expected = expected.map(|e| e.into_iter().map(util::p).collect());
+    util::assert_result(expected, util::p(parent).carve(&util::p(child)));
+}
+
+runner::tests! { carve {
+    same(Ok(vec![]), "10.0.0.0/24", "10.0.0.0/24");
+    left_half(Ok(vec!["10.0.0.128/25"]), "10.0.0.0/24", "10.0.0.0/25");
+    right_half(Ok(vec!["10.0.0.0/25"]), "10.0.0.0/24", "10.0.0.128/25");
+    middle(Ok(vec!["10.0.0.0/26", "10.0.0.128/25"]), "10.0.0.0/24", "10.0.0.64/26");
+    host(
+        Ok(vec!["10.0.0.0/30", "10.0.0.4/31", "10.0.0.7/32", "10.0.0.8/29"]),
+        "10.0.0.0/28", "10.0.0.6/32");
+    host_bits(Ok(vec!["10.0.0.0/25"]), "10.0.0.1/24", "10.0.0.130/25");
+    everything(
+        Ok(vec!["0.0.0.0/1", "128.0.0.0/2", "192.0.0.0/3", "224.0.0.0/4", "240.0.0.0/5",
+            "248.0.0.0/6", "252.0.0.0/7", "254.0.0.0/8"]),
+        "0.0.0.0/0", "255.0.0.0/8");
+    not_contained(Err(addrs::Error::NotContained), "10.0.0.0/24", "10.0.1.0/25");
+    contains_parent(Err(addrs::Error::NotContained), "10.0.0.0/24", "10.0.0.0/23");
+} }
+
+#[test]
+fn carve_address() {
+    let leftovers = util::p("10.0.0.0/31").carve(&util::a("10.0.0.1")).unwrap();
+    assert_eq!(vec![util::p("10.0.0.0/32")], leftovers);
+}
+
+fn new(expected: Result<util::Prefix>, address: util::Address, length: u8) {
+    util::assert_result(expected, util::Prefix::new(address, length));
+}
+
+runner::tests! { new {
+    zero(Ok(util::p("192.168.1.1/0")), util::a("192.168.1.1"), 0);
+    basic(Ok(util::p("192.168.1.1/24")), util::a("192.168.1.1"), 24);
+    host_route(Ok(util::p("192.168.1.1/32")), util::a("192.168.1.1"), 32);
+    invalid_length(Err(addrs::Error::InvalidLength), util::a("192.168.1.1"), 33);
+} }
+
+fn parse_wildcard(expected: Result<util::Prefix>, s: &str) {
+    util::assert_result(expected, util::Prefix::parse_wildcard(s));
+}
+
+runner::tests! { parse_wildcard {
+    basic(Ok(util::p("10.0.0.0/24")), "10.0.0.0 0.0.0.255");
+    host_bits(Ok(util::p("10.0.0.1/22")), "10.0.0.1 0.0.3.255");
+    whitespace(Ok(util::p("10.0.0.0/24")), " 10.0.0.0 \t 0.0.0.255 ");
+    exact(Ok(util::p("10.0.0.1/32")), "10.0.0.1 0.0.0.0");
+    host(Ok(util::p("10.0.0.1/32")), "host 10.0.0.1");
+    any(Ok(util::p("0.0.0.0/0")), "any");
+    everything(Ok(util::p("0.0.0.0/0")), "0.0.0.0 255.255.255.255");
+    not_contiguous(Err(addrs::Error::InvalidMask), "10.0.0.0 0.0.255.0");
+    netmask(Err(addrs::Error::InvalidMask), "10.0.0.0 255.255.255.0");
+    missing_wildcard(Err(addrs::Error::ParseError(None)), "10.0.0.0");
+    extra(Err(addrs::Error::ParseError(None)), "10.0.0.0 0.0.0.255 log");
+    bad_address(Err(addrs::Error::ParseError(None)), "10.0.0 0.0.0.255");
+    empty(Err(addrs::Error::ParseError(None)), "");
+} }
+
+fn parse_glob(expected: Result<util::Prefix>, s: &str) {
+    util::assert_result(expected, util::Prefix::parse_glob(s));
+}
+
+runner::tests! { parse_glob {
+    none(Ok(util::p("10.0.0.1/32")), "10.0.0.1");
+    one(Ok(util::p("10.0.0.0/24")), "10.0.0.*");
+    two(Ok(util::p("10.0.0.0/16")), "10.0.*.*");
+    all(Ok(util::p("0.0.0.0/0")), "*.*.*.*");
+    aligned_range(Ok(util::p("10.0.4.0/22")), "10.0.4-7.*");
+    aligned_last_octet(Ok(util::p("10.0.0.16/28")), "10.0.0.16-31");
+    unaligned_range(Err(addrs::Error::ParseError(None)), "10.0.1-3.*");
+    not_trailing(Err(addrs::Error::ParseError(None)), "10.*.0.*");
+    range_not_trailing(Err(addrs::Error::ParseError(None)), "10.0-1.0.*");
+    backwards(Err(addrs::Error::InvalidRange), "10.0.3-1.*");
+    too_few(Err(addrs::Error::ParseError(None)), "10.0.*");
+    too_many(Err(addrs::Error::ParseError(None)), "10.0.0.*.*");
+    bad_octet(Err(addrs::Error::ParseError(None)), "10.0.256.*");
+    plus(Err(addrs::Error::ParseError(None)), "10.0.+1.*");
+    partial_star(Err(addrs::Error::ParseError(None)), "10.0.1*.*");
+} }
+
+fn coarsen(expected: Result<Vec<&str>>, prefix: &str, length: u8) {
+    let expected = expected.map(|v| v.into_iter().map(util::p).collect::<Vec<_>>());
+    util::assert_result(expected, util::p(prefix).coarsen(length));
+}
+
+runner::tests! { coarsen {
+    supernet(Ok(vec!["10.0.0.0/16"]), "10.0.3.4/24", 16);
+    same(Ok(vec!["10.0.3.0/24"]), "10.0.3.4/24", 24);
+    subnets(Ok(vec!["10.0.3.0/26", "10.0.3.64/26", "10.0.3.128/26", "10.0.3.192/26"]), "10.0.3.4/24", 26);
+    all(Ok(vec!["0.0.0.0/0"]), "10.0.3.4/32", 0);
+    top(Ok(vec!["255.255.255.255/32"]), "255.255.255.255/32", 32);
+    invalid(Err(addrs::Error::InvalidLength), "10.0.3.4/24", 33);
+} }
+
+fn bucket_counts(expected: Result<Vec<(&str, u64)>>, items: Vec<&str>, length: u8) {
+    let expected = expected.map(|v| {
+        v.into_iter()
+            .map(|(p, count)| (util::p(p), count))
+            .collect::<Vec<_>>()
+    });
+    let items = items.into_iter().map(util::p);
+    util::assert_result(expected, ipv4::bucket_counts(items, length));
+}
+
+runner::tests! { bucket_counts {
+    empty(Ok(vec![]), vec![], 24);
+    addresses(
+        Ok(vec![("10.0.0.0/24", 2), ("10.0.2.0/24", 1)]),
+        vec!["10.0.2.1/32", "10.0.0.1/32", "10.0.0.2/32"],
+        24
+    );
+    duplicates(Ok(vec![("10.0.0.0/24", 2)]), vec!["10.0.0.1/32", "10.0.0.1/32"], 24);
+    smaller(
+        Ok(vec![("10.0.0.0/24", 80), ("10.1.0.0/24", 256)]),
+        vec!["10.0.0.0/26", "10.0.0.64/28", "10.1.0.0/24"],
+        24
+    );
+    larger(
+        Ok(vec![("10.0.0.0/24", 257), ("10.0.1.0/24", 256)]),
+        vec!["10.0.0.0/23", "10.0.0.1/32"],
+        24
+    );
+    host_bits(Ok(vec![("10.0.0.0/16", 256)]), vec!["10.0.3.7/24"], 16);
+    all(Ok(vec![("0.0.0.0/0", 1 << 32)]), vec!["0.0.0.0/0"], 0);
+    invalid(Err(addrs::Error::InvalidLength), vec!["10.0.0.0/8"], 33);
+} }
+
+#[test]
+fn bucket_counts_address() {
+    let items = [util::p("10.0.0.1/32"), util::p("10.0.1.0/24")];
+    let buckets: addrs::Result<Vec<(util::Address, u64)>> = ipv4::bucket_counts(items, 24);
+    assert!(matches!(buckets, Err(addrs::Error::InvalidLength)));
+    let buckets: Vec<(util::Address, u64)> = ipv4::bucket_counts(items, 32).unwrap();
+    assert_eq!(257, buckets.len());
+    assert_eq!((util::a("10.0.0.1"), 1), buckets[0]);
+    assert_eq!((util::a("10.0.1.255"), 1), buckets[256]);
+}
+
+fn without_subsets(expected: Vec<&str>, prefixes: Vec<&str>) {
+    let expected: Vec<_> = expected.into_iter().map(util::p).collect();
+    assert_eq!(
+        expected,
+        ipv4::without_subsets(prefixes.into_iter().map(util::p))
+    );
+}
+
+runner::tests! { without_subsets {
+    empty(vec![], vec![]);
+    disjoint(vec!["10.0.0.0/24", "10.0.1.0/24"], vec!["10.0.1.0/24", "10.0.0.0/24"]);
+    nested(vec!["10.0.0.0/8"], vec!["10.0.1.0/24", "10.0.0.0/8", "10.0.0.0/16", "10.1.2.3/32"]);
+    duplicates(vec!["10.0.0.0/16"], vec!["10.0.0.0/16", "10.0.0.0/16"]);
+    siblings_not_merged(vec!["10.0.0.0/25", "10.0.0.128/25"], vec!["10.0.0.128/25", "10.0.0.0/25"]);
+    mixed(
+        vec!["10.0.0.0/16", "10.2.0.0/24", "192.168.0.0/24"],
+        vec!["192.168.0.0/24", "10.0.0.0/16", "10.0.5.0/24", "10.2.0.0/24", "192.168.0.7/32"]
+    );
+    all(vec!["0.0.0.0/0"], vec!["10.0.0.0/8", "0.0.0.0/0", "255.255.255.255/32"]);
+} }
+
+fn without_supersets(expected: Vec<&str>, prefixes: Vec<&str>) {
+    let expected: Vec<_> = expected.into_iter().map(util::p).collect();
+    assert_eq!(
+        expected,
+        ipv4::without_supersets(prefixes.into_iter().map(util::p))
+    );
+}
+
+runner::tests! { without_supersets {
+    empty(vec![], vec![]);
+    disjoint(vec!["10.0.0.0/24", "10.0.1.0/24"], vec!["10.0.1.0/24", "10.0.0.0/24"]);
+    nested(vec!["10.0.1.0/24", "10.1.2.3/32"], vec!["10.0.1.0/24", "10.0.0.0/8", "10.0.0.0/16", "10.1.2.3/32"]);
+    chain(vec!["10.0.0.0/24"], vec!["10.0.0.0/8", "10.0.0.0/16", "10.0.0.0/24"]);
+    duplicates(vec!["10.0.0.0/16"], vec!["10.0.0.0/16", "10.0.0.0/16"]);
+    duplicate_parents(vec!["10.0.1.0/24"], vec!["10.0.0.0/16", "10.0.1.0/24", "10.0.0.0/16"]);
+    mixed(
+        vec!["10.0.5.0/24", "10.2.0.0/24", "192.168.0.7/32"],
+        vec!["192.168.0.0/24", "10.0.0.0/16", "10.0.5.0/24", "10.2.0.0/24", "192.168.0.7/32"]
+    );
+    all(vec!["10.0.0.0/8", "255.255.255.255/32"], vec!["10.0.0.0/8", "0.0.0.0/0", "255.255.255.255/32"]);
+} }
+
+#[test]
+fn aggregate_addresses() {
+    let addresses = ["10.0.0.1", "10.0.0.0", "10.0.0.1", "10.0.0.3"].map(util::a);
+    assert_eq!(
+        vec![
+            util::a("10.0.0.0"),
+            util::a("10.0.0.1"),
+            util::a("10.0.0.3")
+        ],
+        ipv4::aggregate(addresses, None).collect::<Vec<_>>()
+    );
+}
+
+fn overlapping_pairs(expected: Vec<(&str, &str)>, prefixes: Vec<&str>) {
+    let expected: Vec<_> = expected
+        .into_iter()
+        .map(|(a, b)| (util::p(a), util::p(b)))
+        .collect();
+    assert_eq!(
+        expected,
+        ipv4::overlapping_pairs(prefixes.into_iter().map(util::p))
+    );
+}
+
+runner::tests! { overlapping_pairs {
+    empty(vec![], vec![]);
+    disjoint(vec![], vec!["10.0.1.0/24", "10.0.0.0/24", "10.0.2.0/23"]);
+    nested(
+        vec![("10.0.0.0/8", "10.0.0.0/16"), ("10.0.0.0/8", "10.0.0.0/24"), ("10.0.0.0/16", "10.0.0.0/24")],
+        vec!["10.0.0.0/24", "10.0.0.0/8", "10.0.0.0/16"]
+    );
+    duplicates(vec![("10.0.0.0/24", "10.0.0.0/24")], vec!["10.0.0.0/24", "10.0.0.0/24"]);
+    siblings(
+        vec![("10.0.0.0/23", "10.0.0.0/24"), ("10.0.0.0/23", "10.0.1.0/24")],
+        vec!["10.0.1.0/24", "10.0.0.0/24", "10.0.0.0/23"]
+    );
+    popped(
+        vec![("10.0.0.0/24", "10.0.0.128/25"), ("10.0.1.0/24", "10.0.1.5/32")],
+        vec!["10.0.0.128/25", "10.0.0.0/24", "10.0.1.5/32", "10.0.1.0/24"]
+    );
+    everything(
+        vec![("0.0.0.0/0", "10.0.0.0/8"), ("0.0.0.0/0", "255.255.255.255/32")],
+        vec!["255.255.255.255/32", "10.0.0.0/8", "0.0.0.0/0"]
+    );
+} }
+
+fn aggregate(expected: Vec<&str>, prefixes: Vec<&str>, max_length: Option<u8>) {
+    let expected: Vec<_> = expected.into_iter().map(util::p).collect();
+    let prefixes = prefixes.into_iter().map(util::p);
+    assert_eq!(
+        expected,
+        ipv4::aggregate(prefixes, max_length).collect::<Vec<_>>()
+    );
+}
+
+runner::tests! { aggregate {
+    empty(vec![], vec![], None);
+    single(vec!["10.0.0.0/24"], vec!["10.0.0.9/24"], None);
+    duplicates(vec!["10.0.0.0/24"], vec!["10.0.0.0/24", "10.0.0.0/24"], None);
+    contained(vec!["10.0.0.0/16"], vec!["10.0.5.0/24", "10.0.0.0/16", "10.0.0.1/32"], None);
+    siblings(vec!["10.0.0.0/23"], vec!["10.0.1.0/24", "10.0.0.0/24"], None);
+    cascade(
+        vec!["10.0.0.0/22"],
+        vec!["10.0.0.0/24", "10.0.1.0/25", "10.0.1.128/25", "10.0.2.0/23"],
+        None
+    );
+    adjacent_not_siblings(
+        vec!["10.0.1.0/24", "10.0.2.0/24"],
+        vec!["10.0.2.0/24", "10.0.1.0/24"],
+        None
+    );
+    disjoint(vec!["10.0.0.0/24", "192.168.0.0/16"], vec!["192.168.0.0/16", "10.0.0.0/24"], None);
+    max_length(
+        vec!["10.0.0.0/24"],
+        vec!["10.0.0.0/25", "10.0.0.128/25", "10.0.0.0/24", "10.0.1.0/25"],
+        Some(24)
+    );
+    max_length_all_dropped(vec![], vec!["10.0.0.1/32"], Some(31));
+    everything(vec!["0.0.0.0/0"], vec!["128.0.0.0/1", "0.0.0.0/1", "255.255.255.255/32"], None);
+    end(vec!["255.255.255.254/31"], vec!["255.255.255.255/32", "255.255.255.254/32"], None);
+} }
+
+fn reverse_zone_name(expected: &str, prefix: &str) {
+    assert_eq!(expected, util::p(prefix).reverse_zone_name());
+}
+
+runner::tests! { reverse_zone_name {
+    all("in-addr.arpa", "10.1.2.3/0");
+    eight("10.in-addr.arpa", "10.1.2.3/8");
+    sixteen("1.10.in-addr.arpa", "10.1.2.3/16");
+    twenty_four("2.1.10.in-addr.arpa", "10.1.2.3/24");
+    host("3.2.1.10.in-addr.arpa", "10.1.2.3/32");
+    classless("0/26.2.1.10.in-addr.arpa", "10.1.2.3/26");
+    classless_second("128/25.2.1.10.in-addr.arpa", "10.1.2.200/25");
+    classless_31("2/31.2.1.10.in-addr.arpa", "10.1.2.3/31");
+    twenty("16/20.1.10.in-addr.arpa", "10.1.18.0/20");
+    one("0/1.in-addr.arpa", "10.1.2.3/1");
+} }
+
+fn reverse_zones(expected: Vec<&str>, prefix: &str) {
+    assert_eq!(expected, util::p(prefix).reverse_zones());
+}
+
+runner::tests! { reverse_zones {
+    aligned(vec!["2.1.10.in-addr.arpa"], "10.1.2.0/24");
+    twenty_two(
+        vec!["4.1.10.in-addr.arpa", "5.1.10.in-addr.arpa", "6.1.10.in-addr.arpa", "7.1.10.in-addr.arpa"],
+        "10.1.5.0/22"
+    );
+    fifteen(vec!["0.10.in-addr.arpa", "1.10.in-addr.arpa"], "10.0.0.0/15");
+    classless(vec!["64/26.2.1.10.in-addr.arpa"], "10.1.2.64/26");
+    host(vec!["3.2.1.10.in-addr.arpa"], "10.1.2.3/32");
+    all(vec!["in-addr.arpa"], "0.0.0.0/0");
+} }
+
+fn network_bits(expected: &str, prefix: &str) {
+    let bits: String = util::p(prefix)
+        .network_bits()
+        .map(|b| if b { '1' } else { '0' })
+        .collect();
+    assert_eq!(expected, bits);
+}
+
+runner::tests! { network_bits {
+    zero("", "10.1.2.3/0");
+    eight("00001010", "10.1.2.3/8");
+    host_bits_ignored("000010100000", "10.15.2.3/12");
+    host("11111111000000001111111100000001", "255.0.255.1/32");
+} }
+
+fn parse_cidr_partial(expected: Result<(&str, usize)>, bytes: &[u8]) {
+    let expected = expected.map(|(p, consumed)| (util::p(p), consumed));
+    util::assert_result(expected, util::Prefix::parse_cidr_partial(bytes));
+}
+
+runner::tests! { parse_cidr_partial {
+    whole(Ok(("10.0.0.0/8", 10)), b"10.0.0.0/8");
+    trailing(Ok(("10.0.0.0/24", 11)), b"10.0.0.0/24 via 10.0.0.1");
+    max(Ok(("10.0.0.1/32", 11)), b"10.0.0.1/32,");
+    zero(Ok(("0.0.0.0/0", 9)), b"0.0.0.0/0");
+    leading_zero(Ok(("10.0.0.0/8", 11)), b"10.0.0.0/08");
+    too_long(Err(addrs::Error::InvalidLength), b"10.0.0.0/33");
+    three_digits(Err(addrs::Error::InvalidLength), b"10.0.0.0/100");
+    over_u8(Err(addrs::Error::InvalidLength), b"10.0.0.0/245");
+    over_u8_wraps(Err(addrs::Error::InvalidLength), b"10.0.0.0/264");
+    huge(Err(addrs::Error::InvalidLength), b"10.0.0.0/99999999999999999999");
+    no_length(Err(addrs::Error::ParseError(None)), b"10.0.0.0/");
+    no_slash(Err(addrs::Error::ParseError(None)), b"10.0.0.0 ");
+    bad_address(Err(addrs::Error::ParseError(None)), b"10.0.0/8");
+} }
+
+#[test]
+fn parse_cidr_partial_details() {
+    let error = util::Prefix::parse_cidr_partial(b"10.0.0.0/x").unwrap_err();
+    let details = error.parse_details().unwrap();
+    assert_eq!(
+        (addrs::ParseErrorKind::BadLength, 9, "10.0.0.0/x"),
+        (details.kind(), details.offset(), details.input())
+    );
+}
+
+fn ptr_records(expected: Vec<(&str, &str)>, prefix: &str) {
+    let expected: Vec<_> = expected
+        .into_iter()
+        .map(|(name, address)| (name.to_string(), util::a(address)))
+        .collect();
+    assert_eq!(expected, util::p(prefix).ptr_records().collect::<Vec<_>>());
+}
+
+runner::tests! { ptr_records {
+    host(vec![("7.2.1.10.in-addr.arpa", "10.1.2.7")], "10.1.2.7/32");
+    p2p(vec![("6.2.1.10.in-addr.arpa", "10.1.2.6"), ("7.2.1.10.in-addr.arpa", "10.1.2.7")], "10.1.2.7/31");
+    thirty(vec![("5.2.1.10.in-addr.arpa", "10.1.2.5"), ("6.2.1.10.in-addr.arpa", "10.1.2.6")], "10.1.2.7/30");
+} }
+
+#[test]
+fn ptr_records_count() {
+    assert_eq!(254, util::p("10.1.2.0/24").ptr_records().count());
+    let crossing: Vec<_> = util::p("10.1.0.0/23")
+        .ptr_records()
+        .skip(254)
+        .take(2)
+        .collect();
+    assert_eq!(
+        vec![
+            ("255.0.1.10.in-addr.arpa".to_string(), util::a("10.1.0.255")),
+            ("0.1.1.10.in-addr.arpa".to_string(), util::a("10.1.1.0")),
+        ],
+        crossing
+    );
+    let last = util::p("255.255.255.0/24").ptr_records().last().unwrap();
+    assert_eq!(
+        (
+            "254.255.255.255.in-addr.arpa".to_string(),
+            util::a("255.255.255.254")
+        ),
+        last
+    );
+}
+
+fn subnets(expected: Result<Vec<&str>>, prefix: &str, length: u8) {
+    let expected = expected.map(|v| v.into_iter().map(util::p).collect::<Vec<_>>());
+    let prefix = util::p(prefix);
+    if let Ok(expected) = &expected {
+        let length = ipv4::PrefixLength::new(length).unwrap();
+        assert_eq!(*expected, prefix.subnets_of(length).collect::<Vec<_>>());
+    }
+    util::assert_result(expected, prefix.subnets(length).map(Iterator::collect));
+}
+
+#[test]
+fn subnets_of_shorter() {
+    let prefix = util::p("10.0.0.0/24");
+    let length = ipv4::PrefixLength::new(23).unwrap();
+    assert_eq!(0, prefix.subnets_of(length).count());
+    assert_eq!(0, prefix.num_prefixes_of(length).unwrap());
+    let length = ipv4::PrefixLength::new(26).unwrap();
+    assert_eq!(4, prefix.num_prefixes_of(length).unwrap());
+}
+
+runner::tests! { subnets {
+    same(Ok(vec!["10.0.0.0/24"]), "10.0.0.7/24", 24);
+    halves(Ok(vec!["10.0.0.0/25", "10.0.0.128/25"]), "10.0.0.0/24", 25);
+    quarters(Ok(vec!["10.0.0.0/26", "10.0.0.64/26", "10.0.0.128/26", "10.0.0.192/26"]), "10.0.0.0/24", 26);
+    hosts(Ok(vec!["10.0.0.4/32", "10.0.0.5/32", "10.0.0.6/32", "10.0.0.7/32"]), "10.0.0.5/30", 32);
+    end(Ok(vec!["255.255.255.254/32", "255.255.255.255/32"]), "255.255.255.255/31", 32);
+    shorter(Err(addrs::Error::InvalidLength), "10.0.0.0/24", 23);
+    too_long(Err(addrs::Error::InvalidLength), "10.0.0.0/24", 33);
+} }
+
+#[test]
+fn subnets_count() {
+    assert_eq!(1 << 16, util::p("10.0.0.0/8").subnets(24).unwrap().count());
+    let last = util::p("0.0.0.0/0").subnets(8).unwrap().last().unwrap();
+    assert_eq!(util::p("255.0.0.0/8"), last);
+}
+
+fn nth_subnet(expected: Result<util::Prefix>, prefix: &str, index: u64, length: u8) {
+    util::assert_result(expected, util::p(prefix).nth_subnet(index, length));
+}
+
+runner::tests! { nth_subnet {
+    first(Ok(util::p("10.0.0.0/24")), "10.0.0.0/16", 0, 24);
+    seventh(Ok(util::p("10.0.7.0/24")), "10.0.0.0/16", 7, 24);
+    last(Ok(util::p("10.0.255.0/24")), "10.0.0.0/16", 255, 24);
+    past_end(Err(addrs::Error::NotContained), "10.0.0.0/16", 256, 24);
+    host_bits(Ok(util::p("10.0.0.128/25")), "10.0.0.7/24", 1, 25);
+    same(Ok(util::p("10.0.0.0/24")), "10.0.0.7/24", 0, 24);
+    top(Ok(util::p("255.255.255.255/32")), "0.0.0.0/0", u32::MAX as u64, 32);
+    huge_index(Err(addrs::Error::NotContained), "0.0.0.0/0", 1 << 32, 32);
+    shorter(Err(addrs::Error::InvalidLength), "10.0.0.0/24", 0, 23);
+    too_long(Err(addrs::Error::InvalidLength), "10.0.0.0/24", 0, 33);
+} }
+
+#[test]
+fn nth_subnet_matches_subnets() {
+    let prefix = util::p("10.1.0.0/22");
+    for (i, subnet) in prefix.subnets(27).unwrap().enumerate() {
+        assert_eq!(subnet, prefix.nth_subnet(i as u64, 27).unwrap());
+    }
+}
+
+fn parent(expected: Option<util::Prefix>, prefix: &str) {
+    assert_eq!(expected, util::p(prefix).parent());
+}
+
+runner::tests! { parent {
+    right(Some(util::p("10.0.0.0/24")), "10.0.0.128/25");
+    left(Some(util::p("10.0.0.0/24")), "10.0.0.0/25");
+    host_bits(Some(util::p("10.0.0.0/23")), "10.0.1.7/24");
+    host(Some(util::p("10.0.0.6/31")), "10.0.0.7/32");
+    one(Some(util::p("0.0.0.0/0")), "128.0.0.0/1");
+    zero(None, "10.0.0.0/0");
+} }
+
+fn supernet(expected: Option<util::Prefix>, prefix: &str, levels: u8) {
+    assert_eq!(expected, util::p(prefix).supernet(levels));
+}
+
+runner::tests! { supernet {
+    none(Some(util::p("10.0.0.0/24")), "10.0.0.7/24", 0);
+    one(Some(util::p("10.0.0.0/23")), "10.0.1.0/24", 1);
+    several(Some(util::p("10.0.0.0/16")), "10.0.255.0/24", 8);
+    all(Some(util::p("0.0.0.0/0")), "255.0.255.0/24", 24);
+    too_many(None, "10.0.0.0/24", 25);
+    max(None, "10.0.0.0/24", u8::MAX);
+} }
+
+#[test]
+fn supernet_addresses() {
+    let address = util::a("10.0.0.7");
+    assert_eq!(Some(address), address.supernet(0));
+    assert_eq!(None, address.supernet(1));
+    assert_eq!(None, address.parent());
+}
+
+#[test]
+fn parent_inverse_of_halves() {
+    let prefix = util::p("10.1.2.0/23");
+    let (a, b) = prefix.halves().unwrap();
+    assert_eq!(Some(prefix), a.parent());
+    assert_eq!(Some(prefix), b.parent());
+}
+
+#[test]
+fn ancestors() {
+    let ancestors: Vec<_> = util::p("10.1.2.3/32").ancestors().collect();
+    assert_eq!(33, ancestors.len());
+    assert_eq!(util::p("10.1.2.3/32"), ancestors[0]);
+    assert_eq!(util::p("10.1.2.2/31"), ancestors[1]);
+    assert_eq!(util::p("10.1.0.0/16"), ancestors[16]);
+    assert_eq!(util::p("0.0.0.0/0"), ancestors[32]);
+    for pair in ancestors.windows(2) {
+        assert_eq!(pair[0].parent(), Some(pair[1]));
+    }
+
+    let ancestors: Vec<_> = util::p("10.1.2.3/0").ancestors().collect();
+    assert_eq!(vec![util::p("0.0.0.0/0")], ancestors);
+}
+
+#[test]
+fn ancestors_address() {
+    let address = util::a("10.1.2.3");
+    assert_eq!(vec![address], address.ancestors().collect::<Vec<_>>());
+}
+
+fn next(expected: Option<util::Prefix>, prefix: &str) {
+    assert_eq!(expected, util::p(prefix).next());
+}
+
+runner::tests! { next {
+    basic(Some(util::p("10.0.1.0/24")), "10.0.0.0/24");
+    host_bits(Some(util::p("10.0.1.0/24")), "10.0.0.7/24");
+    carry(Some(util::p("11.0.0.0/8")), "10.255.0.0/8");
+    host(Some(util::p("10.0.1.0/32")), "10.0.0.255/32");
+    end(None, "255.255.255.0/24");
+    all(None, "0.0.0.0/0");
+} }
+
+fn prev(expected: Option<util::Prefix>, prefix: &str) {
+    assert_eq!(expected, util::p(prefix).prev());
+}
+
+runner::tests! { prev {
+    basic(Some(util::p("10.0.0.0/24")), "10.0.1.0/24");
+    host_bits(Some(util::p("10.0.0.0/24")), "10.0.1.7/24");
+    borrow(Some(util::p("9.0.0.0/8")), "10.255.0.0/8");
+    host(Some(util::p("10.0.0.255/32")), "10.0.1.0/32");
+    start(None, "0.0.0.0/24");
+    all(None, "0.0.0.0/0");
+} }
+
+fn merge(expected: Option<&str>, a: &str, b: &str) {
+    let (a, b) = (util::p(a), util::p(b));
+    assert_eq!(expected.is_some(), a.is_adjacent_to(&b));
+    assert_eq!(expected.is_some(), b.is_adjacent_to(&a));
+    assert_eq!(expected.map(util::p), a.merge(&b));
+    assert_eq!(expected.map(util::p), b.merge(&a));
+}
+
+runner::tests! { merge {
+    siblings(Some("10.0.0.0/24"), "10.0.0.0/25", "10.0.0.128/25");
+    host_bits(Some("10.0.0.0/24"), "10.0.0.5/25", "10.0.0.130/25");
+    hosts(Some("10.0.0.6/31"), "10.0.0.6/32", "10.0.0.7/32");
+    halves_of_all(Some("0.0.0.0/0"), "0.0.0.0/1", "128.0.0.0/1");
+    same(None, "10.0.0.0/25", "10.0.0.0/25");
+    touching_cousins(None, "10.0.0.128/25", "10.0.1.0/25");
+    different_lengths(None, "10.0.0.0/25", "10.0.0.128/26");
+    far_apart(None, "10.0.0.0/25", "11.0.0.128/25");
+    all(None, "0.0.0.0/0", "0.0.0.0/0");
+} }
+
+#[test]
+fn merge_addresses() {
+    let (a, b) = (util::a("10.0.0.6"), util::a("10.0.0.7"));
+    assert!(a.is_adjacent_to(&b));
+    assert_eq!(None, a.merge(&b));
+    assert_eq!(
+        Some(util::p("10.0.0.6/31")),
+        util::p("10.0.0.6/32").merge(&b)
+    );
+}
+
+fn exclude(expected: Vec<&str>, prefix: &str, other: &str) {
+    let expected: Vec<_> = expected.into_iter().map(util::p).collect();
+    assert_eq!(expected, util::p(prefix).exclude(&util::p(other)));
+}
+
+runner::tests! { exclude {
+    inside(vec!["10.0.0.0/26", "10.0.0.128/25"], "10.0.0.0/24", "10.0.0.64/26");
+    host(
+        vec!["10.0.0.0/30", "10.0.0.4/31", "10.0.0.7/32"],
+        "10.0.0.0/29",
+        "10.0.0.6/32"
+    );
+    same(vec![], "10.0.0.0/24", "10.0.0.0/24");
+    contains(vec![], "10.0.0.0/24", "10.0.0.0/8");
+    disjoint(vec!["10.0.0.0/24"], "10.0.0.0/24", "10.0.1.0/24");
+    disjoint_host_bits(vec!["10.0.0.0/24"], "10.0.0.9/24", "10.0.1.0/24");
+    all(vec!["0.0.0.0/1"], "0.0.0.0/0", "128.0.0.0/1");
+} }
+
+#[test]
+fn exclude_address() {
+    let leftovers = util::p("10.0.0.0/30").exclude(&util::a("10.0.0.1"));
+    assert_eq!(
+        vec![util::p("10.0.0.0/32"), util::p("10.0.0.2/31")],
+        leftovers
+    );
+}
+
+fn contains_set(expected: bool, prefix: &str, from: &str, to: &str) {
+    let prefix = util::p(prefix);
+    let range = util::a(from)..=util::a(to);
+    assert_eq!(expected, prefix.contains_set(&range));
+    if let Ok(range) = ipv4::AddressRange::try_from(range) {
+        assert_eq!(expected, prefix.contains_set(&range));
+    }
+}
+
+runner::tests! { contains_set {
+    exact(true, "10.0.0.0/24", "10.0.0.0", "10.0.0.255");
+    inside(true, "10.0.0.0/24", "10.0.0.1", "10.0.0.254");
+    address(true, "10.0.0.0/24", "10.0.0.7", "10.0.0.7");
+    before(false, "10.0.0.0/24", "9.255.255.255", "10.0.0.7");
+    after(false, "10.0.0.0/24", "10.0.0.7", "10.0.1.0");
+    disjoint(false, "10.0.0.0/24", "10.0.1.0", "10.0.1.5");
+    empty(true, "10.0.0.0/24", "10.0.1.5", "10.0.1.0");
+    all(true, "0.0.0.0/0", "0.0.0.0", "255.255.255.255");
+} }
+
+fn free_blocks(expected: Vec<&str>, prefix: &str, allocated: Vec<&str>) {
+    let expected: Vec<_> = expected.into_iter().map(util::p).collect();
+    let prefix = util::p(prefix);
+    let allocated: Vec<_> = allocated.into_iter().map(util::p).collect();
+    let largest = expected.iter().min_by_key(|p| p.length()).cloned();
+    assert_eq!(expected, prefix.free_blocks(allocated.iter().cloned()));
+    assert_eq!(largest, prefix.largest_free_block(allocated));
+}
+
+runner::tests! { free_blocks {
+    nothing_allocated(vec!["10.0.0.0/24"], "10.0.0.5/24", vec![]);
+    all_allocated(vec![], "10.0.0.0/24", vec!["10.0.0.0/24"]);
+    allocated_supernet(vec![], "10.0.0.0/24", vec!["10.0.0.0/8"]);
+    one(vec!["10.0.0.0/26", "10.0.0.128/25"], "10.0.0.0/24", vec!["10.0.0.64/26"]);
+    unsorted(
+        vec!["10.0.0.32/27", "10.0.0.128/25"],
+        "10.0.0.0/24",
+        vec!["10.0.0.64/26", "10.0.0.0/27"]
+    );
+    overlapping(
+        vec!["10.0.0.128/25"],
+        "10.0.0.0/24",
+        vec!["10.0.0.0/25", "10.0.0.0/26", "10.0.0.64/27"]
+    );
+    outside(
+        vec!["10.0.0.0/25"],
+        "10.0.0.0/24",
+        vec!["9.0.0.0/8", "10.0.0.128/25", "10.0.1.0/24"]
+    );
+    misaligned_gap(
+        vec!["10.0.0.1/32", "10.0.0.2/31", "10.0.0.4/32"],
+        "10.0.0.0/29",
+        vec!["10.0.0.0/32", "10.0.0.5/32", "10.0.0.6/31"]
+    );
+    tie(vec!["10.0.0.0/26", "10.0.0.128/26"], "10.0.0.0/24", vec!["10.0.0.64/26", "10.0.0.192/26"]);
+    everything(vec!["0.0.0.0/1"], "0.0.0.0/0", vec!["255.0.0.0/8", "128.0.0.0/1"]);
+    end(vec!["255.255.255.254/32"], "255.255.255.254/31", vec!["255.255.255.255/32"]);
+} }
+
+fn address_at(expected: Result<util::Address>, prefix: &str, offset: u32) {
+    util::assert_result(expected, util::p(prefix).address_at(offset));
+}
+
+runner::tests! { address_at {
+    first(Ok(util::a("192.168.1.0")), "192.168.1.0/24", 0);
+    tenth(Ok(util::a("192.168.1.10")), "192.168.1.0/24", 10);
+    last(Ok(util::a("192.168.1.255")), "192.168.1.0/24", 255);
+    past_end(Err(addrs::Error::NotContained), "192.168.1.0/24", 256);
+    host_bits(Ok(util::a("192.168.1.1")), "192.168.1.77/24", 1);
+    host(Ok(util::a("192.168.1.77")), "192.168.1.77/32", 0);
+    host_past_end(Err(addrs::Error::NotContained), "192.168.1.77/32", 1);
+    all(Ok(util::a("255.255.255.255")), "0.0.0.0/0", u32::MAX);
+} }
+
+fn common_prefix(expected: &str, a: &str, b: &str) {
+    let (a, b) = (util::a(a), util::a(b));
+    assert_eq!(Some(util::p(expected)), addrs::ipv4::common_prefix(&a, &b));
+    assert_eq!(Some(util::p(expected)), addrs::ipv4::common_prefix(&b, &a));
+}
+
+runner::tests! { common_prefix {
+    same("10.0.0.1/32", "10.0.0.1", "10.0.0.1");
+    pair("10.0.0.0/31", "10.0.0.0", "10.0.0.1");
+    unaligned("10.0.0.0/22", "10.0.0.1", "10.0.3.1");
+    split("0.0.0.0/0", "127.255.255.255", "128.0.0.0");
+    extremes("0.0.0.0/0", "0.0.0.0", "255.255.255.255");
+} }
+
+#[test]
+fn common_prefix_addresses() {
+    let (a, b) = (util::a("10.0.0.0"), util::a("10.0.0.255"));
+    assert_eq!(None, addrs::ipv4::common_prefix::<util::Address>(&a, &b));
+    assert_eq!(Some(a), addrs::ipv4::common_prefix::<util::Address>(&a, &a));
+}
+
+fn position_of(expected: Option<u32>, prefix: &str, address: &str) {
+    let prefix = util::p(prefix);
+    let position = prefix.position_of(&util::a(address));
+    assert_eq!(expected, position);
+    if let Some(position) = position {
+        assert_eq!(util::a(address), prefix.address_at(position).unwrap());
+    }
+}
+
+runner::tests! { position_of {
+    first(Some(0), "192.168.1.0/24", "192.168.1.0");
+    tenth(Some(10), "192.168.1.77/24", "192.168.1.10");
+    last(Some(255), "192.168.1.0/24", "192.168.1.255");
+    before(None, "192.168.1.0/24", "192.168.0.255");
+    after(None, "192.168.1.0/24", "192.168.2.0");
+    host(Some(0), "192.168.1.77/32", "192.168.1.77");
+    all(Some(u32::MAX), "0.0.0.0/0", "255.255.255.255");
+} }
+
+fn set_prefixes(expected: Result<Vec<&str>>, prefix: &str, length: u8) {
+    let expected = expected.map(|v| v.into_iter().map(util::p).collect::<Vec<_>>());
+    let actual = Set::prefixes(&util::p(prefix), length).map(|p| p.collect::<Vec<util::Prefix>>());
+    util::assert_result(expected, actual);
+}
+
+runner::tests! { set_prefixes {
+    same(Ok(vec!["10.0.0.0/24"]), "10.0.0.1/24", 24);
+    halves(Ok(vec!["10.0.0.0/25", "10.0.0.128/25"]), "10.0.0.0/24", 25);
+    shorter(Ok(vec![]), "10.0.0.0/24", 23);
+    all(Ok(vec!["0.0.0.0/1", "128.0.0.0/1"]), "0.0.0.0/0", 1);
+    invalid(Err(addrs::Error::InvalidLength), "10.0.0.0/24", 33);
+} }
@@ -1,4 +1,4 @@
-use addrs::ipv4::{Prefix, Set};
+use addrs::ipv4::{self, Prefix, Set};
 
 mod util;
 
@@ -34,6 +34,114 @@ runner::tests! { range_empty {
     one_off("10.0.0.1", "10.0.0.0", "10.0.0.1");
 } }
 
+fn range_coarsen(expected: Vec<&str>, from: &str, to: &str, length: u8) {
+    let expected: Vec<util::Prefix> = expected.into_iter().map(util::p).collect();
+    let range = util::a(from)..=util::a(to);
+    assert_eq!(expected, range.coarsen::<util::Prefix>(length).unwrap());
+    let minimal = Minimal(range.clone());
+    assert_eq!(expected, minimal.coarsen::<util::Prefix>(length).unwrap());
+    if let Ok(range) = ipv4::AddressRange::try_from(range) {
+        assert_eq!(expected, range.coarsen::<util::Prefix>(length).unwrap());
+    }
+}
+
+runner::tests! { range_coarsen {
+    unaligned(vec!["10.0.0.0/24", "10.0.1.0/24", "10.0.2.0/24"], "10.0.0.200", "10.0.2.10", 24);
+    single(vec!["10.0.0.0/16"], "10.0.0.200", "10.0.2.10", 16);
+    addresses(vec!["10.0.0.1/32", "10.0.0.2/32"], "10.0.0.1", "10.0.0.2", 32);
+    end(vec!["255.255.255.0/24"], "255.255.255.200", "255.255.255.255", 24);
+    empty(vec![], "10.0.0.2", "10.0.0.1", 24);
+    all(vec!["0.0.0.0/1", "128.0.0.0/1"], "0.0.0.0", "255.255.255.255", 1);
+} }
+
+#[test]
+fn range_coarsen_invalid_length() {
+    let range = util::a("10.0.0.0")..=util::a("10.0.0.255");
+    assert!(range.coarsen::<util::Prefix>(33).is_err());
+    assert!(Minimal(range).coarsen::<util::Prefix>(33).is_err());
+}
+
+fn range_overlaps(expected: bool, from: &str, to: &str, prefix: &str) {
+    let range = util::a(from)..=util::a(to);
+    let prefix = util::p(prefix);
+    assert_eq!(expected, range.overlaps(&prefix));
+    if let Ok(range) = ipv4::AddressRange::try_from(range) {
+        assert_eq!(expected, range.overlaps(&prefix));
+    }
+}
+
+runner::tests! { range_overlaps {
+    contained(true, "10.0.0.0", "10.0.255.255", "10.0.1.0/24");
+    contains(true, "10.0.0.10", "10.0.0.20", "10.0.0.0/24");
+    start(true, "10.0.0.200", "10.0.1.10", "10.0.0.0/24");
+    end(true, "10.0.0.200", "10.0.1.10", "10.0.1.0/24");
+    touching_start(true, "10.0.0.255", "10.0.1.10", "10.0.0.0/24");
+    touching_end(true, "10.0.0.200", "10.0.1.0", "10.0.1.0/24");
+    before(false, "10.0.1.0", "10.0.1.10", "10.0.0.0/24");
+    after(false, "10.0.0.200", "10.0.0.255", "10.0.1.0/24");
+    empty(false, "10.0.0.5", "10.0.0.3", "10.0.0.0/24");
+} }
+
+fn range_addresses(expected: Vec<&str>, from: &str, to: &str) {
+    let expected: Vec<util::Address> = expected.into_iter().map(util::a).collect();
+    let range = util::a(from)..=util::a(to);
+    assert_eq!(expected, ipv4::addresses(&range).collect::<Vec<_>>());
+    assert_eq!(expected.len(), ipv4::addresses(&range).len());
+    assert_eq!(expected, range.iter_addresses().collect::<Vec<_>>());
+    if let Ok(range) = ipv4::AddressRange::try_from(range) {
+        assert_eq!(expected, range.addresses().collect::<Vec<_>>());
+        assert_eq!(expected, range.iter_addresses().collect::<Vec<_>>());
+    }
+}
+
+runner::tests! { range_addresses {
+    single(vec!["10.0.0.1"], "10.0.0.1", "10.0.0.1");
+    carry(vec!["10.0.0.254", "10.0.0.255", "10.0.1.0"], "10.0.0.254", "10.0.1.0");
+    start(vec!["0.0.0.0", "0.0.0.1"], "0.0.0.0", "0.0.0.1");
+    end(vec!["255.255.255.254", "255.255.255.255"], "255.255.255.254", "255.255.255.255");
+    empty(vec![], "10.0.0.2", "10.0.0.1");
+} }
+
+#[test]
+fn range_addresses_all() {
+    let range = util::a("0.0.0.0")..=util::a("255.255.255.255");
+    let mut addresses = ipv4::addresses(&range);
+    assert_eq!(1 << 32, addresses.len());
+    assert_eq!(Some(util::a("255.255.255.255")), addresses.next_back());
+    assert_eq!(Some(util::a("0.0.0.0")), addresses.next());
+}
+
+fn range_prefixes(expected: Vec<&str>, from: &str, to: &str, length: u8) {
+    let expected: Vec<util::Prefix> = expected.into_iter().map(util::p).collect();
+    let range = util::a(from)..=util::a(to);
+    let actual: Vec<util::Prefix> = range.prefixes(length).unwrap().collect();
+    assert_eq!(expected, actual);
+    assert_eq!(expected.len() as u32, range.num_prefixes(length).unwrap());
+    let actual: Vec<util::Prefix> = Minimal(range.clone()).prefixes(length).unwrap().collect();
+    assert_eq!(expected, actual);
+    if let Ok(range) = ipv4::AddressRange::try_from(range) {
+        let actual: Vec<util::Prefix> = Set::prefixes(&range, length).unwrap().collect();
+        assert_eq!(expected, actual);
+    }
+}
+
+runner::tests! { range_prefixes {
+    unaligned(vec!["10.0.1.0/24", "10.0.2.0/24"], "10.0.0.200", "10.0.3.10", 24);
+    too_big(vec![], "10.0.0.200", "10.0.3.10", 16);
+    aligned(vec!["10.0.0.0/23", "10.0.2.0/23"], "10.0.0.0", "10.0.3.255", 23);
+    addresses(vec!["10.0.0.254/32", "10.0.0.255/32", "10.0.1.0/32"], "10.0.0.254", "10.0.1.0", 32);
+    end(vec!["255.255.255.0/24"], "255.255.254.200", "255.255.255.255", 24);
+    empty(vec![], "10.0.0.2", "10.0.0.1", 24);
+    all(vec!["0.0.0.0/1", "128.0.0.0/1"], "0.0.0.0", "255.255.255.255", 1);
+} }
+
+#[test]
+fn range_prefixes_invalid_length() {
+    let range = util::a("10.0.0.0")..=util::a("10.0.0.255");
+    assert!(range.prefixes::<util::Prefix>(33).is_err());
+    assert!(Minimal(range).prefixes::<util::Prefix>(33).is_err());
+}
+
 #[test]
 fn debug() {
     let range = util::a("192.168.0.1")..=util::a("192.168.0.7");
@@ -68,6 +176,7 @@ fn num_prefixes(expected: u32, from: &str, to: &str, length: u8) {
     let to = util::a(to);
     let range = from..=to;
     assert_eq!(expected, range.num_prefixes(length).unwrap());
+    assert_eq!(u64::from(expected), range.num_prefixes_u64(length).unwrap());
 }
 
 runner::tests! { num_prefixes {
@@ -83,6 +192,9 @@ runner::tests! { num_prefixes {
     class_c_not_aligned(0, "10.223.255.1", "10.224.0.254", 24);
 
     just_two(2, "127.255.255.255", "128.0.0.0", 32);
+    whole_block(1, "10.224.0.0", "10.224.255.255", 16);
+    whole_block_halves(2, "10.224.0.0", "10.224.255.255", 17);
+    whole_block_too_small(0, "10.224.0.0", "10.224.255.255", 15);
 } }
 
 #[test]
@@ -92,3 +204,358 @@ fn num_prefixes_err() {
     let range = from..=to;
     assert!(range.num_prefixes(33).is_err());
 }
+
+// a set implementing only the required methods, to check the provided ones against
+struct Minimal(std::ops::RangeInclusive<util::Address>);
+
+impl Set for Minimal {
+    type Address = util::Address;
+
+    fn num_prefixes(&self, length: u8) -> addrs::Result<u32> {
+        self.0.num_prefixes(length)
+    }
+
+    fn contains<P: Prefix<Address = util::Address>>(&self, other: &P) -> bool {
+        Set::contains(&self.0, other)
+    }
+
+    fn overlaps<P: Prefix<Address = util::Address>>(&self, other: &P) -> bool {
+        self.0.overlaps(other)
+    }
+}
+
+fn num_prefixes_u64(expected: u64, from: &str, to: &str, length: u8) {
+    let range = util::a(from)..=util::a(to);
+    assert_eq!(expected, range.num_prefixes_u64(length).unwrap());
+    let minimal = Minimal(range.clone());
+    assert_eq!(expected, minimal.num_prefixes_u64(length).unwrap());
+    if let Ok(range) = ipv4::AddressRange::try_from(range) {
+        assert_eq!(expected, range.num_prefixes_u64(length).unwrap());
+    }
+}
+
+runner::tests! { num_prefixes_u64 {
+    all(1 << 32, "0.0.0.0", "255.255.255.255", 32);
+    all_zero(1, "0.0.0.0", "255.255.255.255", 0);
+    all_one(2, "0.0.0.0", "255.255.255.255", 1);
+    all_but_one(0xffffffff, "0.0.0.1", "255.255.255.255", 32);
+    empty(0, "10.0.0.1", "10.0.0.0", 0);
+} }
+
+#[test]
+fn num_prefixes_u64_err() {
+    let minimal = Minimal(util::a("10.0.0.0")..=util::a("10.0.0.255"));
+    assert!(minimal.num_prefixes_u64(33).is_err());
+}
+
+fn address_range_new(expected: Result<u64, addrs::Error>, first: &str, last: &str) {
+    let range = ipv4::AddressRange::new(util::a(first), util::a(last));
+    util::assert_result(expected, range.map(|r| r.len()));
+}
+
+runner::tests! { address_range_new {
+    single(Ok(1), "10.0.0.1", "10.0.0.1");
+    some(Ok(41), "10.0.0.10", "10.0.0.50");
+    all(Ok(1 << 32), "0.0.0.0", "255.255.255.255");
+    backwards(Err(addrs::Error::InvalidRange), "10.0.0.1", "10.0.0.0");
+} }
+
+fn address_range_from_str(expected: Result<(&str, &str), addrs::Error>, s: &str) {
+    let expected = expected
+        .map(|(first, last)| ipv4::AddressRange::new(util::a(first), util::a(last)).unwrap());
+    util::assert_result(expected, s.parse::<ipv4::AddressRange<util::Address>>());
+}
+
+runner::tests! { address_range_from_str {
+    basic(Ok(("10.0.0.10", "10.0.0.50")), "10.0.0.10-10.0.0.50");
+    single(Ok(("10.0.0.10", "10.0.0.10")), "10.0.0.10-10.0.0.10");
+    backwards(Err(addrs::Error::InvalidRange), "10.0.0.50-10.0.0.10");
+    no_dash(Err(addrs::Error::ParseError(None)), "10.0.0.10");
+    bad_first(Err(addrs::Error::ParseError(None)), "10.0.0-10.0.0.50");
+    bad_last(Err(addrs::Error::ParseError(None)), "10.0.0.10-10.0.0.500");
+    spaces(Err(addrs::Error::ParseError(None)), "10.0.0.10 - 10.0.0.50");
+    shorthand_one(Ok(("10.0.0.10", "10.0.0.50")), "10.0.0.10-50");
+    shorthand_two(Ok(("10.0.0.10", "10.0.3.50")), "10.0.0.10-3.50");
+    shorthand_three(Ok(("10.0.0.10", "10.1.3.50")), "10.0.0.10-1.3.50");
+    shorthand_backwards(Err(addrs::Error::InvalidRange), "10.0.0.10-5");
+    shorthand_bad_octet(Err(addrs::Error::ParseError(None)), "10.0.0.10-256");
+    shorthand_plus(Err(addrs::Error::ParseError(None)), "10.0.0.10-+50");
+    shorthand_empty(Err(addrs::Error::ParseError(None)), "10.0.0.10-");
+    shorthand_empty_octet(Err(addrs::Error::ParseError(None)), "10.0.0.10-1..50");
+    shorthand_leading_zero(Err(addrs::Error::ParseError(None)), "10.0.0.10-050");
+    shorthand_leading_zeros(Err(addrs::Error::ParseError(None)), "10.0.0.10-00.50");
+    shorthand_zero(Ok(("10.0.0.10", "10.0.1.0")), "10.0.0.10-1.0");
+    too_many_dots(Err(addrs::Error::ParseError(None)), "10.0.0.10-10.0.0.0.50");
+} }
+
+fn address_range_parse_glob(expected: Result<(&str, &str), addrs::Error>, s: &str) {
+    let expected = expected
+        .map(|(first, last)| ipv4::AddressRange::new(util::a(first), util::a(last)).unwrap());
+    util::assert_result(expected, ipv4::AddressRange::<util::Address>::parse_glob(s));
+}
+
+runner::tests! { address_range_parse_glob {
+    single(Ok(("10.0.0.1", "10.0.0.1")), "10.0.0.1");
+    star(Ok(("10.0.0.0", "10.0.255.255")), "10.0.*.*");
+    unaligned(Ok(("10.0.1.0", "10.0.3.255")), "10.0.1-3.*");
+    last_octet(Ok(("10.0.0.10", "10.0.0.50")), "10.0.0.10-50");
+    not_trailing(Err(addrs::Error::ParseError(None)), "10.*.0.*");
+    backwards(Err(addrs::Error::InvalidRange), "10.0.0.50-10");
+    empty(Err(addrs::Error::ParseError(None)), "");
+} }
+
+#[test]
+fn address_range_from_prefix() {
+    let range = ipv4::AddressRange::from_prefix(&util::p("10.224.24.117/24"));
+    assert_eq!(util::a("10.224.24.0"), range.first());
+    assert_eq!(util::a("10.224.24.255"), range.last());
+    assert_eq!("10.224.24.0-10.224.24.255", range.to_string());
+    assert_eq!("10.224.24.0-10.224.24.255", format!("{range:?}"));
+}
+
+#[test]
+fn address_range_format_flags() {
+    let range = ipv4::AddressRange::new(util::a("10.0.0.1"), util::a("10.0.0.9")).unwrap();
+    assert_eq!("   10.0.0.1-10.0.0.9", format!("{range:>20}"));
+    assert_eq!("10.0.0.1-10.0.0.9   |", format!("{range:<20}|"));
+    assert_eq!(" 10.0.0.1-10.0.0.9  ", format!("{range:^20}"));
+    let range = ipv4::AddressRange::new(util::a("0.0.0.0"), util::a("255.255.255.255")).unwrap();
+    assert_eq!("0.0.0.0-255.255.255.255", range.to_string());
+}
+
+#[test]
+fn address_range_set() {
+    let range = ipv4::AddressRange::new(util::a("10.223.255.1"), util::a("10.225.0.0")).unwrap();
+    assert_eq!(256, range.num_prefixes(24).unwrap());
+    assert!(!range.is_empty());
+    assert!(range.contains(&util::p("10.224.0.0/16")));
+    assert!(!range.contains(&util::p("10.223.0.0/16")));
+}
+
+#[test]
+fn address_range_range_inclusive() {
+    let range = ipv4::AddressRange::try_from(util::a("10.0.0.1")..=util::a("10.0.0.7")).unwrap();
+    assert_eq!(util::a("10.0.0.1")..=util::a("10.0.0.7"), range.into());
+    assert!(ipv4::AddressRange::try_from(util::a("10.0.0.1")..=util::a("10.0.0.0")).is_err());
+}
+
+#[cfg(feature = "rand")]
+fn rng() -> rand::rngs::StdRng {
+    rand::SeedableRng::seed_from_u64(0x5eed)
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_free_prefix() {
+    // the first two /27s are at least partially in use in the first range
+    let used = util::a("10.0.0.0")..=util::a("10.0.0.65");
+    let used2 = util::a("10.0.0.100")..=util::a("10.0.0.191");
+    let within = util::p("10.0.0.0/24");
+    let mut seen = std::collections::BTreeSet::new();
+    let mut rng = rng();
+    for _ in 0..100 {
+        let a: util::Prefix = used
+            .random_free_prefix(&within, 27, &mut rng)
+            .unwrap()
+            .unwrap();
+        assert!(!ipv4::Set::contains(&used, &a.network()));
+        seen.insert(a.to_string());
+        let b: util::Prefix = used2
+            .random_free_prefix(&within, 27, &mut rng)
+            .unwrap()
+            .unwrap();
+        assert!(!ipv4::Set::contains(&used2, &b.network()));
+    }
+    let expected = [
+        "10.0.0.128/27",
+        "10.0.0.160/27",
+        "10.0.0.192/27",
+        "10.0.0.224/27",
+        "10.0.0.96/27",
+    ];
+    assert_eq!(
+        expected
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<std::collections::BTreeSet<_>>(),
+        seen
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_free_prefix_full() {
+    let used = util::a("9.0.0.0")..=util::a("11.0.0.0");
+    let free: Option<util::Prefix> = used
+        .random_free_prefix(&util::p("10.0.0.0/8"), 24, &mut rng())
+        .unwrap();
+    assert_eq!(None, free);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_free_prefix_large() {
+    // only the addresses left free in the /24 are candidates, without visiting the rest
+    let used = util::a("0.0.0.0")..=util::a("10.0.0.253");
+    let within = util::p("0.0.0.0/0");
+    let mut rng = rng();
+    for _ in 0..10 {
+        let free: util::Prefix = used
+            .random_free_prefix(&within, 32, &mut rng)
+            .unwrap()
+            .unwrap();
+        assert!(util::a("10.0.0.253") < free.network());
+    }
+    let used = util::a("0.0.0.0")..=util::a("255.255.255.254");
+    let free: util::Address = used
+        .random_free_prefix(&util::a("255.255.255.255"), 32, &mut rng)
+        .unwrap()
+        .unwrap();
+    assert_eq!(util::a("255.255.255.255"), free);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random_free_prefix_invalid_length() {
+    let used = util::a("10.0.0.0")..=util::a("10.0.0.1");
+    let within = util::p("10.0.0.0/24");
+    let mut rng = rng();
+    assert!(used.random_free_prefix(&within, 23, &mut rng).is_err());
+    assert!(used.random_free_prefix(&within, 33, &mut rng).is_err());
+    let free: util::Prefix = used
+        .random_free_prefix(&within, 24, &mut rng)
+        .unwrap()
+        .unwrap_or(within);
+    assert_eq!(within, free);
+}
+
+fn alignments_within(expected: Option<(&str, &str)>, from: &str, to: &str, length: u8) {
+    let expected = expected.map(|(first, last)| (util::p(first), util::p(last)));
+    let range = util::a(from)..=util::a(to);
+    let actual = ipv4::alignments_within::<util::Prefix>(&range, length).unwrap();
+    assert_eq!(expected, actual);
+    let count = range.num_prefixes(length).unwrap();
+    match actual {
+        Some((first, last)) => {
+            let first: u32 = first.network().into();
+            let last: u32 = last.network().into();
+            assert_eq!(count, ((last - first) >> (32 - length)) + 1);
+        }
+        None => assert_eq!(0, count),
+    }
+}
+
+runner::tests! { alignments_within {
+    misaligned(Some(("10.224.0.0/24", "10.224.255.0/24")), "10.223.255.1", "10.225.0.254", 24);
+    aligned(Some(("10.0.0.0/24", "10.0.3.0/24")), "10.0.0.0", "10.0.3.255", 24);
+    single(Some(("10.0.1.0/24", "10.0.1.0/24")), "10.0.0.1", "10.0.2.0", 24);
+    too_small(None, "10.0.0.1", "10.0.1.0", 24);
+    addresses(Some(("10.0.0.1/32", "10.0.0.5/32")), "10.0.0.1", "10.0.0.5", 32);
+    end(Some(("255.255.255.0/24", "255.255.255.0/24")), "255.255.254.1", "255.255.255.255", 24);
+    half(Some(("0.0.0.0/1", "0.0.0.0/1")), "0.0.0.0", "254.255.255.255", 1);
+    backwards(None, "10.0.0.5", "10.0.0.1", 32);
+} }
+
+#[test]
+fn alignments_within_all() {
+    let range = util::a("0.0.0.0")..=util::a("255.255.255.255");
+    let (first, last) = ipv4::alignments_within::<util::Prefix>(&range, 0)
+        .unwrap()
+        .unwrap();
+    assert_eq!(util::p("0.0.0.0/0"), first);
+    assert_eq!(util::p("0.0.0.0/0"), last);
+    assert!(ipv4::alignments_within::<util::Prefix>(&range, 33).is_err());
+}
+
+fn summarize_range(expected: Vec<&str>, from: &str, to: &str) {
+    let expected: Vec<_> = expected.into_iter().map(util::p).collect();
+    let range = util::a(from)..=util::a(to);
+    let prefixes: Vec<util::Prefix> = ipv4::summarize_range(&range).collect();
+    assert_eq!(expected, prefixes);
+    if let Ok(range) = ipv4::AddressRange::try_from(range) {
+        assert_eq!(expected, range.prefixes().collect::<Vec<util::Prefix>>());
+        // they cover exactly the range
+        let total: u64 = prefixes.iter().map(|p| 1u64 << (32 - p.length())).sum();
+        assert_eq!(range.len(), total);
+    }
+}
+
+runner::tests! { summarize_range {
+    single(vec!["10.0.0.1/32"], "10.0.0.1", "10.0.0.1");
+    prefix(vec!["10.0.0.0/24"], "10.0.0.0", "10.0.0.255");
+    unaligned(vec!["10.0.0.1/32", "10.0.0.2/31", "10.0.0.4/30", "10.0.0.8/31"], "10.0.0.1", "10.0.0.9");
+    across(
+        vec!["10.0.0.255/32", "10.0.1.0/24", "10.0.2.0/31"],
+        "10.0.0.255",
+        "10.0.2.1"
+    );
+    all(vec!["0.0.0.0/0"], "0.0.0.0", "255.255.255.255");
+    top(vec!["255.255.255.255/32"], "255.255.255.255", "255.255.255.255");
+    empty(vec![], "10.0.0.2", "10.0.0.1");
+} }
+
+#[test]
+fn summarize_range_addresses() {
+    // an address can't hold a longer prefix so every address is returned instead
+    let range = util::a("10.0.0.1")..=util::a("10.0.0.5");
+    let addresses: Vec<util::Address> = ipv4::summarize_range(&range).collect();
+    assert_eq!(ipv4::addresses(&range).collect::<Vec<_>>(), addresses);
+    let range = ipv4::AddressRange::try_from(range).unwrap();
+    assert_eq!(addresses, range.prefixes().collect::<Vec<util::Address>>());
+}
+
+#[test]
+fn summarize_range_count() {
+    let range = util::a("0.0.0.1")..=util::a("255.255.255.254");
+    assert_eq!(62, ipv4::summarize_range::<util::Prefix>(&range).count());
+}
+
+fn spanning_prefix(expected: Option<&str>, from: &str, to: &str) {
+    let expected = expected.map(util::p);
+    let range = util::a(from)..=util::a(to);
+    assert_eq!(expected, ipv4::spanning_prefix(&range));
+    if let Ok(range) = ipv4::AddressRange::try_from(range) {
+        assert_eq!(expected, range.spanning_prefix());
+    }
+}
+
+runner::tests! { spanning_prefix {
+    single(Some("10.0.0.1/32"), "10.0.0.1", "10.0.0.1");
+    exact(Some("10.0.0.0/24"), "10.0.0.0", "10.0.0.255");
+    inside(Some("10.0.0.0/24"), "10.0.0.1", "10.0.0.254");
+    across(Some("10.0.0.0/23"), "10.0.0.100", "10.0.1.5");
+    halves(Some("0.0.0.0/0"), "127.255.255.255", "128.0.0.0");
+    all(Some("0.0.0.0/0"), "0.0.0.0", "255.255.255.255");
+    empty(None, "10.0.0.2", "10.0.0.1");
+} }
+
+#[test]
+fn spanning_prefix_address() {
+    let range = ipv4::AddressRange::new(util::a("10.0.0.0"), util::a("10.0.0.1")).unwrap();
+    assert_eq!(None, range.spanning_prefix::<util::Address>());
+    let range = ipv4::AddressRange::new(util::a("10.0.0.1"), util::a("10.0.0.1")).unwrap();
+    assert_eq!(
+        Some(util::a("10.0.0.1")),
+        range.spanning_prefix::<util::Address>()
+    );
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn range_random_address() {
+    use rand::SeedableRng;
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(0x5eed);
+    let range = util::a("10.0.0.250")..=util::a("10.0.1.5");
+    let address_range = ipv4::AddressRange::try_from(range.clone()).unwrap();
+    for _ in 0..100 {
+        assert!(range.contains(&range.random_address(&mut rng).unwrap()));
+        let address = address_range.random_address(&mut rng).unwrap();
+        assert!(Set::contains(&address_range, &address));
+    }
+    let empty = util::a("10.0.0.1")..=util::a("10.0.0.0");
+    assert_eq!(None, empty.random_address(&mut rng));
+
+    let all = util::a("0.0.0.0")..=util::a("255.255.255.255");
+    assert!(all.random_address(&mut rng).is_some());
+}
@@ -0,0 +1,140 @@
+use addrs::ipv4::{self, sets, sets::Blocks, Addr, IpSet, Pfx, Prefix, Set};
+use addrs::prefix;
+
+fn p(s: &str) -> Pfx {
+    s.parse().unwrap()
+}
+
+fn well_formed(set: Blocks) {
+    let set = set.as_slice();
+    assert!(!set.is_empty());
+    for prefix in set {
+        assert!(prefix.is_aligned(), "{prefix}");
+    }
+    for pair in set.windows(2) {
+        assert!(pair[0].broadcast() < pair[1].network(), "{pair:?}");
+    }
+    assert!(ipv4::overlapping_pairs(set.iter().copied()).is_empty());
+}
+
+runner::tests! { well_formed {
+    private(sets::PRIVATE);
+    loopback(sets::LOOPBACK);
+    link_local(sets::LINK_LOCAL);
+    shared(sets::SHARED);
+    documentation(sets::DOCUMENTATION);
+    benchmarking(sets::BENCHMARKING);
+    multicast(sets::MULTICAST);
+    reserved(sets::RESERVED);
+    special_purpose(sets::SPECIAL_PURPOSE);
+    bogons(sets::BOGONS);
+} }
+
+fn classified(set: Blocks, classify: fn(&Pfx) -> bool) {
+    for prefix in set {
+        assert!(classify(prefix), "{prefix}");
+        if let Some(parent) = prefix.parent() {
+            assert!(!classify(&parent), "{parent}");
+        }
+    }
+}
+
+runner::tests! { classified {
+    private(sets::PRIVATE, Pfx::is_private);
+    loopback(sets::LOOPBACK, Pfx::is_loopback);
+    link_local(sets::LINK_LOCAL, Pfx::is_link_local);
+    shared(sets::SHARED, Pfx::is_shared);
+    documentation(sets::DOCUMENTATION, Pfx::is_documentation);
+    benchmarking(sets::BENCHMARKING, Pfx::is_benchmarking);
+    multicast(sets::MULTICAST, Pfx::is_multicast);
+    reserved(sets::RESERVED, Pfx::is_reserved);
+} }
+
+#[test]
+fn special_purpose() {
+    let special: IpSet = sets::SPECIAL_PURPOSE.iter().copied().collect();
+    for set in [
+        sets::PRIVATE,
+        sets::LOOPBACK,
+        sets::LINK_LOCAL,
+        sets::SHARED,
+        sets::DOCUMENTATION,
+        sets::BENCHMARKING,
+        sets::RESERVED,
+    ] {
+        assert!(special.contains_set(&set), "{set:?}");
+        assert!(sets::SPECIAL_PURPOSE.contains_set(&set), "{set:?}");
+    }
+    assert!(special.contains(&Addr::BROADCAST));
+    assert!(!special.contains(&Addr::new(224, 0, 0, 1)));
+}
+
+#[test]
+fn bogons() {
+    let bogons: IpSet = sets::BOGONS.iter().copied().collect();
+    for address in [
+        Addr::new(8, 8, 8, 8),
+        Addr::new(1, 1, 1, 1),
+        Addr::new(192, 0, 1, 1),
+    ] {
+        assert!(!bogons.contains(&address), "{address}");
+        assert!(address.is_global(), "{address}");
+    }
+    for address in [
+        Addr::new(0, 1, 2, 3),
+        Addr::new(10, 1, 2, 3),
+        Addr::new(239, 1, 2, 3),
+    ] {
+        assert!(bogons.contains(&address), "{address}");
+    }
+}
+
+#[test]
+fn bogons_not_global() {
+    for prefix in sets::BOGONS {
+        let multicast = sets::MULTICAST.contains(prefix);
+        assert_eq!(multicast, prefix.is_global(), "{prefix}");
+        assert_eq!(multicast, prefix.network().is_global(), "{prefix}");
+        assert_eq!(multicast, prefix.broadcast().is_global(), "{prefix}");
+    }
+}
+
+#[test]
+fn contains() {
+    assert!(sets::PRIVATE.contains(&p("10.1.0.0/16")));
+    assert!(sets::PRIVATE.contains(&Addr::new(192, 168, 1, 1)));
+    assert!(!sets::PRIVATE.contains(&p("10.0.0.0/7")));
+    assert!(sets::PRIVATE.overlaps(&p("10.0.0.0/7")));
+    assert!(!sets::PRIVATE.overlaps(&p("11.0.0.0/8")));
+    assert_eq!(1 << 24, sets::LOOPBACK.num_addresses().unwrap());
+}
+
+#[test]
+fn adjacent() {
+    // neither /25 contains the /24 but together they cover it
+    const SET: Option<Blocks> = Blocks::new(&[
+        prefix!("10.0.0.0/25"),
+        prefix!("10.0.0.128/25"),
+        prefix!("10.0.1.0/24"),
+    ]);
+    let set = SET.unwrap();
+    assert!(set.contains(&p("10.0.0.0/24")));
+    assert!(set.contains(&p("10.0.0.0/23")));
+    assert!(!set.contains(&p("10.0.0.0/22")));
+    assert_eq!(1, set.num_prefixes(23).unwrap());
+    assert_eq!(2, set.num_prefixes(24).unwrap());
+    assert_eq!(
+        vec![p("10.0.0.0/23")],
+        set.prefixes::<Pfx>(23).unwrap().collect::<Vec<_>>()
+    );
+    assert_eq!(vec![p("10.0.0.0/16")], set.coarsen::<Pfx>(16).unwrap());
+    assert_eq!(512, set.iter_addresses().count());
+}
+
+#[test]
+fn not_a_set() {
+    assert!(Blocks::new(&[]).is_some());
+    assert!(Blocks::new(&[prefix!("10.0.0.0/8"), prefix!("10.0.0.0/8")]).is_none());
+    assert!(Blocks::new(&[prefix!("10.0.0.0/8"), prefix!("9.0.0.0/8")]).is_none());
+    assert!(Blocks::new(&[prefix!("11.0.0.0/7")]).is_none());
+}
@@ -0,0 +1,84 @@
+use addrs::{
+    ipv4::{Addr, Pfx, Prefix, Subnet},
+    Result,
+};
+
+mod util;
+
+fn new(expected: Result<&str>, address: Addr, length: u8) {
+    let expected = expected.map(|s| s.parse::<Subnet>().unwrap());
+    util::assert_result(expected, Subnet::new(address, length));
+}
+
+runner::tests! { new {
+    zero(Ok("0.0.0.0/0"), Addr::UNSPECIFIED, 0);
+    basic(Ok("10.224.24.0/24"), Addr::new(10, 224, 24, 0), 24);
+    host_route(Ok("10.224.24.1/32"), Addr::new(10, 224, 24, 1), 32);
+    host_bits(Err(addrs::Error::HostBitsSet), Addr::new(10, 224, 24, 1), 24);
+    invalid_length(Err(addrs::Error::InvalidLength), Addr::new(10, 224, 24, 0), 33);
+} }
+
+fn masked(expected: Result<&str>, address: Addr, length: u8) {
+    let expected = expected.map(|s| s.parse::<Subnet>().unwrap());
+    util::assert_result(expected, Subnet::masked(address, length));
+}
+
+runner::tests! { masked {
+    zero(Ok("0.0.0.0/0"), Addr::BROADCAST, 0);
+    basic(Ok("10.224.24.0/24"), Addr::new(10, 224, 24, 1), 24);
+    host_route(Ok("10.224.24.1/32"), Addr::new(10, 224, 24, 1), 32);
+    invalid_length(Err(addrs::Error::InvalidLength), Addr::new(10, 224, 24, 0), 33);
+} }
+
+fn from_str(expected: Result<(Addr, u8)>, s: &str) {
+    let expected = expected.map(|(address, length)| Subnet::new(address, length).unwrap());
+    util::assert_result(expected, s.parse());
+}
+
+runner::tests! { from_str {
+    basic(Ok((Addr::new(10, 224, 24, 0), 24)), "10.224.24.0/24");
+    host_bits(Err(addrs::Error::HostBitsSet), "10.224.24.1/24");
+    invalid_length(Err(addrs::Error::InvalidLength), "10.224.24.0/33");
+    bogus(Err(addrs::Error::ParseError(None)), "bogus");
+} }
+
+#[test]
+fn prefix_methods_keep_invariant() {
+    let subnet: Subnet = Prefix::from_address_length(Addr::new(10, 224, 24, 1), 24).unwrap();
+    assert_eq!(Addr::new(10, 224, 24, 0), subnet.address());
+
+    let subnet: Subnet =
+        Prefix::from_address_mask(Addr::new(10, 224, 24, 1), Addr::new(255, 255, 255, 0)).unwrap();
+    assert_eq!(Addr::new(10, 224, 24, 0), subnet.address());
+
+    let (a, b) = subnet.halves().unwrap();
+    assert_eq!("10.224.24.0/25", a.to_string());
+    assert_eq!("10.224.24.128/25", b.to_string());
+}
+
+#[test]
+fn conversions() {
+    let prefix: Pfx = "10.224.24.0/24".parse().unwrap();
+    let subnet = Subnet::try_from(prefix).unwrap();
+    assert_eq!(prefix, subnet.into());
+
+    let prefix: Pfx = "10.224.24.1/24".parse().unwrap();
+    util::assert_result(Err(addrs::Error::HostBitsSet), Subnet::try_from(prefix));
+}
+
+#[test]
+fn format() {
+    let subnet: Subnet = "10.224.24.0/24".parse().unwrap();
+    assert_eq!("10.224.24.0/24", subnet.to_string());
+    assert_eq!("10.224.24.0/24", format!("{subnet:?}"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    let subnet: Subnet = "10.224.24.0/24".parse().unwrap();
+    let json = serde_json::to_string(&subnet).unwrap();
+    assert_eq!("\"10.224.24.0/24\"", json);
+    assert_eq!(subnet, serde_json::from_str(&json).unwrap());
+    assert!(serde_json::from_str::<Subnet>("\"10.224.24.1/24\"").is_err());
+}
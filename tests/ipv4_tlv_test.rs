@@ -0,0 +1,96 @@
+use addrs::{
+    ipv4::{tlv, Addr, Pfx, Prefix},
+    Result,
+};
+
+mod util;
+
+fn prefix_round_trip(expected: Vec<u8>, prefix: &str) {
+    let prefix: Pfx = prefix.parse().unwrap();
+    let mut buf = vec![];
+    tlv::put_prefix(&prefix, &mut buf);
+    assert_eq!(expected, buf);
+
+    let (decoded, consumed) = tlv::get_prefix::<Pfx>(&buf).unwrap();
+    assert_eq!(buf.len(), consumed);
+    assert_eq!(prefix.network(), decoded.address());
+    assert_eq!(prefix.length(), decoded.length());
+}
+
+runner::tests! { prefix_round_trip {
+    zero(vec![0], "0.0.0.0/0");
+    one(vec![1, 128], "128.0.0.0/1");
+    eight(vec![8, 10], "10.0.0.0/8");
+    nine(vec![9, 10, 128], "10.128.0.0/9");
+    host_bits(vec![24, 10, 17, 3], "10.17.3.77/24");
+    host_route(vec![32, 10, 17, 3, 77], "10.17.3.77/32");
+} }
+
+fn get_prefix(expected: Result<(&str, usize)>, buf: Vec<u8>) {
+    let expected = expected.map(|(prefix, consumed)| (prefix.parse::<Pfx>().unwrap(), consumed));
+    util::assert_result(expected, tlv::get_prefix(&buf));
+}
+
+runner::tests! { get_prefix {
+    trailing_data(Ok(("10.0.0.0/8", 2)), vec![8, 10, 99, 99]);
+    empty(Err(addrs::Error::ParseError(None)), vec![]);
+    truncated(Err(addrs::Error::ParseError(None)), vec![24, 10, 17]);
+    invalid_length(Err(addrs::Error::InvalidLength), vec![33, 10, 0, 0, 0, 0]);
+} }
+
+#[test]
+fn address_list() {
+    let addresses = vec![Addr::new(10, 0, 0, 1), Addr::new(192, 168, 0, 1)];
+    let mut buf = vec![0xff];
+    tlv::put_address_list(addresses.clone(), &mut buf).unwrap();
+    assert_eq!(vec![0xff, 8, 10, 0, 0, 1, 192, 168, 0, 1], buf);
+
+    let (decoded, consumed) = tlv::get_address_list::<Addr>(&buf[1..]).unwrap();
+    assert_eq!(addresses, decoded);
+    assert_eq!(9, consumed);
+}
+
+#[test]
+fn address_list_too_many() {
+    let mut buf = vec![0xff];
+    tlv::put_address_list(vec![Addr::UNSPECIFIED; 63], &mut buf).unwrap();
+    assert_eq!(1 + 1 + 63 * 4, buf.len());
+
+    let mut buf = vec![0xff];
+    util::assert_result(
+        Err(addrs::Error::TooMany),
+        tlv::put_address_list(vec![Addr::UNSPECIFIED; 64], &mut buf),
+    );
+    assert_eq!(vec![0xff], buf);
+}
+
+fn get_address_list(expected: Result<(Vec<&str>, usize)>, buf: Vec<u8>) {
+    let expected = expected
+        .map(|(addresses, consumed)| (addresses.into_iter().map(util::a).collect(), consumed));
+    util::assert_result(expected, tlv::get_address_list::<util::Address>(&buf));
+}
+
+runner::tests! { get_address_list {
+    empty_list(Ok((vec![], 1)), vec![0]);
+    trailing_data(Ok((vec!["1.2.3.4"], 5)), vec![4, 1, 2, 3, 4, 5]);
+    empty(Err(addrs::Error::ParseError(None)), vec![]);
+    truncated(Err(addrs::Error::ParseError(None)), vec![8, 1, 2, 3, 4, 5]);
+    not_multiple(Err(addrs::Error::ParseError(None)), vec![3, 1, 2, 3]);
+} }
+
+#[test]
+fn option() {
+    let mut buf = vec![];
+    tlv::put_option(1, b"circuit", &mut buf).unwrap();
+    tlv::put_option(2, &[], &mut buf).unwrap();
+    assert_eq!(b"\x01\x07circuit\x02\x00".to_vec(), buf);
+
+    let (code, value, consumed) = tlv::get_option(&buf).unwrap();
+    assert_eq!((1, b"circuit".as_slice(), 9), (code, value, consumed));
+    let (code, value, consumed) = tlv::get_option(&buf[consumed..]).unwrap();
+    assert_eq!((2, [].as_slice(), 2), (code, value, consumed));
+
+    assert!(tlv::get_option(&buf[..8]).is_err());
+    assert!(tlv::get_option(&buf[..1]).is_err());
+    assert!(tlv::put_option(1, &[0; 256], &mut buf).is_err());
+}
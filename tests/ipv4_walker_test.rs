@@ -0,0 +1,115 @@
+use addrs::ipv4::{Addr, AddressRange, IpSet, Pfx, Walker};
+
+fn range(first: &str, last: &str) -> AddressRange {
+    AddressRange::new(first.parse().unwrap(), last.parse().unwrap()).unwrap()
+}
+
+#[test]
+fn walk() {
+    let walker = Walker::new(range("10.0.0.254", "10.0.1.1"));
+    assert_eq!((4, Some(4)), walker.size_hint());
+    let addresses: Vec<String> = walker.map(|a| a.to_string()).collect();
+    assert_eq!(
+        vec!["10.0.0.254", "10.0.0.255", "10.0.1.0", "10.0.1.1"],
+        addresses
+    );
+}
+
+#[test]
+fn checkpoint() {
+    let mut walker = Walker::new(range("10.0.0.1", "10.0.0.3"));
+    assert_eq!(None, walker.checkpoint());
+    assert!(!walker.is_done());
+    walker.next();
+    assert_eq!(Some(Addr::new(10, 0, 0, 1)), walker.checkpoint());
+    assert_eq!((2, Some(2)), walker.size_hint());
+    walker.by_ref().for_each(drop);
+    assert_eq!(Some(Addr::new(10, 0, 0, 3)), walker.checkpoint());
+    assert!(walker.is_done());
+    assert_eq!(None, walker.next());
+    assert_eq!((0, Some(0)), walker.size_hint());
+}
+
+#[test]
+fn resume() {
+    let r = range("10.0.0.1", "10.0.0.5");
+    let mut walker = Walker::new(r);
+    walker.next();
+    walker.next();
+
+    let resumed = Walker::resume(r, walker.checkpoint()).unwrap();
+    assert_eq!(walker.collect::<Vec<_>>(), resumed.collect::<Vec<_>>());
+
+    let fresh = Walker::resume(r, None).unwrap();
+    assert_eq!(5, fresh.count());
+
+    let done = Walker::resume(r, Some(Addr::new(10, 0, 0, 5))).unwrap();
+    assert!(done.is_done());
+    assert_eq!(0, done.count());
+}
+
+#[test]
+fn resume_outside() {
+    let r = range("10.0.0.1", "10.0.0.5");
+    assert!(Walker::resume(r, Some(Addr::new(10, 0, 0, 0))).is_err());
+    assert!(Walker::resume(r, Some(Addr::new(10, 0, 0, 6))).is_err());
+}
+
+#[test]
+fn end_of_address_space() {
+    let walker = Walker::new(range("255.255.255.254", "255.255.255.255"));
+    assert_eq!(2, walker.count());
+}
+
+fn set(prefixes: &[&str]) -> IpSet {
+    prefixes.iter().map(|s| s.parse::<Pfx>().unwrap()).collect()
+}
+
+#[test]
+fn walk_set() {
+    let set = set(&["10.0.0.254/31", "10.0.1.0/32", "10.0.2.0/30"]);
+    let mut walker = Walker::new(set.clone());
+    assert_eq!((7, Some(7)), walker.size_hint());
+    let addresses: Vec<String> = walker.by_ref().map(|a| a.to_string()).collect();
+    assert_eq!(
+        vec![
+            "10.0.0.254",
+            "10.0.0.255",
+            "10.0.1.0",
+            "10.0.2.0",
+            "10.0.2.1",
+            "10.0.2.2",
+            "10.0.2.3"
+        ],
+        addresses
+    );
+    assert!(walker.is_done());
+    assert_eq!((0, Some(0)), walker.size_hint());
+
+    let mut resumed = Walker::resume(set.clone(), Some(Addr::new(10, 0, 1, 0))).unwrap();
+    assert_eq!((4, Some(4)), resumed.size_hint());
+    assert_eq!(Some(Addr::new(10, 0, 2, 0)), resumed.next());
+    // addresses in the gaps between prefixes are not checkpoints of the set
+    assert!(Walker::resume(set, Some(Addr::new(10, 0, 1, 1))).is_err());
+}
+
+#[test]
+fn walk_prefix() {
+    let prefix: Pfx = "10.0.0.4/30".parse().unwrap();
+    let walker = Walker::new(prefix);
+    assert_eq!(prefix, *walker.set());
+    assert_eq!(4, walker.count());
+
+    let top: Pfx = "255.255.255.254/31".parse().unwrap();
+    let done = Walker::resume(top, Some(Addr::new(255, 255, 255, 255))).unwrap();
+    assert!(done.is_done());
+    assert_eq!((0, Some(0)), done.size_hint());
+    assert_eq!(0, done.count());
+}
+
+#[test]
+fn walk_empty() {
+    let walker = Walker::new(IpSet::<Pfx>::new());
+    assert!(walker.is_done());
+    assert_eq!(0, walker.count());
+}
@@ -0,0 +1,63 @@
+use addrs::{
+    ipv4::{Addr, Netmask, Prefix, Wildcard},
+    Result,
+};
+
+mod util;
+
+fn from_length(expected: Result<&str>, length: u8) {
+    let expected = expected.map(|s| s.parse::<Wildcard>().unwrap());
+    util::assert_result(expected, Wildcard::from_length(length));
+}
+
+runner::tests! { from_length {
+    zero(Ok("255.255.255.255"), 0);
+    one(Ok("127.255.255.255"), 1);
+    twentytwo(Ok("0.0.3.255"), 22);
+    thirtytwo(Ok("0.0.0.0"), 32);
+    invalid_length(Err(addrs::Error::InvalidLength), 33);
+} }
+
+fn new(expected: Result<u8>, wildcard: &str) {
+    let wildcard: Addr = wildcard.parse().unwrap();
+    util::assert_result(expected, Wildcard::new(wildcard).map(|w| w.length()));
+}
+
+runner::tests! { new {
+    zero(Ok(0), "255.255.255.255");
+    twentytwo(Ok(22), "0.0.3.255");
+    thirtytwo(Ok(32), "0.0.0.0");
+    hole(Err(addrs::Error::InvalidMask), "0.0.255.0");
+    netmask(Err(addrs::Error::InvalidMask), "255.255.255.0");
+} }
+
+#[test]
+fn netmask_conversions() {
+    let mask = Netmask::from_length(20).unwrap();
+    let wildcard = Wildcard::from(mask);
+    assert_eq!(20, wildcard.length());
+    assert_eq!("0.0.15.255", wildcard.to_string());
+    assert_eq!(mask, wildcard.into());
+}
+
+#[test]
+fn conversions() {
+    let wildcard = Wildcard::from_length(20).unwrap();
+    assert_eq!(0x00000fffu32, wildcard.into());
+    assert_eq!(Addr::new(0, 0, 15, 255), wildcard.into());
+    assert_eq!(util::a("0.0.15.255"), wildcard.address());
+    assert_eq!(wildcard, Wildcard::try_from(0x00000fffu32).unwrap());
+    assert_eq!(
+        wildcard,
+        Wildcard::try_from(Addr::new(0, 0, 15, 255)).unwrap()
+    );
+    assert!(Wildcard::try_from(0x00000ffeu32).is_err());
+    assert_eq!("0.0.15.255", format!("{wildcard:?}"));
+}
+
+#[test]
+fn from_address_wildcard() {
+    let wildcard: Wildcard = "0.0.0.255".parse().unwrap();
+    let prefix = util::Prefix::from_address_wildcard(util::a("192.168.1.1"), wildcard);
+    assert_eq!(util::p("192.168.1.1/24"), prefix);
+}
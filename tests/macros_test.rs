@@ -0,0 +1,43 @@
+use addrs::{
+    addr,
+    ipv4::{Addr, Pfx, Prefix},
+    prefix,
+};
+
+const LOCAL: Addr = addr!("127.0.0.1");
+static DEFAULT: Pfx = prefix!("0.0.0.0/0");
+
+#[test]
+fn addr() {
+    assert_eq!(Addr::LOCALHOST, LOCAL);
+    assert_eq!(Addr::BROADCAST, addr!("255.255.255.255"));
+    assert_eq!("10.224.24.1".parse::<Addr>().unwrap(), addr!("10.224.24.1"));
+}
+
+#[test]
+fn prefix() {
+    assert_eq!(0, DEFAULT.length());
+    assert_eq!(
+        "10.224.24.1/27".parse::<Pfx>().unwrap(),
+        prefix!("10.224.24.1/27")
+    );
+    assert_eq!(32, prefix!("255.255.255.255/32").length());
+}
+
+fn parse_const(valid: bool, s: &str) {
+    assert_eq!(valid, Pfx::parse_const(s).is_some());
+    assert_eq!(s.parse::<Pfx>().ok(), Pfx::parse_const(s));
+}
+
+runner::tests! { parse_const {
+    basic(true, "10.0.0.0/8");
+    two_digits(true, "10.0.0.0/24");
+    max(true, "10.0.0.0/32");
+    too_long(false, "10.0.0.0/33");
+    three_digits(false, "10.0.0.0/100");
+    no_length(false, "10.0.0.0/");
+    no_slash(false, "10.0.0.0");
+    trailing(false, "10.0.0.0/8 ");
+    plus(false, "10.0.0.0/+8");
+    bad_address(false, "10.0.0/8");
+} }
@@ -10,6 +10,16 @@ pub struct Address {
 }
 
 impl addrs::ipv4::Address for Address {
+    const UNSPECIFIED: Self = Self {
+        octets: [0, 0, 0, 0],
+    };
+    const BROADCAST: Self = Self {
+        octets: [255, 255, 255, 255],
+    };
+    const LOCALHOST: Self = Self {
+        octets: [127, 0, 0, 1],
+    };
+
     fn octets(&self) -> [u8; 4] {
         self.octets
     }
@@ -115,7 +125,7 @@ impl addrs::ipv4::Prefix for Prefix {
     fn length(&self) -> u8 {
         self.length
     }
-    unsafe fn unsafe_new(address: Self::Address, length: u8) -> Self {
+    unsafe fn new_unchecked(address: Self::Address, length: u8) -> Self {
         Self { address, length }
     }
 }
@@ -136,10 +146,11 @@ impl std::str::FromStr for Prefix {
         let addr = net.addr();
         let array: [u8; 4] = addr.octets();
         let addr: Address = array.into();
-        Ok(unsafe { addrs::ipv4::Prefix::unsafe_new(addr, net.prefix_len()) })
+        Ok(unsafe { addrs::ipv4::Prefix::new_unchecked(addr, net.prefix_len()) })
     }
 }
 
+#[allow(dead_code)]
 pub fn a(s: &str) -> Address {
     s.parse().expect("bad ip")
 }